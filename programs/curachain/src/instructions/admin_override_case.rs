@@ -1,35 +1,75 @@
-use anchor_lang::{prelude::*, solana_program::{self, rent::Rent/*, system_program system_instruction*/}};
+use anchor_lang::prelude::*;
 
-use solana_program::pubkey::Pubkey;
+use crate::{instructions::{create_patient_escrow, record_appeal_outcome, settle_submission_bond}, states::{contexts::*, errors::*, events::*, BondOutcome, CaseStatus, PatientCase, RoundOutcome, format_case_id}};
 
-use crate::states::{contexts::*, errors::*, constants::*, events::*};
 
 
 
+pub fn admin_override_case<'info>(ctx: Context<'_, '_, '_, 'info, AdminOverrideCase<'info>>, _case_id: String, is_verified: bool) -> Result<()> {
 
-pub fn admin_override_case(ctx: Context<AdminOverrideCase>, case_id: String, is_verified: bool) -> Result<()> {
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
     let patient_case = &mut ctx.accounts.patient_case;
 
-    // Only allow after 10 days from submission time
+    // Only allow after 10 days from submission time, not counting time spent waiting on the patient
     let now = Clock::get()?.unix_timestamp;
     require!(
-        now >= patient_case.submission_time + ALLOWED_VERIFICATION_TIME as i64, 
+        now >= patient_case.verification_deadline(now)?,
         CuraChainError::VerifiersVerificationActive
     );
 
     // Case shouldn't already be verified
     require!(!patient_case.is_verified, CuraChainError::CaseAlreadyVerified);
 
+    // A Split Vote With The Senior Panel, Or A Passed Vote Waiting On Multisig Co-Approval, Is Not The Admin's To Decide
+    if let Some(escalation) = &patient_case.escalation {
+        require!(escalation.resolution.is_some(), CuraChainError::CaseEscalated);
+        return err!(CuraChainError::EscalationDecisionFinal);
+    }
+    require!(!patient_case.awaiting_co_approval, CuraChainError::CaseAwaitingCoApproval);
+
     // Set the verification status based on admin decision
     patient_case.is_verified = is_verified;
 
+    // The Admin's Decision Settles Any Question Still Waiting On The Patient
+    if patient_case.info_request.take().is_some() {
+        patient_case.case_status = CaseStatus::PendingVerification;
+    }
+    let appeal_outcome = record_override_verdict(patient_case, is_verified, now);
+
     msg!("[ADMIN OVERRIDE] Case ID: {}", case_id);
     msg!("[ADMIN OVERRIDE] Patient case: {}", ctx.accounts.patient_case.key());
     msg!("[ADMIN OVERRIDE] Verification status set to: {}", is_verified);
 
+    // Approving Refunds The Submission Bond; Rejecting Flags The Case And Forfeits It
+    let (bond_outcome, bond_recipient) = if is_verified {
+        (BondOutcome::Refunded, ctx.accounts.patient_case.rent_payer())
+    } else {
+        (BondOutcome::Forfeited, ctx.accounts.protocol_config.bond_forfeit_pool)
+    };
+    settle_submission_bond(
+        &mut ctx.accounts.patient_case,
+        bond_outcome,
+        bond_recipient,
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.bond_recipient.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    // An Appealed Case Records How Its Second Round Ended, Unless The Verifiers' Rejection Already Did
+    if let Some(outcome) = appeal_outcome {
+        record_appeal_outcome(&ctx.accounts.patient_case, ctx.accounts.case_appeal.as_mut(), outcome, now)?;
+    }
+
     // If the admin approves the case, create an escrow account
     if is_verified {
-        create_escrow_pda(ctx)?;
+        create_patient_escrow(
+            &ctx.accounts.patient_case,
+            &mut ctx.accounts.case_lookup,
+            &ctx.accounts.patient_escrow,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
     }
 
     // Emit event for tracking admin override actions
@@ -53,59 +93,64 @@ pub fn admin_override_case(ctx: Context<AdminOverrideCase>, case_id: String, is_
 }
 
 
-fn create_escrow_pda(ctx: Context<AdminOverrideCase>) -> Result<()> {
-    
-    let patient_case_key = ctx.accounts.patient_case.key();
-    let case_id_lookup = &mut ctx.accounts.case_lookup;
+// An Approval Lifts Any Earlier Rejection. A Rejection Is Recorded Just As A Verifier Rejection Is, So The Appeal
+// Window Starts And The Case Can Later Be Closed, But One Already Recorded Keeps Its Time And Window.
+// Returns The Outcome Still To Be Recorded On The Case's Appeal, If Any
+fn record_override_verdict(patient_case: &mut PatientCase, is_verified: bool, now: i64) -> Option<RoundOutcome> {
+    if is_verified {
+        patient_case.case_status = CaseStatus::Verified;
+        patient_case.rejected_at = None;
+        return Some(RoundOutcome::Verified);
+    }
 
-    // Get Escrow PDA address using find_program_address
-    let (patient_escrow_pda, _patient_escrow_bump) = Pubkey::find_program_address(
-        &[b"patient_escrow", ctx.accounts.patient_case.case_id.as_bytes(), patient_case_key.as_ref()],
-        ctx.program_id
-    );
+    if patient_case.rejected_at.is_some() {
+        return None;
+    }
+    patient_case.rejected_at = Some(now);
+    Some(RoundOutcome::Rejected)
+}
 
-    // Verify passed PDA account matches derived one
-    require!(
-        *ctx.accounts.patient_escrow.key == patient_escrow_pda, CuraChainError::InvalidEscrowPDA
-    );
-    
-    // Store the patient_escrow PDA bump in the case_lookup 
-    case_id_lookup.patient_escrow_bump = _patient_escrow_bump;
-
-    let rent = Rent::get()?;
-    let space = 0;
-    let lamports = rent.minimum_balance(space);
-
-    // Create the Escrow PDA Account, setting system_program as owner
-    let create_escrow_ix = solana_program::system_instruction::create_account(
-        &ctx.accounts.admin.key(),
-        &patient_escrow_pda,
-        lamports,
-        0,
-        &solana_program::system_program::ID,
-    );
 
-    let accounts_needed = &[
-        ctx.accounts.admin.to_account_info(),
-        ctx.accounts.patient_escrow.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
-    ];
-
-    let seeds = &[
-        b"patient_escrow",
-        ctx.accounts.patient_case.case_id.as_bytes().as_ref(),
-        patient_case_key.as_ref(),
-        &[_patient_escrow_bump],
-    ];
-
-    let signer_seeds = &[&seeds[..]];
-
-    solana_program::program::invoke_signed(
-        &create_escrow_ix,
-        accounts_needed,
-        signer_seeds
-    )?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::fixtures::{cast_vote, patient_case};
 
-    // Escrow PDA creation will throw an error if it fails
-    Ok(())
+    // Voted Down In Its Appeal Round, With The Rejection Already Recorded On The Appeal
+    fn rejected_on_appeal() -> PatientCase {
+        let mut case = patient_case();
+        case.verification_round = 2;
+        case.appeal = Some(Pubkey::new_unique());
+        cast_vote(&mut case, false);
+        case.rejected_at = Some(4_000);
+        case
+    }
+
+    #[test]
+    fn approving_a_rejected_case_lifts_the_rejection() {
+        let mut case = rejected_on_appeal();
+        case.is_verified = true;
+
+        assert_eq!(record_override_verdict(&mut case, true, 9_000), Some(RoundOutcome::Verified));
+        assert_eq!(case.rejected_at, None);
+        assert_eq!(case.case_status, CaseStatus::Verified);
+    }
+
+    #[test]
+    fn rejecting_a_rejected_case_keeps_its_appeal_window() {
+        let mut case = rejected_on_appeal();
+
+        // The Window Still Runs From The First Rejection, And The Appeal Already Holds Its Outcome
+        assert_eq!(record_override_verdict(&mut case, false, 9_000), None);
+        assert_eq!(case.rejected_at, Some(4_000));
+    }
+
+    #[test]
+    fn rejecting_an_undecided_case_starts_its_appeal_window() {
+        let mut case = patient_case();
+
+        assert_eq!(record_override_verdict(&mut case, false, 9_000), Some(RoundOutcome::Rejected));
+        assert_eq!(case.rejected_at, Some(9_000));
+        assert_eq!(case.case_status, CaseStatus::PendingVerification);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, AmendmentPolicy, ManifestRef, PatientCase, format_case_id};
+
+
+/*
+The Case Manager Can Fix A Case While It Is Still In Its First Verification Round And Not Yet Rejected.
+A Rejected Case Gets One Appeal Or Is Closed; Amending Must Not Hand It A Fresh Round Instead.
+If No Verifier Has Voted Yet, The Amendment Is Applied Straight Away. Otherwise The
+Protocol Config Decides: Either The Amendment Is Refused, Or All Votes Are Wiped
+And Verification Starts Over From The Time Of Amendment.
+A New Manifest Must Still Commit To The Records Fingerprint The Case Was Registered With.
+*/
+pub fn amend_patient_case(
+    ctx: Context<AmendPatientCase>,
+    _case_id: String,
+    new_manifest: Option<ManifestRef>,
+    new_total_amount_needed: Option<u64>,
+    new_manifest_body_hash: Option<[u8; 32]>)
+    -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let patient_case = &mut ctx.accounts.patient_case;
+    let amendment_policy = ctx.accounts.protocol_config.amendment_policy;
+
+    check_amendable(patient_case)?;
+
+    // Only Keep The Fields That Actually Change
+    let new_manifest = new_manifest.filter(|m| *m != patient_case.manifest);
+    let new_total_amount_needed = new_total_amount_needed.filter(|a| *a != patient_case.total_amount_needed);
+
+    require!(
+        new_manifest.is_some() || new_total_amount_needed.is_some(),
+        CuraChainError::EmptyAmendment
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let votes_reset = apply_amendment_policy(patient_case, amendment_policy, current_time)?;
+
+    let mut previous_manifest = None;
+    if let Some(manifest) = &new_manifest {
+        manifest.validate()?;
+
+        // An Amendment Rewords The Case; The Records Bundle It Was Registered With Stays The Same
+        let body_hash = new_manifest_body_hash.ok_or(CuraChainError::RecordsFingerprintMismatch)?;
+        manifest.verify_records_fingerprint(&body_hash, &patient_case.records_fingerprint)?;
+
+        previous_manifest = Some(std::mem::replace(&mut patient_case.manifest, manifest.clone()));
+    }
+
+    let mut previous_amount_needed = None;
+    if let Some(amount) = new_total_amount_needed {
+        require!(amount > 0, CuraChainError::NonZeroAmount);
+        previous_amount_needed = Some(std::mem::replace(&mut patient_case.total_amount_needed, amount));
+
+        // A New Amount Can Move The Case Into A Different Tier
+        patient_case.verification_tier = ctx.accounts.protocol_config.amount_tier(amount);
+    }
+
+    patient_case.amendment_count = patient_case.amendment_count.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE MANAGER AMENDS A CASE
+    let message = format!("Patient Case With ID, {} Has Been Amended For The {} Time At Time, {}",
+        case_id, patient_case.amendment_count, current_time);
+
+    emit!(PatientCaseAmended {
+        message,
+        case_id,
+        amendment_count: patient_case.amendment_count,
+        previous_manifest,
+        new_manifest,
+        previous_amount_needed,
+        new_amount_needed: new_total_amount_needed,
+        votes_reset,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+fn check_amendable(patient_case: &PatientCase) -> Result<()> {
+    // Verified Cases Are Already Open For Donations, So Their Terms Are Final
+    require!(!patient_case.is_verified, CuraChainError::CaseAlreadyVerified);
+
+    // A Case Sent To The Senior Panel Is Judged As Escalated, And Stays Judged Once The Panel Decides
+    require!(patient_case.escalation.is_none(), CuraChainError::CaseEscalated);
+
+    // Wiping The Votes On A Rejected Or Appealed Case Would Be A Second Appeal That Skips close_rejected_case
+    require!(patient_case.rejected_at.is_none(), CuraChainError::CaseRejected);
+    require!(patient_case.appeal.is_none(), CuraChainError::CaseUnderAppeal);
+    Ok(())
+}
+
+
+// Votes Already Cast Were Cast On The Old Version Of The Case: Refuse The Amendment, Or Wipe Them
+// And Give Verifiers A Fresh Window On The Amended Case. Returns Whether Votes Were Wiped
+fn apply_amendment_policy(patient_case: &mut PatientCase, amendment_policy: AmendmentPolicy, now: i64) -> Result<bool> {
+    if patient_case.voted_verifiers.is_empty() {
+        return Ok(false);
+    }
+    require!(amendment_policy == AmendmentPolicy::ResetVotes, CuraChainError::AmendmentAfterVotes);
+
+    patient_case.clear_votes();
+    patient_case.restart_verification_clock(now);
+    Ok(true)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{fixtures::{cast_vote, patient_case}, CaseStatus, InfoRequest};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn unvoted_case_keeps_its_window_under_either_policy() {
+        for policy in [AmendmentPolicy::RejectIfVoted, AmendmentPolicy::ResetVotes] {
+            let mut case = patient_case();
+            let deadline = case.verification_deadline(5_000).unwrap();
+
+            assert!(!apply_amendment_policy(&mut case, policy, 5_000).unwrap());
+            assert_eq!(case.verification_deadline(5_000).unwrap(), deadline);
+        }
+    }
+
+    #[test]
+    fn reject_if_voted_leaves_the_voted_case_untouched() {
+        let mut case = patient_case();
+        cast_vote(&mut case, true);
+        let before = case.clone();
+
+        assert_eq!(
+            apply_amendment_policy(&mut case, AmendmentPolicy::RejectIfVoted, 5_000).unwrap_err(),
+            error!(CuraChainError::AmendmentAfterVotes)
+        );
+        assert_eq!(case.voted_verifiers, before.voted_verifiers);
+        assert_eq!(case.submission_time, before.submission_time);
+    }
+
+    #[test]
+    fn votes_on_the_old_version_do_not_count_against_the_amended_case() {
+        let mut case = patient_case();
+        let early_no_voter = cast_vote(&mut case, false);
+        cast_vote(&mut case, false);
+
+        // Amended On The Last Day Of The Window
+        let amended_at = case.submission_time + case.verification_window - DAY;
+        assert!(apply_amendment_policy(&mut case, AmendmentPolicy::ResetVotes, amended_at).unwrap());
+
+        // The Same Verifier May Vote Again, On What The Case Now Says, With A Full Window To Do So
+        assert!(!case.voted_verifiers.contains(&early_no_voter));
+        assert!(case.voter_weights.is_empty());
+        assert_eq!((case.yes_weight, case.no_weight), (0, 0));
+        assert_eq!(case.verification_deadline(amended_at).unwrap(), amended_at + case.verification_window);
+    }
+
+    #[test]
+    fn co_approval_given_on_the_old_version_is_dropped() {
+        // The Vote Passed And One Multisig Member Had Already Co-Approved When The Case Was Changed
+        let mut case = patient_case();
+        cast_vote(&mut case, true);
+        case.awaiting_co_approval = true;
+        case.co_approvals.push(Pubkey::new_unique());
+
+        assert!(apply_amendment_policy(&mut case, AmendmentPolicy::ResetVotes, 5_000).unwrap());
+        assert!(!case.awaiting_co_approval);
+        assert!(case.co_approvals.is_empty());
+    }
+
+    #[test]
+    fn open_question_only_pauses_the_new_window_from_the_amendment() {
+        // A Verifier Asked Early On, Voted, And The Patient Amends The Case Instead Of Answering
+        let mut case = patient_case();
+        cast_vote(&mut case, false);
+        let asked_at = case.submission_time + DAY;
+        case.info_request = Some(InfoRequest { question_hash: [9u8; 32], requested_by: Pubkey::new_unique(), requested_at: asked_at });
+        case.case_status = CaseStatus::InfoRequested;
+
+        let amended_at = asked_at + 5 * DAY;
+        assert!(apply_amendment_policy(&mut case, AmendmentPolicy::ResetVotes, amended_at).unwrap());
+
+        // The Five Days Before The Amendment Belonged To The Old Window, So They Do Not Extend The New One
+        assert_eq!(case.verification_deadline(amended_at).unwrap(), amended_at + case.verification_window);
+        assert_eq!(case.case_status, CaseStatus::InfoRequested);
+    }
+
+    // First Round Voted The Case Down: Three No Votes Out Of Three, Rejection Recorded
+    fn rejected_case() -> PatientCase {
+        let mut case = patient_case();
+        for _ in 0..3 {
+            cast_vote(&mut case, false);
+        }
+        case.rejected_at = Some(4_000);
+        case
+    }
+
+    #[test]
+    fn rejected_case_cannot_be_amended_into_a_fresh_round() {
+        let case = rejected_case();
+
+        // Even Under ResetVotes, Which Would Otherwise Wipe The No Votes
+        assert_eq!(check_amendable(&case).unwrap_err(), error!(CuraChainError::CaseRejected));
+
+        // The Rejection Stands, So The Appeal Window And close_rejected_case Still Run From It
+        assert_eq!(case.rejected_at, Some(4_000));
+        assert_eq!(case.verification_no_votes, 3);
+    }
+
+    #[test]
+    fn appealed_case_cannot_be_amended_during_or_after_its_second_round() {
+        // appeal_case Opens Round Two: Votes Cleared, Rejection Lifted, Appeal Recorded
+        let mut case = rejected_case();
+        case.clear_votes();
+        case.rejected_at = None;
+        case.verification_round = 2;
+        case.appeal = Some(Pubkey::new_unique());
+        assert_eq!(check_amendable(&case).unwrap_err(), error!(CuraChainError::CaseUnderAppeal));
+
+        // Rejected Again In Round Two: Only close_rejected_case Is Left
+        cast_vote(&mut case, false);
+        case.rejected_at = Some(9_000);
+        assert_eq!(check_amendable(&case).unwrap_err(), error!(CuraChainError::CaseRejected));
+    }
+
+    #[test]
+    fn first_round_case_with_split_votes_can_still_be_amended() {
+        let mut case = patient_case();
+        cast_vote(&mut case, true);
+        cast_vote(&mut case, false);
+
+        assert!(check_amendable(&case).is_ok());
+    }
+}
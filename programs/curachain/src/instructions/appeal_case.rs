@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, format_case_id, CaseAppeal, ManifestRef, PatientCase, RecusedVerifier, RoundOutcome, RoundResult};
+
+
+/*
+A Rejected Case Gets One Appeal, Filed By The Patient Or Their Case Manager Within The Configured Window.
+1. The Appeal Attaches New Evidence, And The First-Round Tally Is Archived In The CaseAppeal PDA.
+2. The Votes Are Wiped And A Second Verification Window Opens From The Time Of Appeal.
+3. Verifiers Who Voted In The First Round Are Recused, As Long As Others Remain To Hear The Appeal.
+4. How The Second Round Ends Is Written Back To The CaseAppeal PDA By Verification Or Closure.
+*/
+pub fn appeal_patient_case(ctx: Context<AppealPatientCase>, _case_id: String, evidence: ManifestRef) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    require!(!patient_case.is_verified, CuraChainError::CaseAlreadyVerified);
+    require!(patient_case.verification_round == 1, CuraChainError::AlreadyAppealed);
+
+    // The Senior Panel's Decision On A Split Vote Is Final
+    require!(patient_case.escalation.is_none(), CuraChainError::EscalationDecisionFinal);
+
+    // Only A Case The Verifiers Actually Turned Down Can Be Appealed, And Only Within The Window
+    let rejected_at = patient_case.rejected_at.ok_or(CuraChainError::CaseNotRejected)?;
+    let appeal_deadline = rejected_at
+        .checked_add(ctx.accounts.protocol_config.appeal_window).ok_or(CuraChainError::OverflowError)?;
+    require!(current_time < appeal_deadline, CuraChainError::AppealWindowClosed);
+
+    evidence.validate()?;
+
+    let first_round = RoundResult {
+        yes_votes: patient_case.verification_yes_votes,
+        no_votes: patient_case.verification_no_votes,
+        outcome: RoundOutcome::Rejected,
+        concluded_at: rejected_at,
+    };
+
+    // The Registry Holds Verifier PDAs While Votes Are Recorded By Wallet, So Map Each Voter To Its PDA
+    let first_round_verifiers: Vec<RecusedVerifier> = patient_case.voted_verifiers
+        .iter()
+        .zip(patient_case.voter_weights.iter())
+        .map(|(voter, vote_weight)| RecusedVerifier {
+            verifier: Pubkey::find_program_address(&[b"verifier_role", voter.as_ref()], ctx.program_id).0,
+            vote_weight: *vote_weight,
+        })
+        .collect();
+
+    let verifiers_list = &ctx.accounts.verifiers_list;
+    let fresh_verifiers = verifiers_list.all_verifiers
+        .iter()
+        .filter(|verifier| !first_round_verifiers.iter().any(|recused| recused.verifier == **verifier))
+        .count();
+
+    // Recusal Would Leave Nobody To Vote, So The Same Panel Hears The Appeal
+    let recusal_applied = fresh_verifiers > 0;
+    if recusal_applied {
+        patient_case.recused_verifiers = first_round_verifiers;
+    }
+
+    // Start The Second Round From A Clean Slate
+    patient_case.clear_votes();
+    patient_case.restart_verification_clock(current_time);
+    patient_case.rejected_at = None;
+    patient_case.verification_round = 2;
+    patient_case.appeal = Some(ctx.accounts.case_appeal.key());
+
+    ctx.accounts.case_appeal.set_inner(CaseAppeal {
+        case_id: patient_case.case_id,
+        evidence: evidence.clone(),
+        appealed_at: current_time,
+        first_round,
+        second_round: None,
+        recusal_applied,
+        appeal_bump: ctx.bumps.case_appeal,
+    });
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A REJECTED CASE IS APPEALED
+    let message = format!("Patient Case With ID, {} Has Been Appealed By, {} At Time, {}",
+        case_id, ctx.accounts.appellant.key(), current_time);
+
+    emit!(CaseAppealed {
+        message,
+        case_id,
+        evidence,
+        first_round,
+        recusal_applied,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// Writes The Second-Round Result Of An Appealed Case; Cases Still In Their First Round Are Left As They Are
+pub fn record_appeal_outcome(
+    patient_case: &PatientCase,
+    case_appeal: Option<&mut Account<CaseAppeal>>,
+    outcome: RoundOutcome,
+    concluded_at: i64)
+    -> Result<()> {
+
+    let Some(appeal_key) = patient_case.appeal else {
+        return Ok(());
+    };
+
+    let case_appeal = case_appeal.ok_or(CuraChainError::AppealAccountRequired)?;
+    require!(case_appeal.key() == appeal_key, CuraChainError::AppealAccountRequired);
+
+    let second_round = RoundResult {
+        yes_votes: patient_case.verification_yes_votes,
+        no_votes: patient_case.verification_no_votes,
+        outcome,
+        concluded_at,
+    };
+    case_appeal.second_round = Some(second_round);
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME AN APPEAL ROUND CONCLUDES
+    let case_id = format_case_id(patient_case.case_id);
+    let message = format!("Appeal Of Patient Case With ID, {} Ended As {:?} At Time, {}",
+        case_id, outcome, concluded_at);
+
+    emit!(AppealRoundConcluded {
+        message,
+        case_id,
+        first_round: case_appeal.first_round,
+        second_round,
+        timestamp: concluded_at,
+    });
+
+    Ok(())
+}
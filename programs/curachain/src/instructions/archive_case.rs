@@ -0,0 +1,261 @@
+use anchor_lang::{prelude::*, solana_program};
+
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount};
+
+use crate::states::{contexts::*, errors::*, events::*, CaseOutcome, CaseReceipt, CaseStatus, MintTotal, PatientCase, NATIVE_SOL_ADDRESS, format_case_id};
+
+
+/*
+Once Everything Raised For A Case Has Been Released, The Full PatientCase No Longer Needs To Stay On-Chain.
+1. A Compact CaseReceipt Keeps The Totals Per Mint, Every Release, The Outcome And The Key Timestamps.
+2. The PatientCase And CaseIDLookup Are Closed, Their Rent Going Back To The Submitter.
+3. The Escrow And The Now Empty Token Vaults Are Closed, Their Rent Going Back To Whoever Created Them.
+*/
+pub fn finalize_and_archive_case<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeAndArchiveCase<'info>>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let case_id_seed = ctx.accounts.patient_case.case_id.to_le_bytes();
+
+    let patient_case = &ctx.accounts.patient_case;
+
+    // Only Cases That Paid Out Everything They Held Can Be Archived
+    require!(patient_case.is_verified, CuraChainError::CaseNotYetVerified);
+    require!(patient_case.case_status != CaseStatus::Refunding, CuraChainError::CaseRefunding);
+    require!(patient_case.total_released > 0 && !patient_case.has_donations(), CuraChainError::CaseNotFullyReleased);
+
+    // Close Every Token Vault, Paying Its Rent Back To The Donor Who Created It
+    require!(
+        ctx.remaining_accounts.len() == patient_case.spl_donations.len() * 2,
+        CuraChainError::InvalidRemainingVaults
+    );
+
+    let multisig_seeds: &[&[u8]] = &[
+        b"multisig",
+        b"escrow-authority".as_ref(),
+        &[ctx.accounts.multisig.multisig_bump]
+    ];
+
+    for (each_spl_donation, vault_accounts) in patient_case.spl_donations.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let patient_token_vault = &vault_accounts[0];
+        let vault_payer = &vault_accounts[1];
+
+        require!(patient_token_vault.key() == each_spl_donation.patient_token_vault, CuraChainError::InvalidRemainingVaults);
+        require!(vault_payer.key() == each_spl_donation.vault_payer, CuraChainError::InvalidVaultPayer);
+
+        let vault = TokenAccount::try_deserialize(&mut &patient_token_vault.try_borrow_data()?[..])?;
+        require!(vault.amount == 0, CuraChainError::TokenVaultNotEmpty);
+
+        let close_accounts = CloseAccount {
+            account: patient_token_vault.clone(),
+            destination: vault_payer.clone(),
+            authority: ctx.accounts.multisig.to_account_info(),
+        };
+        let signer_seeds = &[multisig_seeds];
+        let close_cpi = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), close_accounts, signer_seeds);
+        close_account(close_cpi)?;
+    }
+
+    // Empty The Escrow Into Whoever Paid For It; An Account Left With No Lamports Is Removed
+    let escrow_balance = ctx.accounts.patient_escrow.lamports();
+    if escrow_balance > 0 {
+        let patient_case_key = patient_case.key();
+        let seeds = &[
+            b"patient_escrow",
+            case_id_seed.as_ref(),
+            patient_case_key.as_ref(),
+            &[ctx.accounts.case_lookup.patient_escrow_bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ix = solana_program::system_instruction::transfer(
+            &ctx.accounts.patient_escrow.key(),
+            &ctx.accounts.escrow_payer.key(),
+            escrow_balance
+        );
+
+        solana_program::program::invoke_signed(
+            &transfer_ix,
+            &[
+                ctx.accounts.patient_escrow.to_account_info(),
+                ctx.accounts.escrow_payer.to_account_info(),
+                ctx.accounts.system_program.to_account_info()
+            ],
+            signer_seeds
+        )?;
+    }
+
+    // The Records Are Free To Be Filed Again Once No Other Case Holds Them
+    ctx.accounts.records_fingerprint.release_case(patient_case.case_id);
+
+    // Write The Receipt; PatientCase And CaseIDLookup Are Closed Once The Instruction Ends
+    let current_time = Clock::get()?.unix_timestamp;
+    let receipt = build_receipt(patient_case, current_time, ctx.bumps.case_receipt)?;
+    let outcome = receipt.outcome;
+
+    ctx.accounts.case_receipt.set_inner(receipt);
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE IS ARCHIVED
+    let message = format!("Patient Case With ID, {} Has Been Archived After Releasing, {} At Time, {}",
+        case_id, patient_case.total_released, current_time);
+
+    emit!(CaseArchived {
+        message,
+        case_id,
+        receipt: ctx.accounts.case_receipt.key(),
+        outcome,
+        total_released: patient_case.total_released,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// The Compact Record Of A Case That Outlives The PatientCase
+fn build_receipt(patient_case: &PatientCase, archived_at: i64, receipt_bump: u8) -> Result<CaseReceipt> {
+    Ok(CaseReceipt {
+        case_id: patient_case.case_id,
+        beneficiary: patient_case.patient_pubkey,
+        submitter: patient_case.submitter,
+        sponsor: patient_case.sponsor,
+        facility: patient_case.facility,
+        manifest_hash: patient_case.manifest.hash,
+        outcome: case_outcome(patient_case),
+        mint_totals: mint_totals(patient_case)?,
+        releases: patient_case.releases.clone(),
+        submitted_at: patient_case.submission_time,
+        funding_deadline: patient_case.funding_deadline,
+        archived_at,
+        receipt_bump,
+    })
+}
+
+
+fn case_outcome(patient_case: &PatientCase) -> CaseOutcome {
+    if patient_case.case_status == CaseStatus::Redirecting {
+        CaseOutcome::Redirected
+    } else if patient_case.goal_reached {
+        CaseOutcome::Funded
+    } else {
+        CaseOutcome::PartiallyFunded
+    }
+}
+
+
+// SOL First, Then Each SPL Mint, With What Was Released In It Summed From The Release Records
+fn mint_totals(patient_case: &PatientCase) -> Result<Vec<MintTotal>> {
+    let contributions = std::iter::once((NATIVE_SOL_ADDRESS, patient_case.total_sol_contributed))
+        .chain(patient_case.spl_donations.iter().map(|donation| (donation.mint, donation.total_contributed)));
+
+    let mut totals = Vec::new();
+    for (mint, total_contributed) in contributions {
+        let mut total_released: u64 = 0;
+        for release in patient_case.releases.iter().filter(|release| release.mint == mint) {
+            total_released = total_released.checked_add(release.amount).ok_or(CuraChainError::OverflowError)?;
+        }
+
+        totals.push(MintTotal { mint, total_contributed, total_released });
+    }
+    Ok(totals)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{fixtures::patient_case, ReleaseRecord, SplDonations};
+
+    fn release(mint: Pubkey, amount: u64, proposal_index: u64) -> ReleaseRecord {
+        ReleaseRecord { proposal_index, invoice: Pubkey::new_unique(), mint, amount, slot: 100 + proposal_index, released_at: 5_000 }
+    }
+
+    fn spl_donation(mint: Pubkey, total_contributed: u64) -> SplDonations {
+        SplDonations {
+            mint,
+            total_mint_amount: 0,
+            patient_token_vault: Pubkey::new_unique(),
+            total_contributed,
+            vault_payer: Pubkey::new_unique(),
+        }
+    }
+
+    // A Verified Case That Raised SOL And One Token, And Has Released Both In Full
+    fn released_case() -> (PatientCase, Pubkey) {
+        let usdc = Pubkey::new_unique();
+        let mut case = patient_case();
+        case.is_verified = true;
+        case.case_status = CaseStatus::Verified;
+        case.facility = Some(Pubkey::new_unique());
+        case.total_sol_contributed = 3_000;
+        case.spl_donations = vec![spl_donation(usdc, 900)];
+        case.releases = vec![release(NATIVE_SOL_ADDRESS, 1_000, 0), release(usdc, 900, 1), release(NATIVE_SOL_ADDRESS, 2_000, 2)];
+        case.total_released = 3_900;
+        (case, usdc)
+    }
+
+    #[test]
+    fn outcome_follows_status_then_goal() {
+        let mut case = patient_case();
+        assert_eq!(case_outcome(&case), CaseOutcome::PartiallyFunded);
+
+        case.goal_reached = true;
+        assert_eq!(case_outcome(&case), CaseOutcome::Funded);
+
+        // A Redirected Case Is Reported As Such Even If It Met Its Goal
+        case.case_status = CaseStatus::Redirecting;
+        assert_eq!(case_outcome(&case), CaseOutcome::Redirected);
+    }
+
+    #[test]
+    fn mint_totals_list_sol_first_and_sum_releases_per_mint() {
+        let (case, usdc) = released_case();
+
+        assert_eq!(mint_totals(&case).unwrap(), vec![
+            MintTotal { mint: NATIVE_SOL_ADDRESS, total_contributed: 3_000, total_released: 3_000 },
+            MintTotal { mint: usdc, total_contributed: 900, total_released: 900 },
+        ]);
+    }
+
+    #[test]
+    fn mint_totals_keep_mints_with_nothing_released() {
+        let usdt = Pubkey::new_unique();
+        let mut case = patient_case();
+        case.spl_donations = vec![spl_donation(usdt, 0)];
+
+        assert_eq!(mint_totals(&case).unwrap(), vec![
+            MintTotal { mint: NATIVE_SOL_ADDRESS, total_contributed: 0, total_released: 0 },
+            MintTotal { mint: usdt, total_contributed: 0, total_released: 0 },
+        ]);
+    }
+
+    #[test]
+    fn mint_totals_refuse_to_overflow() {
+        let mut case = patient_case();
+        case.releases = vec![release(NATIVE_SOL_ADDRESS, u64::MAX, 0), release(NATIVE_SOL_ADDRESS, 1, 1)];
+
+        assert_eq!(mint_totals(&case).unwrap_err(), error!(CuraChainError::OverflowError));
+    }
+
+    #[test]
+    fn receipt_carries_the_case_record() {
+        let (mut case, _) = released_case();
+        case.goal_reached = true;
+        let sponsor = Pubkey::new_unique();
+        case.sponsor = Some(sponsor);
+
+        let receipt = build_receipt(&case, 9_000, 254).unwrap();
+
+        assert_eq!(receipt.case_id, case.case_id);
+        assert_eq!(receipt.beneficiary, case.patient_pubkey);
+        assert_eq!(receipt.submitter, case.submitter);
+        assert_eq!(receipt.sponsor, Some(sponsor));
+        assert_eq!(receipt.facility, case.facility);
+        assert_eq!(receipt.manifest_hash, case.manifest.hash);
+        assert_eq!(receipt.outcome, CaseOutcome::Funded);
+        assert_eq!(receipt.mint_totals, mint_totals(&case).unwrap());
+        assert_eq!(receipt.releases, case.releases);
+        assert_eq!(receipt.submitted_at, case.submission_time);
+        assert_eq!(receipt.funding_deadline, case.funding_deadline);
+        assert_eq!((receipt.archived_at, receipt.receipt_bump), (9_000, 254));
+    }
+}
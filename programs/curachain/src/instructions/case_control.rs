@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, format_case_id, PatientCase};
+
+
+/*
+A Beneficiary Whose Case Was Submitted By A Guardian Or Organization Can Take It Over.
+From Then On Only The Beneficiary Can Amend, Withdraw Or Share Records For The Case.
+The Original Submitter Stays Recorded, And Still Receives The Rent If The Case Is Closed.
+*/
+pub fn assume_case_control(ctx: Context<AssumeCaseControl>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let beneficiary = ctx.accounts.beneficiary.key();
+    let previous_manager = take_over_case(&mut ctx.accounts.patient_case, beneficiary)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A BENEFICIARY TAKES OVER THEIR CASE
+    let message = format!("Beneficiary, {} Has Taken Over Patient Case With ID, {} From, {} At Time, {}",
+        beneficiary, case_id, previous_manager, current_time);
+
+    emit!(CaseControlAssumed {
+        message,
+        case_id,
+        beneficiary,
+        previous_manager,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// Hands The Case To The Beneficiary, Leaving The Submitter And Sponsor Untouched. Returns The Previous Manager
+fn take_over_case(patient_case: &mut PatientCase, beneficiary: Pubkey) -> Result<Pubkey> {
+    require!(patient_case.case_manager != beneficiary, CuraChainError::AlreadyCaseManager);
+
+    Ok(std::mem::replace(&mut patient_case.case_manager, beneficiary))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::fixtures::patient_case;
+
+    // A Case Submitted By A Sponsoring Guardian On The Patient's Behalf
+    fn guardian_submitted_case() -> PatientCase {
+        let mut case = patient_case();
+        let guardian = Pubkey::new_unique();
+        case.submitter = guardian;
+        case.case_manager = guardian;
+        case.sponsor = Some(guardian);
+        case
+    }
+
+    #[test]
+    fn beneficiary_takes_over_from_the_guardian() {
+        let mut case = guardian_submitted_case();
+        let guardian = case.submitter;
+        let beneficiary = case.patient_pubkey;
+
+        assert_eq!(take_over_case(&mut case, beneficiary).unwrap(), guardian);
+        assert_eq!(case.case_manager, beneficiary);
+
+        // The Guardian Stays On Record And Still Gets The Rent Back
+        assert_eq!(case.submitter, guardian);
+        assert_eq!(case.rent_payer(), guardian);
+    }
+
+    #[test]
+    fn taking_over_twice_is_rejected() {
+        let mut case = guardian_submitted_case();
+        let beneficiary = case.patient_pubkey;
+        take_over_case(&mut case, beneficiary).unwrap();
+
+        assert_eq!(take_over_case(&mut case, beneficiary).unwrap_err(), error!(CuraChainError::AlreadyCaseManager));
+        assert_eq!(case.case_manager, beneficiary);
+    }
+
+    #[test]
+    fn self_submitted_case_is_already_managed_by_the_patient() {
+        let mut case = patient_case();
+        let patient = case.patient_pubkey;
+
+        assert_eq!(take_over_case(&mut case, patient).unwrap_err(), error!(CuraChainError::AlreadyCaseManager));
+    }
+}
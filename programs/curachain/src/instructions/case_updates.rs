@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, contexts::*, errors::*, events::*, format_case_id, ProgressUpdate, UpdateType};
+
+
+/*
+Donors Follow A Case After Giving Through Its Progress Timeline.
+1. The Patient, The Case Manager Or The Bound Facility Posts An Update: A Content Hash, A URI And What Happened.
+2. Updates Are Stored In Order Across CaseUpdate Pages Of MAX_UPDATES_PER_PAGE, So No Single Account Grows Unbounded.
+3. Every Post Emits An Event, So Donor-Facing Apps Can Render The Timeline Without Polling The Pages.
+*/
+pub fn post_case_update(
+    ctx: Context<PostCaseUpdate>,
+    _case_id: String,
+    update_type: UpdateType,
+    content_hash: [u8; 32],
+    uri: String)
+    -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let poster = ctx.accounts.poster.key();
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    // The Facility Can Only Post If It Is The One The Case Is Bound To
+    let is_bound_facility = match &ctx.accounts.facility {
+        Some(facility) => patient_case.facility == Some(facility.key()),
+        None => false,
+    };
+    require!(
+        poster == patient_case.patient_pubkey || poster == patient_case.case_manager || is_bound_facility,
+        CuraChainError::UnauthorizedUpdatePoster
+    );
+
+    // Updates Tell Donors What Happened After They Gave, So Only Verified Cases Have A Timeline
+    require!(patient_case.is_verified, CuraChainError::CaseNotYetVerified);
+
+    require!(content_hash != [0u8; 32], CuraChainError::EmptyContentHash);
+    require!(!uri.is_empty() && uri.len() <= MAX_MANIFEST_URI_LEN, CuraChainError::InvalidUpdateUri);
+
+    let update_index = patient_case.update_count;
+    let page_index = update_index / MAX_UPDATES_PER_PAGE as u32;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // A Freshly Opened Page Is Stamped With Its Place In The Series
+    let case_update = &mut ctx.accounts.case_update;
+    if case_update.updates.is_empty() {
+        case_update.case_id = patient_case.case_id;
+        case_update.page_index = page_index;
+        case_update.page_bump = ctx.bumps.case_update;
+    }
+
+    case_update.updates.push(ProgressUpdate {
+        update_type,
+        content_hash,
+        uri: uri.clone(),
+        posted_by: poster,
+        posted_at: current_time,
+    });
+
+    patient_case.update_count = update_index.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE POSTS A PROGRESS UPDATE
+    let message = format!("Patient Case With ID, {} Has A New {:?} Update From, {} At Time, {}",
+        case_id, update_type, poster, current_time);
+
+    emit!(CaseProgressUpdated {
+        message,
+        case_id,
+        page_index,
+        update_index,
+        update_type,
+        content_hash,
+        uri,
+        posted_by: poster,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
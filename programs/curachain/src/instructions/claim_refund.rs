@@ -0,0 +1,144 @@
+use anchor_lang::{prelude::*, solana_program};
+
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+
+use crate::states::{contexts::*, errors::*, events::*, CaseStatus, NATIVE_SOL_ADDRESS, format_case_id};
+
+
+// Donor Reclaims Their Share Of The SOL Still Held In The Case Escrow
+pub fn claim_sol_refund(ctx: Context<ClaimSolRefund>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    require!(ctx.accounts.patient_case.case_status == CaseStatus::Refunding, CuraChainError::CaseNotRefunding);
+
+    let contributed = ctx.accounts.contribution.amount;
+    let refund_amount = pro_rata_refund(
+        contributed,
+        ctx.accounts.patient_case.total_sol_raised,
+        ctx.accounts.patient_case.total_sol_contributed
+    )?;
+
+    if refund_amount > 0 {
+        let patient_case_key = ctx.accounts.patient_case.key();
+        let case_id_seed = ctx.accounts.patient_case.case_id.to_le_bytes();
+        let seeds = &[
+            b"patient_escrow",
+            case_id_seed.as_ref(),
+            patient_case_key.as_ref(),
+            &[ctx.accounts.case_lookup.patient_escrow_bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ix = solana_program::system_instruction::transfer(
+            &ctx.accounts.patient_escrow.key(),
+            &ctx.accounts.donor.key(),
+            refund_amount
+        );
+
+        solana_program::program::invoke_signed(
+            &transfer_ix,
+            &[
+                ctx.accounts.patient_escrow.to_account_info(),
+                ctx.accounts.donor.to_account_info(),
+                ctx.accounts.system_program.to_account_info()
+            ],
+            signer_seeds
+        )?;
+    }
+
+    let patient_case = &mut ctx.accounts.patient_case;
+    patient_case.total_sol_raised = patient_case.total_sol_raised
+        .checked_sub(refund_amount).ok_or(CuraChainError::UnderflowError)?;
+    patient_case.total_sol_contributed = patient_case.total_sol_contributed
+        .checked_sub(contributed).ok_or(CuraChainError::UnderflowError)?;
+
+    emit_refund(ctx.accounts.donor.key(), NATIVE_SOL_ADDRESS, refund_amount, case_id)
+}
+
+
+// Donor Reclaims Their Share Of One SPL Token Still Held In The Case Token Vault
+pub fn claim_token_refund(ctx: Context<ClaimTokenRefund>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    require!(ctx.accounts.patient_case.case_status == CaseStatus::Refunding, CuraChainError::CaseNotRefunding);
+
+    let mint = ctx.accounts.donation_token.key();
+    let contributed = ctx.accounts.contribution.amount;
+
+    let spl_donation = ctx.accounts.patient_case
+        .spl_donations
+        .iter()
+        .find(|donation| donation.mint == mint)
+        .copied()
+        .ok_or(CuraChainError::TokenMismatched)?;
+
+    let refund_amount = pro_rata_refund(contributed, spl_donation.total_mint_amount, spl_donation.total_contributed)?;
+
+    if refund_amount > 0 {
+        let seeds = &[
+            b"multisig",
+            b"escrow-authority".as_ref(),
+            &[ctx.accounts.multisig.multisig_bump]
+        ];
+        let multisig_seeds = &[&seeds[..]];
+
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.patient_token_vault.to_account_info(),
+            mint: ctx.accounts.donation_token.to_account_info(),
+            to: ctx.accounts.donor_ata.to_account_info(),
+            authority: ctx.accounts.multisig.to_account_info()
+        };
+        let transfer_cpi = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, multisig_seeds);
+        transfer_checked(transfer_cpi, refund_amount, ctx.accounts.donation_token.decimals)?;
+    }
+
+    for entry in ctx.accounts.patient_case.spl_donations.iter_mut() {
+        if entry.mint == mint {
+            entry.total_mint_amount = entry.total_mint_amount
+                .checked_sub(refund_amount).ok_or(CuraChainError::UnderflowError)?;
+            entry.total_contributed = entry.total_contributed
+                .checked_sub(contributed).ok_or(CuraChainError::UnderflowError)?;
+            break;
+        }
+    }
+
+    emit_refund(ctx.accounts.donor.key(), mint, refund_amount, case_id)
+}
+
+
+// Refunds Are Pro-Rata: Each Donor Gets Their Share Of What Is Still Held, So Any Funds Already
+// Released To Treatment Are Borne By All Donors Alike. Paying One Refund Leaves The Ratio Of
+// held To outstanding Unchanged, So Claim Order Does Not Matter. The Last Claimant Sweeps Any Dust.
+fn pro_rata_refund(contributed: u64, held: u64, outstanding: u64) -> Result<u64> {
+    if contributed >= outstanding {
+        return Ok(held);
+    }
+
+    let refund = (contributed as u128)
+        .checked_mul(held as u128).ok_or(CuraChainError::OverflowError)?
+        .checked_div(outstanding as u128).ok_or(CuraChainError::OverflowError)?;
+
+    // contributed < outstanding, So refund < held And Always Fits In A u64
+    Ok(refund as u64)
+}
+
+
+fn emit_refund(donor_address: Pubkey, mint: Pubkey, refunded_amount: u64, case_id: String) -> Result<()> {
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A DONOR RECLAIMS A CONTRIBUTION
+    let current_time = Clock::get()?.unix_timestamp;
+    let message = format!("Donor {} Has Been Refunded An Amount Of {} From Patient Case Of ID {}", donor_address, refunded_amount, case_id);
+
+    emit!(DonationRefunded {
+        message,
+        donor_address,
+        mint,
+        refunded_amount,
+        case_id,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, events::*};
+use crate::{instructions::{record_appeal_outcome, settle_submission_bond}, states::{contexts::*, errors::*, events::*, format_case_id, BondOutcome, RejectedCaseTombstone, RoundOutcome}};
 
 
 /*
@@ -10,25 +10,77 @@ the 70% approval threshold was not Agreed Upon.
 NOTE BETTER::: We will implement the above checks as an access_control, not as anchor constraints, as 
 we don't want the checks to be done on the account level, but prior to even account-level validations*/
 
-pub fn close_rejected_case(ctx: Context<ClosePatientCase>, case_id: String) -> Result<()> {
-    
+pub fn close_rejected_case<'info>(ctx: Context<'_, '_, '_, 'info, ClosePatientCase<'info>>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Verifiers Rejected The Case, So Its Submission Bond Goes To The Forfeit Pool
+    let bond_forfeit_pool = ctx.accounts.protocol_config.bond_forfeit_pool;
+    settle_submission_bond(
+        &mut ctx.accounts.patient_case,
+        BondOutcome::Forfeited,
+        bond_forfeit_pool,
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.bond_recipient.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    // An Appealed Case Records That Its Second Round Failed Too
+    record_appeal_outcome(
+        &ctx.accounts.patient_case,
+        ctx.accounts.case_appeal.as_mut(),
+        RoundOutcome::Rejected,
+        current_time,
+    )?;
+
+    // The Records Are Free To Be Filed Again Once No Other Case Holds Them
+    ctx.accounts.records_fingerprint.release_case(ctx.accounts.patient_case.case_id);
 
-    let patient_case = &mut ctx.accounts.patient_case;
+    // Leave A Tombstone Behind, So The ID Reads As Rejected Rather Than Never Used
+    ctx.accounts.case_tombstone.set_inner(RejectedCaseTombstone {
+        case_id: ctx.accounts.patient_case.case_id,
+        rejected_at: current_time,
+        tombstone_bump: ctx.bumps.case_tombstone,
+    });
 
+    // The Caller Gets Back The Tombstone Rent They Fronted, Plus The Configured Share Of The Reclaimed Rent
+    let patient_case_info = ctx.accounts.patient_case.to_account_info();
     let user_closing = ctx.accounts.user.to_account_info();
 
-    // Let's Call The Solana Close instruction straight away, and no need to cpi into it.
-    patient_case.close(user_closing)?;
+    let reclaimed_rent = patient_case_info.lamports()
+        .checked_add(ctx.accounts.case_lookup.to_account_info().lamports()).ok_or(CuraChainError::OverflowError)?;
+
+    let caller_bounty = (reclaimed_rent as u128)
+        .checked_mul(ctx.accounts.protocol_config.rejected_close_bounty_bps as u128).ok_or(CuraChainError::OverflowError)?
+        .checked_div(10_000).ok_or(CuraChainError::OverflowError)? as u64;
+
+    let caller_share = std::cmp::min(
+        caller_bounty.checked_add(ctx.accounts.case_tombstone.to_account_info().lamports()).ok_or(CuraChainError::OverflowError)?,
+        patient_case_info.lamports()
+    );
+
+    **patient_case_info.try_borrow_mut_lamports()? = patient_case_info.lamports()
+        .checked_sub(caller_share).ok_or(CuraChainError::UnderflowError)?;
+    **user_closing.try_borrow_mut_lamports()? = user_closing.lamports()
+        .checked_add(caller_share).ok_or(CuraChainError::OverflowError)?;
+
+    // Whatever Is Left In The Case And Its Lookup Goes Back To The Submitter When Both Are Closed
+    let rent_returned = reclaimed_rent.checked_sub(caller_share).ok_or(CuraChainError::UnderflowError)?;
 
 
     // CATCHING THIS EVENT ON-CHAIN ANYTIME A REJECTED CASE IS CLOSED
-    let current_time = Clock::get()?.unix_timestamp;
     let message = format!("Patient Case with ID, {} Was Rejected And Is Therefore Being Closed At Time, {}", case_id, current_time);
 
     emit!(
         CloseRejectedPatientCase {
             message,
             case_id,
+            submitter: ctx.accounts.submitter.key(),
+            rent_returned,
+            closed_by: ctx.accounts.user.key(),
+            caller_bounty,
             timestamp: current_time,
         }
     );
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::complete_verification,
+    states::{contexts::*, errors::*, events::*, format_case_id}};
+
+
+/*
+Cases In An Amount Tier That Requires It Are Not Verified By Verifiers Alone.
+1. Once Verifiers Pass Such A Case, It Waits On Multisig Co-Approval And Takes No More Votes.
+2. Each Multisig Member Co-Approves Once; The Co-Approval Reaching The Multisig's Threshold Verifies The Case.
+3. Verification Then Completes As It Would From A Vote: The Bond Is Refunded And The Escrow Is Created.
+*/
+pub fn co_approve_verification<'info>(ctx: Context<'_, '_, '_, 'info, CoApproveVerification<'info>>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let multisig_member = ctx.accounts.multisig_member.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // The Multisig's Own Threshold, Capped At Its Member Count So Removing Members Cannot Strand A Case
+    let multisig = &ctx.accounts.multisig;
+    let threshold = multisig.required_threshold.min(multisig.multisig_members.len() as u8).max(1);
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    require!(!patient_case.is_verified, CuraChainError::CaseAlreadyVerified);
+    require!(patient_case.awaiting_co_approval, CuraChainError::CaseNotAwaitingCoApproval);
+    require!(!patient_case.co_approvals.contains(&multisig_member), CuraChainError::AlreadyCoApproved);
+
+    patient_case.co_approvals.push(multisig_member);
+    let co_approvals = patient_case.co_approvals.len() as u8;
+
+    let is_verified = co_approvals >= threshold;
+    if is_verified {
+        complete_verification(
+            patient_case,
+            &mut ctx.accounts.case_lookup,
+            &ctx.accounts.patient_escrow,
+            ctx.accounts.case_appeal.as_mut(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.bond_recipient.to_account_info(),
+            &ctx.accounts.multisig_member.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+    }
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A MULTISIG MEMBER CO-APPROVES A CASE
+    let message = format!("Multisig Member, {} Has Co-Approved Patient Case With ID, {} ({} Of {}) At Time, {}",
+        multisig_member, case_id, co_approvals, threshold, current_time);
+
+    emit!(CaseCoApproved {
+        message,
+        case_id,
+        multisig_member,
+        co_approvals,
+        is_verified,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -2,50 +2,179 @@
 
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, /*CuraChainError,*/ PatientCase, PatientCaseSubmission};
+use crate::{instructions::post_submission_bond, states::{contexts::*, format_case_id, PostedBond, CaseCounter, CaseIDLookup, CuraChainError, CaseStatus, DuplicateRecordsFlagged, FundingMode, UrgencyLevel, ManifestRef, PatientCase, PatientCaseSubmission, ProtocolConfig, RecordsFingerprint}};
 
-//use aes_gcm::{aead::{Aead, KeyInit}, Aes256Gcm, Nonce};
 
-//use base64::{Engine as _, engine::general_purpose};
+// Initialize Patient Case
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_patient<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializePatientCase<'info>>, 
+    manifest: ManifestRef, 
+    records_fingerprint: [u8; 32],
+    manifest_body_hash: [u8; 32],
+    total_amount_needed: u64,
+    campaign_duration: i64,
+    funding_mode: FundingMode,
+    urgency: UrgencyLevel) 
+    -> Result<()> {
 
+        // A Patient Submitting For Themselves Is Both Beneficiary And Submitter
+        let patient = ctx.accounts.patient.key();
 
+        // A Separate Payer Sponsors The Submission, So The Patient Only Has To Sign
+        let payer = ctx.accounts.payer.key();
+        let sponsor = (payer != patient).then_some(payer);
 
-// Initialize Patient Case
-pub fn initialize_patient(
-    ctx: Context<InitializePatientCase>, 
-    case_description: String, 
+        let submission_bond = post_submission_bond(
+            ctx.accounts.case_counter.current_id.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+            &ctx.accounts.protocol_config,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            ctx.bumps.bond_vault,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+
+        let duplicate_of = register_records_fingerprint(
+            &mut ctx.accounts.records_fingerprint,
+            ctx.bumps.records_fingerprint,
+            records_fingerprint,
+            &manifest,
+            manifest_body_hash,
+            ctx.accounts.case_counter.current_id.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+        )?;
+
+        open_patient_case(
+            &mut ctx.accounts.patient_case,
+            ctx.bumps.patient_case,
+            &mut ctx.accounts.case_counter,
+            &mut ctx.accounts.case_lookup,
+            ctx.bumps.case_lookup,
+            &ctx.accounts.protocol_config,
+            CaseSubmission { beneficiary: patient, submitter: patient, sponsor, manifest, records_fingerprint, duplicate_of,
+                total_amount_needed, campaign_duration, funding_mode, urgency, submission_bond },
+        )
+    }
+
+
+/*
+A Guardian, Hospital Social Worker Or NGO Submits A Case On Behalf Of A Beneficiary.
+1. The Case Is Keyed By The Beneficiary, Exactly Like A Self-Submitted Case.
+2. The Submitter Pays The Rent And Manages The Case: Amending, Withdrawing And Sharing Records.
+3. The Beneficiary Can Take Over Management At Any Time With assume_case_control.
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_represented_patient<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeRepresentedPatientCase<'info>>,
+    beneficiary: Pubkey,
+    manifest: ManifestRef,
+    records_fingerprint: [u8; 32],
+    manifest_body_hash: [u8; 32],
     total_amount_needed: u64,
-    link_to_records: String) 
+    campaign_duration: i64,
+    funding_mode: FundingMode,
+    urgency: UrgencyLevel)
     -> Result<()> {
 
-        // Let's get the account
-        let patient_details = &mut ctx.accounts.patient_case;
-        let case_id_counter = &mut ctx.accounts.case_counter;
-        let case_id_lookup = &mut ctx.accounts.case_lookup;
+        let submitter = ctx.accounts.submitter.key();
+        require!(beneficiary != submitter, CuraChainError::BeneficiaryIsSubmitter);
+
+        // The Representative Posts The Bond, Just As They Pay The Rent
+        let submission_bond = post_submission_bond(
+            ctx.accounts.case_counter.current_id.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+            &ctx.accounts.protocol_config,
+            &ctx.accounts.submitter.to_account_info(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            ctx.bumps.bond_vault,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+
+        let duplicate_of = register_records_fingerprint(
+            &mut ctx.accounts.records_fingerprint,
+            ctx.bumps.records_fingerprint,
+            records_fingerprint,
+            &manifest,
+            manifest_body_hash,
+            ctx.accounts.case_counter.current_id.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+        )?;
+
+        open_patient_case(
+            &mut ctx.accounts.patient_case,
+            ctx.bumps.patient_case,
+            &mut ctx.accounts.case_counter,
+            &mut ctx.accounts.case_lookup,
+            ctx.bumps.case_lookup,
+            &ctx.accounts.protocol_config,
+            CaseSubmission { beneficiary, submitter, sponsor: None, manifest, records_fingerprint, duplicate_of,
+                total_amount_needed, campaign_duration, funding_mode, urgency, submission_bond },
+        )
+    }
+
+
+// Who A Case Is For, Who Filed It, And The Terms They Filed It With
+pub struct CaseSubmission {
+    pub beneficiary: Pubkey,
+    pub submitter: Pubkey,
+    pub sponsor: Option<Pubkey>,
+    pub manifest: ManifestRef,
+    pub records_fingerprint: [u8; 32],
+    pub duplicate_of: Option<u64>,
+    pub total_amount_needed: u64,
+    pub campaign_duration: i64,
+    pub funding_mode: FundingMode,
+    pub urgency: UrgencyLevel,
+    pub submission_bond: Option<PostedBond>,
+}
+
+
+fn open_patient_case(
+    patient_details: &mut Account<PatientCase>,
+    patient_case_bump: u8,
+    case_id_counter: &mut Account<CaseCounter>,
+    case_id_lookup: &mut Account<CaseIDLookup>,
+    case_lookup_bump: u8,
+    protocol_config: &ProtocolConfig,
+    submission: CaseSubmission)
+    -> Result<()> {
+
+        let CaseSubmission { beneficiary, submitter, sponsor, manifest, records_fingerprint, duplicate_of,
+            total_amount_needed, campaign_duration, funding_mode, urgency, submission_bond } = submission;
+
+        // The Case Keeps The Window And Quorum Of Its Urgency Level For Its Whole Verification
+        let urgency_policy = protocol_config.urgency_policy(urgency);
 
         let current_time = Clock::get()?.unix_timestamp;
-        
-        let patient_case_id = format!("CASE{:04}", case_id_counter.current_id + 1);
 
+        // The Case Details Live In The Off-Chain Manifest; Only Its Pointer Is Stored Here
+        manifest.validate()?;
+
+        // The Patient Picks How Long To Fundraise, Capped By The Protocol Config
+        require!(
+            campaign_duration > 0 && campaign_duration <= protocol_config.max_campaign_duration,
+            CuraChainError::InvalidCampaignDuration
+        );
+        let funding_deadline = current_time.checked_add(campaign_duration).ok_or(CuraChainError::OverflowError)?;
+        
         // update global counter
-        case_id_counter.current_id += 1;
+        case_id_counter.current_id = case_id_counter.current_id.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+        let patient_case_number = case_id_counter.current_id;
 
         // Patient Case ID Lookup
-        case_id_lookup.case_id_in_lookup = patient_case_id.clone();
+        case_id_lookup.case_id_in_lookup = patient_case_number;
         case_id_lookup.patient_pda = patient_details.key();
-        case_id_lookup.case_lookup_bump = ctx.bumps.case_lookup;
-        case_id_lookup.patient_address = ctx.accounts.patient.key();
+        case_id_lookup.case_lookup_bump = case_lookup_bump;
+        case_id_lookup.patient_address = beneficiary;
 
     
 
     // Clone values to for event emission
-    let case_description_clone = case_description.clone();
-    let patient_case_id_clone = patient_case_id.clone();
-    let raw_records_link_clone = link_to_records.clone();
+    let manifest_clone = manifest.clone();
+    let patient_case_id_clone = format_case_id(patient_case_number);
 
         patient_details.set_inner(
             PatientCase {
-                case_description,
+                manifest,
                 total_amount_needed,
                 total_sol_raised: 0,
                 spl_donations: vec![],
@@ -53,24 +182,80 @@ pub fn initialize_patient(
                 is_verified: false,
                 verification_yes_votes: 0,
                 voted_verifiers: vec![],
-                patient_pubkey: ctx.accounts.patient.key(),
-                patient_case_bump: ctx.bumps.patient_case,
-                case_id: patient_case_id,
-                link_to_records: link_to_records,
+                voter_weights: vec![],
+                patient_pubkey: beneficiary,
+                urgency,
+                patient_case_bump,
+                case_id: patient_case_number,
                 case_funded: false,
-                submission_time: Clock::get()?.unix_timestamp
+                submission_time: current_time,
+                amendment_count: 0,
+                case_status: CaseStatus::PendingVerification,
+                funding_deadline,
+                expiry_policy: protocol_config.expiry_policy,
+                total_sol_contributed: 0,
+                funding_mode,
+                goal_reached: false,
+                submitter,
+                sponsor,
+                case_manager: submitter,
+                facility: None,
+                treatment_plan: None,
+                total_released: 0,
+                releases: vec![],
+                submission_bond,
+                update_count: 0,
+                verification_round: 1,
+                rejected_at: None,
+                appeal: None,
+                recused_verifiers: vec![],
+                info_request: None,
+                paused_duration: 0,
+                info_requests_this_round: 0,
+                records_fingerprint,
+                duplicate_of,
+                verification_window: urgency_policy.verification_window,
+                quorum_bps: urgency_policy.quorum_bps,
+                verification_tier: protocol_config.amount_tier(total_amount_needed),
+                awaiting_co_approval: false,
+                co_approvals: vec![],
+                verification_strategy: protocol_config.verification_strategy,
+                yes_weight: 0,
+                no_weight: 0,
+                allow_early_approval: protocol_config.allow_early_approval,
+                escalation_policy: protocol_config.escalation_policy,
+                escalation: None,
             }
         );
 
+        // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE REUSES THE RECORDS OF A CASE STILL OPEN
+        if let Some(earlier_case) = duplicate_of {
+            let message = format!("Patient Case With ID, {} Reuses The Records Of Open Case, {} And Has Been Flagged For Verifiers",
+                patient_case_id_clone, format_case_id(earlier_case));
+            emit!(DuplicateRecordsFlagged {
+                message,
+                case_id: patient_case_id_clone.clone(),
+                duplicate_of: format_case_id(earlier_case),
+                records_fingerprint,
+                timestamp: current_time,
+            });
+        }
+
         // CATCHING THIS EVENT ON-CHAIN ANYTIME THERE IS A SUBMISSION OF CASE
 
-        let message = format!("A patient case with ID, {} and description, {} has been successfully submitted", patient_case_id_clone, case_description_clone);
+        let message = format!("A patient case with ID, {} and manifest, {} has been successfully submitted", patient_case_id_clone, manifest_clone.uri);
         emit!(PatientCaseSubmission {
             message,
-            description: case_description_clone,
+            manifest: manifest_clone,
             case_id: patient_case_id_clone,
+            beneficiary,
+            submitter,
+            sponsor,
+            urgency,
+            verification_deadline: current_time.checked_add(urgency_policy.verification_window).ok_or(CuraChainError::OverflowError)?,
             total_needed_amount: total_amount_needed,
-            link_to_records: raw_records_link_clone,
+            funding_mode,
+            funding_deadline,
             is_verified: false,
             total_raised: 0,
             timestamp: current_time,
@@ -80,24 +265,31 @@ pub fn initialize_patient(
     }
 
 
-    /* On-chain Encryption Algorithm;
-    fn encrypt_link(link: &str) -> Result<String> {
+/*
+Every Submission Registers The Fingerprint Of Its Records Bundle, So The Same Records Can't Quietly Raise Twice.
+1. The Fingerprint Must Be The One The Manifest Hash Commits To, So It Can't Be Made Up To Dodge A Match.
+2. Every Case Filed With A Fingerprint Stays On Its Live List Until Its Lookup Is Closed, Withdrawn Or Archived.
+3. A Case Filed While Others Are Still Live Is Flagged As A Duplicate Of The Newest Of Them, And Becomes The Newest Itself.
+4. Once Every Case On The List Is Gone, The Records Can Be Filed Again Unflagged.
+5. Anyone Can File Against Any Records Hash, So The Flag Is Only A Signal: A Filing Is Never Refused Over It.
+*/
+fn register_records_fingerprint(
+    records_fingerprint: &mut Account<RecordsFingerprint>,
+    fingerprint_bump: u8,
+    fingerprint: [u8; 32],
+    manifest: &ManifestRef,
+    manifest_body_hash: [u8; 32],
+    new_case_id: u64)
+    -> Result<Option<u64>> {
 
-        const NONCE_BYTES: [u8; 12] = *b"VERIFICATION";
+        require!(fingerprint != [0u8; 32], CuraChainError::EmptyRecordsFingerprint);
+        manifest.verify_records_fingerprint(&manifest_body_hash, &fingerprint)?;
 
-        let mut key = [0u8;32];
-        for i in 0..32 {
-            key[i] = (i as u8) ^ 0xFF;
+        if records_fingerprint.submissions == 0 {
+            records_fingerprint.fingerprint = fingerprint;
+            records_fingerprint.first_submitted_at = Clock::get()?.unix_timestamp;
+            records_fingerprint.fingerprint_bump = fingerprint_bump;
         }
 
-        let cipher = Aes256Gcm::new_from_slice(&key)
-        .map_err(|_| error!(CuraChainError::KeyGenerationError))?;
-
-        let nonce = Nonce::from_slice(&NONCE_BYTES);
-
-        let encrypted_link = cipher
-        .encrypt(nonce, link.as_bytes())
-        .map_err(|_| error!(CuraChainError::EncryptionError))?;
-
-        Ok(general_purpose::STANDARD.encode(encrypted_link))
-    }*/
\ No newline at end of file
+        records_fingerprint.file_case(new_case_id)
+    }
\ No newline at end of file
@@ -5,7 +5,7 @@ use anchor_spl::token_interface::{TransferChecked, transfer_checked};
 use crate::states::{contexts::*, constants::*, errors::*, accounts::*, events::*};
 
 
-pub fn donate_spl(ctx: Context<SplDonation>, case_id: String, donation_token: Pubkey, amount_to_donate: u64) -> Result<()> {
+pub fn donate_spl(ctx: Context<SplDonation>, _case_id: String, donation_token: Pubkey, amount_to_donate: u64) -> Result<()> {
     
     // Let's Get the Patient Escrow PDA, Patient Case and Donor PDAs
     let patient_case = &mut ctx.accounts.patient_case;
@@ -14,6 +14,11 @@ pub fn donate_spl(ctx: Context<SplDonation>, case_id: String, donation_token: Pu
 
     // Check to ensure if case is verified or not.
     require!(patient_case.is_verified == true, CuraChainError::UnverifiedCase);
+    require!(patient_case.case_status == CaseStatus::Verified, CuraChainError::CaseNotAcceptingDonations);
+
+    // Donations Close At The Campaign Deadline, Even If Nobody Has Called expire_case Yet
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time < patient_case.funding_deadline, CuraChainError::CampaignEnded);
 
     let donor = &ctx.accounts.donor;
 
@@ -51,6 +56,9 @@ pub fn donate_spl(ctx: Context<SplDonation>, case_id: String, donation_token: Pu
             entry.total_mint_amount = entry.total_mint_amount
                 .checked_add(amount_to_donate)
                 .ok_or(CuraChainError::OverflowError)?;
+            entry.total_contributed = entry.total_contributed
+                .checked_add(amount_to_donate)
+                .ok_or(CuraChainError::OverflowError)?;
             found = true;
             break;
         }
@@ -60,41 +68,42 @@ pub fn donate_spl(ctx: Context<SplDonation>, case_id: String, donation_token: Pu
         patient_case.spl_donations.push(SplDonations{
             mint: donation_token,
             total_mint_amount: amount_to_donate,
-            patient_token_vault: ctx.accounts.patient_token_vault.key()
+            patient_token_vault: ctx.accounts.patient_token_vault.key(),
+            total_contributed: amount_to_donate,
+            vault_payer: ctx.accounts.donor.key(),
         });
     }
    
     // If Case Has Reached Full Funding, Let's Reset The CaseFunded to true, to prevent further funds
     // We intend to allow a buffer of 1 SOL on all fundings
     // Known ISSUE: This is inefficient as we cannot sum SOL and Spl token together for tracking, as they have different prizes
-    let mut grand_donations = patient_case.total_sol_raised;
-    for each_spl in patient_case.spl_donations.iter() {
-        grand_donations = grand_donations
-            .checked_add(each_spl.total_mint_amount)
-            .ok_or(CuraChainError::OverflowError)?;
-    }
+    let grand_donations = patient_case.total_raised()?;
     if grand_donations >= patient_case.total_amount_needed + DONATION_BUFFER {
         patient_case.case_funded = true;
     }
+    patient_case.update_goal_reached()?;
 
     // DONOR INFO UPDATE
     // Let's update Donor Account
-    let case_id_bytes = {
-        let bytes = case_id.as_bytes();
-        let mut arr = [0u8;8];
-        arr.copy_from_slice(bytes);
-        arr
-    };
+    let case_number = patient_case.case_id;
+    let case_id = format_case_id(case_number);
     donor_info.donor_address = donor.key();
     donor_info.donor_bump = ctx.bumps.donor_account;
     donor_info.total_donations = donor_info.total_donations.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
-    if !donor_info.donated_cases.contains(&case_id_bytes) {
-        donor_info.donated_cases.push(case_id_bytes);
+    if !donor_info.donated_cases.contains(&case_number) {
+        donor_info.donated_cases.push(case_number);
     }
 
+    // Record This Donor's Share So It Can Be Refunded If The Case Ends Up Refunding
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.donor = donor.key();
+    contribution.case_id = case_number;
+    contribution.mint = donation_token;
+    contribution.amount = contribution.amount.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
+    contribution.contribution_bump = ctx.bumps.contribution;
+
     // CATCHING THIS EVENT ON-CHAIN ANYTIME A DONATION IS MADE TO ANY CASE ID
     let message = format!("A Donor of address {} has contributed an amount of {} to patient case of ID {}", donor.key(), amount_to_donate, case_id);
-    let current_time = Clock::get()?.unix_timestamp;
 
     emit!(DonationsMade {
         message,
@@ -110,7 +119,7 @@ pub fn donate_spl(ctx: Context<SplDonation>, case_id: String, donation_token: Pu
 
 
 // SOL DONATION LOGIC
-pub fn donate(ctx: Context<SolDonation>, case_id: String, amount_to_donate: u64) -> Result<()> {
+pub fn donate(ctx: Context<SolDonation>, _case_id: String, amount_to_donate: u64) -> Result<()> {
 
     // Let's Get the Patient Escrow PDA, Patient Case and Donor PDAs
     let patient_case = &mut ctx.accounts.patient_case;
@@ -119,6 +128,11 @@ pub fn donate(ctx: Context<SolDonation>, case_id: String, amount_to_donate: u64)
 
     // Check to ensure if case is verified or not.
     require!(patient_case.is_verified == true, CuraChainError::UnverifiedCase);
+    require!(patient_case.case_status == CaseStatus::Verified, CuraChainError::CaseNotAcceptingDonations);
+
+    // Donations Close At The Campaign Deadline, Even If Nobody Has Called expire_case Yet
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time < patient_case.funding_deadline, CuraChainError::CampaignEnded);
 
     let donor = &ctx.accounts.donor;
 
@@ -145,20 +159,26 @@ pub fn donate(ctx: Context<SolDonation>, case_id: String, amount_to_donate: u64)
 
     // Let's Update the patient-case with these infos
     patient_case.total_sol_raised = patient_case.total_sol_raised.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
+    patient_case.total_sol_contributed = patient_case.total_sol_contributed.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
+    patient_case.update_goal_reached()?;
     
     // DONOR INFO UPDATE
     // Let's update Donor Account
-    let case_id_bytes = {
-        let bytes = case_id.as_bytes();
-        let mut arr = [0u8;8];
-        arr.copy_from_slice(bytes);
-        arr
-    };
+    let case_number = patient_case.case_id;
     donor_info.donor_address = donor.key();
     donor_info.donor_bump = ctx.bumps.donor_account;
     donor_info.total_donations = donor_info.total_donations.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
-    if !donor_info.donated_cases.contains(&case_id_bytes) {
-        donor_info.donated_cases.push(case_id_bytes);
+    if !donor_info.donated_cases.contains(&case_number) {
+        donor_info.donated_cases.push(case_number);
     }
+
+    // Record This Donor's Share So It Can Be Refunded If The Case Ends Up Refunding
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.donor = donor.key();
+    contribution.case_id = case_number;
+    contribution.mint = NATIVE_SOL_ADDRESS;
+    contribution.amount = contribution.amount.checked_add(amount_to_donate).ok_or(CuraChainError::OverflowError)?;
+    contribution.contribution_bump = ctx.bumps.contribution;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::{settle_passed_vote, VerifierOperationType},
+    states::{constants::*, contexts::*, errors::*, events::*, format_case_id, CaseAppeal, CaseIDLookup, CaseStatus, Escalation, EscalationResolution, PatientCase},
+    verification_policy::Tally};
+
+
+/*
+A Split Vote Gets A Second Opinion Instead Of A Straight Rejection.
+1. When Verifiers Reject A Case Whose Yes Share Still Reached The Configured Grey-Zone Floor, It Is Escalated,
+   But Only Once The Tally Is Final: Every Eligible Verifier Has Voted, Or The Window Was Closed By finalize_verification.
+2. An Escalated Case Takes No More Verifier Votes; Senior Verifiers, A Separate Role Kept By The Admin, Vote Instead.
+3. Once The Escalation's Senior Quorum Has Voted, The Panel's Majority Decides And Ties Reject.
+   A Panel That Has Not Reached Quorum By The Escalation's decide_by Is Settled By finalize_verification On The Votes It Has.
+4. The Panel's Decision Is Final: A Case It Rejects Can Be Closed Straight Away And Cannot Be Appealed Or Amended.
+*/
+pub fn senior_panel_vote<'info>(ctx: Context<'_, '_, '_, 'info, SeniorPanelVote<'info>>, _case_id: String, is_yes: bool) -> Result<()> {
+
+    let senior_verifier = ctx.accounts.senior_verifier.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    require!(patient_case.case_status == CaseStatus::Escalated, CuraChainError::CaseNotEscalated);
+    let escalation = patient_case.escalation.as_mut().ok_or(CuraChainError::CaseNotEscalated)?;
+    require!(escalation.resolution.is_none(), CuraChainError::EscalationDecisionFinal);
+    require!(!escalation.senior_voters.contains(&senior_verifier), CuraChainError::SeniorVerifierAlreadyVoted);
+
+    match is_yes {
+        true => escalation.senior_yes_votes = escalation.senior_yes_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+        false => escalation.senior_no_votes = escalation.senior_no_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+    };
+    escalation.senior_voters.push(senior_verifier);
+
+    // The Panel Decides Only Once Its Quorum Has Voted, A Quorum The Panel Has Since Shrunk Below Being Capped At Its Size
+    let quorum = (escalation.senior_quorum as usize).min(ctx.accounts.senior_panel.members.len());
+    if escalation.senior_voters.len() < quorum {
+        return Ok(());
+    }
+
+    resolve_escalation(
+        patient_case,
+        &mut ctx.accounts.case_lookup,
+        &ctx.accounts.patient_escrow,
+        ctx.accounts.case_appeal.as_mut(),
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.bond_recipient.to_account_info(),
+        &ctx.accounts.senior_verifier.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        current_time,
+    )
+}
+
+
+// Settles An Escalation On The Senior Votes Cast So Far: The Majority Decides And Ties, Or No Votes At All, Reject
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_escalation<'info>(
+    patient_case: &mut Account<'info, PatientCase>,
+    case_id_lookup: &mut Account<'info, CaseIDLookup>,
+    patient_escrow: &AccountInfo<'info>,
+    case_appeal: Option<&mut Account<'info, CaseAppeal>>,
+    bond_vault: &AccountInfo<'info>,
+    bond_recipient: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    now: i64)
+    -> Result<()> {
+
+    let case_id = format_case_id(patient_case.case_id);
+    let escalation = patient_case.escalation.as_mut().ok_or(CuraChainError::CaseNotEscalated)?;
+
+    let approved = escalation.senior_yes_votes > escalation.senior_no_votes;
+    escalation.resolution = Some(EscalationResolution { approved, resolved_at: now });
+    let (senior_yes_votes, senior_no_votes) = (escalation.senior_yes_votes, escalation.senior_no_votes);
+
+    patient_case.case_status = CaseStatus::PendingVerification;
+
+    if approved {
+        settle_passed_vote(
+            patient_case,
+            case_id_lookup,
+            patient_escrow,
+            case_appeal,
+            bond_vault,
+            bond_recipient,
+            payer,
+            system_program,
+            remaining_accounts,
+            now,
+        )?;
+    } else {
+        patient_case.rejected_at = Some(now);
+    }
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME THE SENIOR PANEL DECIDES AN ESCALATED CASE
+    let message = format!("Senior Panel Has {} Escalated Patient Case With ID, {} ({} Yes, {} No) At Time, {}",
+        if approved { "Approved" } else { "Rejected" }, case_id, senior_yes_votes, senior_no_votes, now);
+
+    emit!(EscalationResolved {
+        message,
+        case_id,
+        senior_yes_votes,
+        senior_no_votes,
+        approved,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+// Called Wherever A Vote Is Settled As Rejected: A Split Vote Is Escalated Once, Anything Else Starts The Appeal Window
+pub fn record_rejected_vote(patient_case: &mut PatientCase, tally: &Tally, tally_final: bool, now: i64) -> Result<()> {
+
+    let policy = patient_case.verification_policy();
+    let escalation_policy = patient_case.escalation_policy;
+
+    if patient_case.escalation.is_none() && policy.in_grey_zone(tally, escalation_policy.grey_zone_min_bps) {
+        // Verifiers Still To Vote Could Move A Split Vote Either Way, So It Stays Open Until They Have
+        if !tally_final {
+            return Ok(());
+        }
+
+        let yes_share_bps = policy.yes_share_bps(tally);
+
+        patient_case.case_status = CaseStatus::Escalated;
+        patient_case.escalation = Some(Escalation {
+            escalated_at: now,
+            yes_share_bps,
+            senior_quorum: escalation_policy.senior_quorum,
+            decide_by: now.checked_add(escalation_policy.decision_window).ok_or(CuraChainError::OverflowError)?,
+            senior_yes_votes: 0,
+            senior_no_votes: 0,
+            senior_voters: vec![],
+            resolution: None,
+        });
+
+        // CATCHING THIS EVENT ON-CHAIN ANYTIME A SPLIT VOTE IS ESCALATED TO THE SENIOR PANEL
+        let case_id = format_case_id(patient_case.case_id);
+        let message = format!("Patient Case With ID, {} Split Its Verifiers At {} Basis Points Yes And Has Been Escalated To The Senior Panel",
+            case_id, yes_share_bps);
+
+        emit!(CaseEscalated {
+            message,
+            case_id,
+            yes_votes: patient_case.verification_yes_votes,
+            no_votes: patient_case.verification_no_votes,
+            yes_share_bps,
+            senior_quorum: escalation_policy.senior_quorum,
+            timestamp: now,
+        });
+
+        return Ok(());
+    }
+
+    patient_case.is_verified = false;
+    if patient_case.rejected_at.is_none() {
+        patient_case.rejected_at = Some(now);
+    }
+
+    Ok(())
+}
+
+
+// Admin Keeps The Senior Panel, Which Is Created On First Use
+pub fn update_senior_panel(ctx: Context<UpdateSeniorPanel>, member: Pubkey, operation_type: VerifierOperationType) -> Result<()> {
+
+    let senior_panel = &mut ctx.accounts.senior_panel;
+    senior_panel.panel_bump = ctx.bumps.senior_panel;
+
+    let added = match operation_type {
+        VerifierOperationType::Add => {
+            require!(!senior_panel.members.contains(&member), CuraChainError::VerifierAlreadyExists);
+            require!(senior_panel.members.len() < MAX_SENIOR_VERIFIERS, CuraChainError::SeniorPanelFull);
+            senior_panel.members.push(member);
+            true
+        },
+
+        VerifierOperationType::Remove => {
+            let index = senior_panel.members.iter().position(|existing| *existing == member)
+                .ok_or(CuraChainError::NotSeniorVerifier)?;
+            senior_panel.members.remove(index);
+
+            // Escalated Cases Must Still Be Able To Reach The Configured Quorum
+            let escalation_policy = ctx.accounts.protocol_config.escalation_policy;
+            if escalation_policy.grey_zone_min_bps > 0 {
+                require!(
+                    senior_panel.members.len() >= escalation_policy.senior_quorum as usize,
+                    CuraChainError::SeniorPanelBelowQuorum
+                );
+            }
+            false
+        },
+    };
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let message = format!("Senior Verifier, {} Has Been {} The Senior Panel At Time, {}",
+        member, if added { "Added To" } else { "Removed From" }, current_time);
+
+    emit!(SeniorPanelUpdated {
+        message,
+        member,
+        added,
+        panel_size: senior_panel.members.len() as u8,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, CaseStatus, ExpiryPolicy, FundingMode, format_case_id};
+
+
+/*
+Once The Campaign Deadline Has Passed, Anybody Can Move A Verified Case Into Its Terminal State.
+The Expiry Policy Snapshotted At Submission Decides What Happens To Funds Still Held:
+Either They Can Still Be Released To Treatment (Expired), Or Donors Reclaim Them (Refunding).
+All-Or-Nothing Cases Ignore The Policy: They Release Only If The Goal Was Met, Otherwise They Refund.
+*/
+pub fn expire_patient_case(ctx: Context<ExpirePatientCase>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time >= patient_case.funding_deadline, CuraChainError::CampaignStillActive);
+    require!(patient_case.case_status == CaseStatus::Verified, CuraChainError::CaseNotExpirable);
+
+    let new_status = match (patient_case.funding_mode, patient_case.expiry_policy) {
+        (FundingMode::AllOrNothing, _) if patient_case.goal_reached => CaseStatus::Expired,
+        (FundingMode::AllOrNothing, _) => CaseStatus::Refunding,
+        (FundingMode::KeepWhatYouRaise, ExpiryPolicy::ReleaseRaised) => CaseStatus::Expired,
+        (FundingMode::KeepWhatYouRaise, ExpiryPolicy::RefundDonors) => CaseStatus::Refunding,
+    };
+    patient_case.case_status = new_status;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE CAMPAIGN EXPIRES
+    let message = format!("Campaign For Patient Case With ID, {} Has Expired At Time, {}", case_id, current_time);
+
+    emit!(PatientCaseExpired {
+        message,
+        case_id,
+        new_status,
+        expiry_policy: patient_case.expiry_policy,
+        funding_mode: patient_case.funding_mode,
+        goal_reached: patient_case.goal_reached,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
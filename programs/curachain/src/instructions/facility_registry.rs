@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, contexts::*, errors::*, events::*, Facility, FacilityStatus, Multisig, PatientCase, format_case_id};
+
+
+/*
+The Facility Registry Fixes Where Released Funds Can Go.
+1. The Admin Or A Multisig Member Registers Each Facility With A Payout Wallet; It Starts Unverified.
+2. Once Vetted, It Is Marked Verified, And Cases Can Bind To It.
+3. release_funds Only Pays The Bound Facility's Registered Payout Wallet And Its ATAs.
+4. Changing The Payout Wallet Drops The Facility Back To Unverified, And Only Someone Other Than Whoever
+   Set The Wallet Can Verify It Again, So No Single Member Can Point A Facility's Payouts At Their Own Wallet.
+*/
+pub fn register_facility(ctx: Context<RegisterFacility>, facility_key: Pubkey, name: String, payout_wallet: Pubkey) -> Result<()> {
+
+    require!(!name.is_empty() && name.len() <= MAX_FACILITY_NAME_LEN, CuraChainError::InvalidFacilityName);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.facility.set_inner(Facility {
+        facility_key,
+        name,
+        payout_wallet,
+        payout_wallet_set_by: ctx.accounts.authority.key(),
+        status: FacilityStatus::Unverified,
+        registered_at: current_time,
+        updated_at: current_time,
+        facility_bump: ctx.bumps.facility,
+    });
+
+    let facility = ctx.accounts.facility.key();
+    let registered_by = ctx.accounts.authority.key();
+
+    let message = format!("Facility, {} Has Been Registered With Payout Wallet, {} At Time, {}", facility, payout_wallet, current_time);
+
+    emit!(FacilityRegistered {
+        message,
+        facility,
+        facility_key,
+        payout_wallet,
+        registered_by,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+pub fn update_facility(
+    ctx: Context<UpdateFacility>,
+    _facility_key: Pubkey,
+    new_payout_wallet: Option<Pubkey>,
+    new_status: Option<FacilityStatus>)
+    -> Result<()> {
+
+    let facility = &mut ctx.accounts.facility;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    apply_facility_update(facility, ctx.accounts.authority.key(), new_payout_wallet, new_status, current_time)?;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A FACILITY'S PAYOUT WALLET OR STATUS CHANGES
+    let message = format!("Facility, {} Has Been Updated At Time, {}", facility.key(), current_time);
+
+    emit!(FacilityUpdated {
+        message,
+        facility: facility.key(),
+        payout_wallet: facility.payout_wallet,
+        status: facility.status,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// A New Payout Wallet Has Not Been Vetted, So The Facility Goes Back To Unverified Until A Second Party Verifies It
+fn apply_facility_update(
+    facility: &mut Facility,
+    authority: Pubkey,
+    new_payout_wallet: Option<Pubkey>,
+    new_status: Option<FacilityStatus>,
+    now: i64)
+    -> Result<()> {
+
+    if let Some(payout_wallet) = new_payout_wallet {
+        if payout_wallet != facility.payout_wallet {
+            facility.payout_wallet = payout_wallet;
+            facility.payout_wallet_set_by = authority;
+            facility.status = FacilityStatus::Unverified;
+        }
+    }
+    if let Some(status) = new_status {
+        if status == FacilityStatus::Verified {
+            require!(authority != facility.payout_wallet_set_by, CuraChainError::FacilityVerifierIsWalletSetter);
+        }
+        facility.status = status;
+    }
+    facility.updated_at = now;
+    Ok(())
+}
+
+
+/*
+Binding Decides Which Facility A Case's Funds Go To.
+The Case Manager Picks It At Submission And Can Change It Until The Case Is Verified.
+The Admin Or A Multisig Member Can Only Bind A Case That Has No Facility Yet, E.g. At Verification,
+So No Single Multisig Member Can Redirect A Case Away From Its Facility.
+*/
+pub fn bind_case_facility(ctx: Context<BindCaseFacility>, _case_id: String, _facility_key: Pubkey) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let patient_case = &mut ctx.accounts.patient_case;
+    let authority = ctx.accounts.authority.key();
+
+    let facility = ctx.accounts.facility.key();
+    let previous_facility = bind_facility(patient_case, &ctx.accounts.multisig, &authority, facility)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let message = format!("Patient Case With ID, {} Has Been Bound To Facility, {} At Time, {}", case_id, facility, current_time);
+
+    emit!(CaseFacilityBound {
+        message,
+        case_id,
+        facility,
+        previous_facility,
+        bound_by: authority,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// Points The Case At A Facility If The Authority May, Returning The Facility It Replaced
+fn bind_facility(patient_case: &mut PatientCase, multisig: &Multisig, authority: &Pubkey, facility: Pubkey) -> Result<Option<Pubkey>> {
+    check_binding_authority(patient_case, multisig, authority)?;
+    Ok(patient_case.facility.replace(facility))
+}
+
+
+// The Case Manager Can Bind Or Rebind Until The Case Is Verified; The Admin Or A Multisig Member
+// Can Bind At Any Time, But Only A Case That Has No Facility Yet
+fn check_binding_authority(patient_case: &PatientCase, multisig: &Multisig, authority: &Pubkey) -> Result<()> {
+    let is_case_manager = patient_case.case_manager == *authority && !patient_case.is_verified;
+    let is_registry_manager = multisig.is_admin_or_member(authority);
+
+    require!(is_case_manager || is_registry_manager, CuraChainError::UnauthorizedFacilityBinding);
+    if !is_case_manager {
+        require!(patient_case.facility.is_none(), CuraChainError::FacilityAlreadyBound);
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::fixtures::{multisig, patient_case};
+
+    fn facility(registered_by: Pubkey) -> Facility {
+        Facility {
+            facility_key: Pubkey::new_unique(),
+            name: "General Hospital".to_string(),
+            payout_wallet: Pubkey::new_unique(),
+            payout_wallet_set_by: registered_by,
+            status: FacilityStatus::Unverified,
+            registered_at: 1_000,
+            updated_at: 1_000,
+            facility_bump: 255,
+        }
+    }
+
+    #[test]
+    fn registrar_cannot_verify_their_own_facility() {
+        let (registrar, reviewer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut facility = facility(registrar);
+
+        assert_eq!(
+            apply_facility_update(&mut facility, registrar, None, Some(FacilityStatus::Verified), 2_000).unwrap_err(),
+            error!(CuraChainError::FacilityVerifierIsWalletSetter)
+        );
+        assert_eq!(facility.status, FacilityStatus::Unverified);
+
+        apply_facility_update(&mut facility, reviewer, None, Some(FacilityStatus::Verified), 2_000).unwrap();
+        assert_eq!(facility.status, FacilityStatus::Verified);
+    }
+
+    #[test]
+    fn member_cannot_redirect_payouts_to_their_own_wallet() {
+        let (registrar, member) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut facility = facility(registrar);
+        facility.status = FacilityStatus::Verified;
+
+        // Swapping The Wallet Takes The Facility Out Of release_funds' Reach
+        let own_wallet = Pubkey::new_unique();
+        apply_facility_update(&mut facility, member, Some(own_wallet), None, 2_000).unwrap();
+        assert_eq!(facility.payout_wallet, own_wallet);
+        assert_eq!(facility.status, FacilityStatus::Unverified);
+
+        // Neither In The Same Call Nor In A Follow-Up Can They Vouch For It Themselves
+        assert_eq!(
+            apply_facility_update(&mut facility, member, None, Some(FacilityStatus::Verified), 3_000).unwrap_err(),
+            error!(CuraChainError::FacilityVerifierIsWalletSetter)
+        );
+        let mut same_call = self::facility(registrar);
+        assert_eq!(
+            apply_facility_update(&mut same_call, member, Some(own_wallet), Some(FacilityStatus::Verified), 3_000).unwrap_err(),
+            error!(CuraChainError::FacilityVerifierIsWalletSetter)
+        );
+
+        // The Registrar, Who Did Not Pick This Wallet, Can
+        apply_facility_update(&mut facility, registrar, None, Some(FacilityStatus::Verified), 3_000).unwrap();
+        assert_eq!(facility.status, FacilityStatus::Verified);
+    }
+
+    #[test]
+    fn resubmitting_the_same_wallet_keeps_the_facility_verified() {
+        let (registrar, member) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut facility = facility(registrar);
+        facility.status = FacilityStatus::Verified;
+        let wallet = facility.payout_wallet;
+
+        apply_facility_update(&mut facility, member, Some(wallet), None, 2_000).unwrap();
+        assert_eq!(facility.status, FacilityStatus::Verified);
+        assert_eq!(facility.payout_wallet_set_by, registrar);
+        assert_eq!(facility.updated_at, 2_000);
+    }
+
+    #[test]
+    fn anyone_on_the_registry_can_suspend_a_facility() {
+        let registrar = Pubkey::new_unique();
+        let mut facility = facility(registrar);
+        facility.status = FacilityStatus::Verified;
+
+        apply_facility_update(&mut facility, registrar, None, Some(FacilityStatus::Unverified), 2_000).unwrap();
+        assert_eq!(facility.status, FacilityStatus::Unverified);
+    }
+
+    #[test]
+    fn member_cannot_steer_a_verified_case_to_another_facility() {
+        let multisig = multisig();
+        let (member, accomplice) = (multisig.multisig_members[0], multisig.multisig_members[1]);
+        let mut case = patient_case();
+        case.is_verified = true;
+
+        // Binding An Unbound Case At Verification Is Allowed
+        let hospital = Pubkey::new_unique();
+        assert_eq!(bind_facility(&mut case, &multisig, &member, hospital).unwrap(), None);
+
+        // Rebinding It Elsewhere Is Not, Whether By The Same Member, Another One Or The Admin
+        let own_facility = Pubkey::new_unique();
+        for authority in [member, accomplice, multisig.multisig_admin] {
+            assert_eq!(
+                bind_facility(&mut case, &multisig, &authority, own_facility).unwrap_err(),
+                error!(CuraChainError::FacilityAlreadyBound)
+            );
+        }
+        assert_eq!(case.facility, Some(hospital));
+    }
+
+    #[test]
+    fn case_manager_loses_rebinding_once_the_case_is_verified() {
+        let multisig = multisig();
+        let mut case = patient_case();
+        let manager = case.case_manager;
+
+        // Before Verification The Case Manager Can Change Their Mind
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(bind_facility(&mut case, &multisig, &manager, first).unwrap(), None);
+        assert_eq!(bind_facility(&mut case, &multisig, &manager, second).unwrap(), Some(first));
+
+        // Once Donors Can Give Against The Verified Facility, It Is Fixed
+        case.is_verified = true;
+        assert_eq!(
+            bind_facility(&mut case, &multisig, &manager, first).unwrap_err(),
+            error!(CuraChainError::UnauthorizedFacilityBinding)
+        );
+        assert_eq!(case.facility, Some(second));
+    }
+
+    #[test]
+    fn outsiders_cannot_bind_even_an_unbound_case() {
+        let multisig = multisig();
+        let mut case = patient_case();
+        case.case_manager = Pubkey::new_unique();
+
+        // The Patient Is Not The Case Manager Here, And Neither Is A Random Signer
+        for authority in [case.patient_pubkey, Pubkey::new_unique()] {
+            assert_eq!(
+                bind_facility(&mut case, &multisig, &authority, Pubkey::new_unique()).unwrap_err(),
+                error!(CuraChainError::UnauthorizedFacilityBinding)
+            );
+        }
+        assert_eq!(case.facility, None);
+    }
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::{record_rejected_vote, resolve_escalation, settle_passed_vote},
+    states::{contexts::*, errors::*, events::*, format_case_id, CaseStatus},
+    verification_policy::TallyOutcome};
+
+
+/*
+Once A Case's Verification Window Has Closed, Anybody Can Settle It, So No Case Sits Unresolved Short Of Quorum.
+1. The Final Verdict Comes From Every Vote Cast; A Vote Still Short Of Quorum Or Of The Minimum Yes Count Is Rejected.
+2. A Passed Case Is Verified And Its Escrow Created, Or Waits On Multisig Co-Approval When Its Amount Tier Asks For It.
+3. A Rejected Case Starts Its Appeal Window, And Can Be Closed Once That Window Has Passed; A Split Vote Is Escalated Instead.
+4. An Escalation The Senior Panel Has Not Decided By Its decide_by Is Settled On The Senior Votes Cast So Far.
+5. A Question The Patient Left Unanswered Past The Response Window Lapses First, So It Cannot Block Settlement.
+*/
+pub fn finalize_case_verification<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeVerification<'info>>, _case_id: String) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let caller = ctx.accounts.caller.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    // A Senior Panel Short Of Quorum Cannot Hold A Case Forever
+    if patient_case.case_status == CaseStatus::Escalated {
+        let escalation = patient_case.escalation.as_ref().ok_or(CuraChainError::CaseNotEscalated)?;
+        require!(escalation.resolution.is_none(), CuraChainError::EscalationDecisionFinal);
+        require!(current_time >= escalation.decide_by, CuraChainError::EscalationWindowOpen);
+
+        return resolve_escalation(
+            patient_case,
+            &mut ctx.accounts.case_lookup,
+            &ctx.accounts.patient_escrow,
+            ctx.accounts.case_appeal.as_mut(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.bond_recipient.to_account_info(),
+            &ctx.accounts.caller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            current_time,
+        );
+    }
+
+    // Only A Case Still Waiting On Its Verdict Can Be Settled
+    require!(!patient_case.is_verified, CuraChainError::CaseAlreadyVerified);
+    require!(!patient_case.awaiting_co_approval, CuraChainError::CaseAwaitingCoApproval);
+    require!(patient_case.rejected_at.is_none(), CuraChainError::VerificationAlreadyFinalized);
+    patient_case.lapse_unanswered_info_request(current_time)?;
+    require!(patient_case.case_status == CaseStatus::PendingVerification, CuraChainError::CaseNotPendingVerification);
+
+    require!(
+        current_time >= patient_case.verification_deadline(current_time)?,
+        CuraChainError::VerificationWindowOpen
+    );
+
+    let tally = patient_case.tally(&ctx.accounts.verifiers_list);
+    let approved = patient_case.verification_policy().finalize(&tally) == TallyOutcome::Approved;
+
+    if approved {
+        settle_passed_vote(
+            patient_case,
+            &mut ctx.accounts.case_lookup,
+            &ctx.accounts.patient_escrow,
+            ctx.accounts.case_appeal.as_mut(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.bond_recipient.to_account_info(),
+            &ctx.accounts.caller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+    } else {
+        // The Rejection Starts The Appeal Window, Or Sends A Split Vote To The Senior Panel, Just As A Rejecting Vote Would
+        record_rejected_vote(patient_case, &tally, true, current_time)?;
+    }
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE'S VERIFICATION IS FINALIZED
+    let escalated = patient_case.case_status == CaseStatus::Escalated;
+    let verdict = if approved { "Approved" } else if escalated { "Escalated" } else { "Rejected" };
+    let message = format!("Verification Of Patient Case With ID, {} Has Been Finalized By, {} As {} At Time, {}",
+        case_id, caller, verdict, current_time);
+
+    emit!(VerificationFinalized {
+        message,
+        case_id,
+        finalized_by: caller,
+        yes_votes: patient_case.verification_yes_votes,
+        no_votes: patient_case.verification_no_votes,
+        approved,
+        awaiting_co_approval: patient_case.awaiting_co_approval,
+        escalated,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, contexts::*, errors::*, events::*, format_case_id, CaseStatus, InfoRequest};
+
+
+/*
+Verifiers Who Need More From The Patient Can Ask Instead Of Voting No Or Letting The Window Run Out.
+1. A Verifier Records The Hash Of Their Question, Moving The Case To InfoRequested And Pausing The Clock.
+2. No Votes Are Taken While The Question Is Open.
+3. The Patient Or Case Manager Answers With The Hash Of Their Response, And The Time Spent Paused
+Is Added Onto The Verification Deadline Used By Both Verifiers And The Admin Override.
+4. A Question Pauses The Clock For At Most INFO_REQUEST_RESPONSE_WINDOW. Left Unanswered Past That, It Lapses
+The Next Time Someone Votes Or Finalizes, So An Absent Patient Cannot Hold The Case And Its Bond Forever.
+*/
+pub fn request_case_info(ctx: Context<RequestCaseInfo>, _case_id: String, question_hash: [u8; 32]) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let verifier = ctx.accounts.verifier.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    require!(patient_case.case_status == CaseStatus::PendingVerification, CuraChainError::CaseNotPendingVerification);
+    require!(current_time < patient_case.verification_deadline(current_time)?, CuraChainError::VotingPeriodExpired);
+
+    // Once A Quorum Has Rejected The Case, New Documents Go Through An Appeal Instead
+    require!(patient_case.rejected_at.is_none(), CuraChainError::CaseNotPendingVerification);
+
+    // Recused Verifiers Have No Say In The Appeal Round, Questions Included
+    require!(
+        !patient_case.is_recused(&ctx.accounts.verifier_account.key()),
+        CuraChainError::VerifierRecused
+    );
+
+    require!(question_hash != [0u8; 32], CuraChainError::EmptyInfoHash);
+    require!(
+        patient_case.info_requests_this_round < MAX_INFO_REQUESTS_PER_ROUND,
+        CuraChainError::TooManyInfoRequests
+    );
+
+    patient_case.info_request = Some(InfoRequest {
+        question_hash,
+        requested_by: verifier,
+        requested_at: current_time,
+    });
+    patient_case.info_requests_this_round += 1;
+    patient_case.case_status = CaseStatus::InfoRequested;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A VERIFIER PAUSES A CASE WITH A QUESTION
+    let message = format!("Verifier, {} Has Requested More Information On Patient Case With ID, {} At Time, {}",
+        verifier, case_id, current_time);
+
+    emit!(CaseInfoRequested {
+        message,
+        case_id,
+        requested_by: verifier,
+        question_hash,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+pub fn respond_to_info_request(ctx: Context<RespondToInfoRequest>, _case_id: String, answer_hash: [u8; 32]) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    require!(patient_case.case_status == CaseStatus::InfoRequested, CuraChainError::NoOpenInfoRequest);
+    require!(answer_hash != [0u8; 32], CuraChainError::EmptyInfoHash);
+
+    // Bank The Paused Time, So The Deadline Moves Back By How Long The Question Was Open, Up To The Response Window
+    let (request, paused_for) = patient_case.close_info_request(current_time)?.ok_or(CuraChainError::NoOpenInfoRequest)?;
+
+    let verification_deadline = patient_case.verification_deadline(current_time)?;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A PATIENT ANSWERS A VERIFIER'S QUESTION
+    let message = format!("Patient Case With ID, {} Has Answered A Request For Information, Resuming Verification At Time, {}",
+        case_id, current_time);
+
+    emit!(CaseInfoProvided {
+        message,
+        case_id,
+        question_hash: request.question_hash,
+        answer_hash,
+        paused_for,
+        verification_deadline,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{fixtures::patient_case, PatientCase};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    // A Verifier Asks A Question A Day Into The Verification Window
+    fn case_with_open_question() -> (PatientCase, i64) {
+        let mut case = patient_case();
+        let asked_at = case.submission_time + DAY;
+        case.info_request = Some(InfoRequest { question_hash: [9u8; 32], requested_by: Pubkey::new_unique(), requested_at: asked_at });
+        case.info_requests_this_round = 1;
+        case.case_status = CaseStatus::InfoRequested;
+        (case, asked_at)
+    }
+
+    #[test]
+    fn unanswered_question_stops_pausing_the_clock_after_the_response_window() {
+        let (case, asked_at) = case_with_open_question();
+        let longest_deadline = case.submission_time + case.verification_window + INFO_REQUEST_RESPONSE_WINDOW;
+
+        // The Deadline Stops Moving Once The Patient Has Had The Full Response Window
+        assert_eq!(case.verification_deadline(asked_at + INFO_REQUEST_RESPONSE_WINDOW).unwrap(), longest_deadline);
+        assert_eq!(case.verification_deadline(asked_at + 365 * DAY).unwrap(), longest_deadline);
+    }
+
+    #[test]
+    fn unanswered_question_lapses_so_the_case_can_be_finalized() {
+        let (mut case, asked_at) = case_with_open_question();
+        let months_later = asked_at + 90 * DAY;
+
+        // finalize_verification And admin_override_case Both Need The Deadline Behind Them
+        assert!(months_later >= case.verification_deadline(months_later).unwrap());
+
+        assert!(case.lapse_unanswered_info_request(months_later).unwrap());
+        assert_eq!(case.case_status, CaseStatus::PendingVerification);
+        assert_eq!(case.info_request, None);
+        assert_eq!(case.paused_duration, INFO_REQUEST_RESPONSE_WINDOW);
+        assert!(months_later >= case.verification_deadline(months_later).unwrap());
+    }
+
+    #[test]
+    fn question_still_within_the_response_window_does_not_lapse() {
+        let (mut case, asked_at) = case_with_open_question();
+
+        assert!(!case.lapse_unanswered_info_request(asked_at + INFO_REQUEST_RESPONSE_WINDOW - 1).unwrap());
+        assert_eq!(case.case_status, CaseStatus::InfoRequested);
+        assert!(case.info_request.is_some());
+    }
+
+    #[test]
+    fn answer_banks_exactly_the_time_spent_waiting() {
+        let (mut case, asked_at) = case_with_open_question();
+
+        let (request, paused_for) = case.close_info_request(asked_at + 2 * DAY).unwrap().unwrap();
+        assert_eq!(request.requested_at, asked_at);
+        assert_eq!(paused_for, 2 * DAY);
+        assert_eq!(case.verification_deadline(asked_at + 2 * DAY).unwrap(), case.submission_time + case.verification_window + 2 * DAY);
+
+        // Nothing Left To Answer
+        assert_eq!(case.close_info_request(asked_at + 3 * DAY).unwrap(), None);
+    }
+}
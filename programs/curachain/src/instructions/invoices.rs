@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, Invoice, InvoiceStatus};
+
+
+/*
+The Facility A Case Is Bound To Bills The Case Through On-Chain Invoices.
+Every Release Proposal Points At One Invoice, And release_funds Never Pays More Than Is Still
+Owed On It, So Each Payout Can Be Traced Back To A Specific Bill.
+*/
+pub fn submit_invoice(
+    ctx: Context<SubmitInvoice>,
+    case_id: String,
+    invoice_number_hash: [u8; 32],
+    document_hash: [u8; 32],
+    mint: Pubkey,
+    amount: u64,
+    due_date: i64)
+    -> Result<()> {
+
+    require!(amount > 0, CuraChainError::NonZeroAmount);
+    require!(invoice_number_hash != [0u8; 32] && document_hash != [0u8; 32], CuraChainError::InvalidInvoiceHash);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(due_date > current_time, CuraChainError::InvalidDueDate);
+
+    let facility = ctx.accounts.facility.key();
+
+    ctx.accounts.invoice.set_inner(Invoice {
+        case_id: ctx.accounts.patient_case.case_id,
+        facility,
+        invoice_number_hash,
+        document_hash,
+        mint,
+        amount,
+        amount_paid: 0,
+        due_date,
+        status: InvoiceStatus::Open,
+        submitted_at: current_time,
+        invoice_bump: ctx.bumps.invoice,
+    });
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A FACILITY BILLS A CASE
+    let message = format!("Facility, {} Has Submitted An Invoice Of Amount, {} For Patient Case With ID, {} At Time, {}",
+        facility, amount, case_id, current_time);
+
+    emit!(InvoiceSubmitted {
+        message,
+        case_id,
+        invoice: ctx.accounts.invoice.key(),
+        facility,
+        invoice_number_hash,
+        document_hash,
+        mint,
+        amount,
+        due_date,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
\ No newline at end of file
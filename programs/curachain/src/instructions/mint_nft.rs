@@ -8,7 +8,7 @@ use anchor_spl::{
 
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, CuraChainError};
+use crate::states::{contexts::*, format_case_id, parse_case_id, CuraChainError};
 
 
  //    ---------------------  NFT MINTING LOGIC AND METADATA UPDATES    ------------------------------- //
@@ -18,12 +18,8 @@ use crate::states::{contexts::*, CuraChainError};
 pub fn nft_mint(ctx: Context<MintNFT>, case_id: String, nft_uri: String) -> Result<()> {
 
     let donor_info = &mut ctx.accounts.donor_account;
-     let case_id_bytes = {
-        let bytes = case_id.as_bytes();
-        let mut arr = [0u8;8];
-        arr.copy_from_slice(bytes);
-        arr
-    };
+    let case_number = parse_case_id(&case_id).ok_or(CuraChainError::InvalidCaseID)?;
+    let case_id = format_case_id(case_number);
 
     let seeds = &[
             b"multisig",
@@ -31,13 +27,13 @@ pub fn nft_mint(ctx: Context<MintNFT>, case_id: String, nft_uri: String) -> Resu
             &[ctx.accounts.multisig.multisig_bump]
         ];
     let multisig_seeds = &[&seeds[..]];
-    let first_time_donation = !donor_info.donated_cases.contains(&case_id_bytes);
+    let first_time_donation = !donor_info.donated_cases.contains(&case_number);
 
     let donor_meta = ctx.accounts.donor_nft_metadata.to_account_info();
     let has_metadata = donor_meta.lamports() > 0 && donor_meta.data_len() > 0;
 
     if first_time_donation {
-        donor_info.donated_cases.push(case_id_bytes);
+        donor_info.donated_cases.push(case_number);
     }
     // If First Time Case Donation, Mint Recognition NFT
     
@@ -1,5 +1,4 @@
 pub mod create_patient_case;
-pub mod view_case_details;
 pub mod initialize_admin;
 pub mod init_nft_collection;
 pub mod release_funds;
@@ -10,10 +9,28 @@ pub mod close_rejected_case;
 pub mod multisig_operations;
 pub mod mint_nft;
 pub mod admin_override_case;
+pub mod protocol_config;
+pub mod amend_patient_case;
+pub mod withdraw_case;
+pub mod expire_case;
+pub mod claim_refund;
+pub mod records_access;
+pub mod case_control;
+pub mod facility_registry;
+pub mod invoices;
+pub mod treatment_plan;
+pub mod archive_case;
+pub mod submission_bond;
+pub mod views;
+pub mod case_updates;
+pub mod appeal_case;
+pub mod info_requests;
+pub mod co_approve_case;
+pub mod finalize_verification;
+pub mod escalation;
 
  
 pub use create_patient_case::*;
-pub use view_case_details::*;
 pub use initialize_admin::*;
 pub use init_nft_collection::*;
 pub use donate_funds::*;
@@ -23,4 +40,23 @@ pub use verifiers_operations::*;
 pub use close_rejected_case::*;
 pub use multisig_operations::*;
 pub use mint_nft::*;
-pub use admin_override_case::*;
\ No newline at end of file
+pub use admin_override_case::*;
+pub use protocol_config::*;
+pub use amend_patient_case::*;
+pub use withdraw_case::*;
+pub use expire_case::*;
+pub use claim_refund::*;
+pub use records_access::*;
+pub use case_control::*;
+pub use facility_registry::*;
+pub use invoices::*;
+pub use treatment_plan::*;
+pub use archive_case::*;
+pub use submission_bond::*;
+pub use views::*;
+pub use case_updates::*;
+pub use appeal_case::*;
+pub use info_requests::*;
+pub use co_approve_case::*;
+pub use finalize_verification::*;
+pub use escalation::*;
\ No newline at end of file
@@ -1,7 +1,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, errors::*, MultisigApprovals, MULTISIG_THRESHOLD};
+use crate::states::{contexts::*, errors::*, CaseStatus, FundingMode, InvoiceStatus, MultisigApprovals, case_number};
 
 
 
@@ -54,23 +54,32 @@ pub fn add_or_remove_members(ctx: Context<AddorRemoveMultisigMember>, member_add
 
 
 // A Multisig Member Can Propose Full or Partial Funds Release
-pub fn propose_funds_release(ctx: Context<ProposeFundRelease>, case_id: String, proposal_index: u64) -> Result<()> {
+pub fn propose_funds_release(ctx: Context<ProposeFundRelease>, _case_id: String, proposal_index: u64) -> Result<()> {
 
     let patient_case = &ctx.accounts.patient_case;
     let proposal = &mut ctx.accounts.proposal;
 
     // Case Must Be Verified, And At Least A Non-Zero Donation Made To Either Escrow PDA or Patient Token Vault
     require!(patient_case.is_verified == true, CuraChainError::CaseNotYetVerified);
+    require!(patient_case.case_status != CaseStatus::Refunding, CuraChainError::CaseRefunding);
 
-    let has_any_spl = patient_case
-        .spl_donations
-        .iter()
-        .any(|donation| donation.total_mint_amount > 0);
+    require!(patient_case.has_donations(), CuraChainError::NoDonationsMade);
+
+    // Funds Can Only Ever Go To The Facility The Case Is Bound To
+    require!(patient_case.facility.is_some(), CuraChainError::FacilityNotBound);
 
-    require!(patient_case.total_sol_raised > 0 || has_any_spl, CuraChainError::NoDonationsMade);
+    // Every Release Pays Towards An Open Invoice From That Facility
+    let invoice = &ctx.accounts.invoice;
+    require!(patient_case.facility == Some(invoice.facility), CuraChainError::InvoiceMismatch);
+    require!(invoice.status != InvoiceStatus::Settled, CuraChainError::InvoiceAlreadySettled);
+
+    // All-Or-Nothing Cases Hold Every Donation Until The Goal Has Been Met
+    if patient_case.funding_mode == FundingMode::AllOrNothing {
+        require!(patient_case.goal_reached, CuraChainError::FundingGoalNotReached);
+    }
 
     // Fill Proposal
-    proposal.case_id = case_id;
+    proposal.case_id = patient_case.case_id;
     proposal.proposal_index = proposal_index;
     proposal.voted_multisig.push( MultisigApprovals {
         multisig_member: ctx.accounts.proposer.key(),
@@ -78,6 +87,7 @@ pub fn propose_funds_release(ctx: Context<ProposeFundRelease>, case_id: String,
     });
     proposal.approved = false;
     proposal.proposal_bump = ctx.bumps.proposal;
+    proposal.invoice = invoice.key();
     Ok(())
 }
 
@@ -88,7 +98,7 @@ pub fn proposal_approve(ctx: Context<ApproveProposal>, case_id: String, proposal
     let proposal = &mut ctx.accounts.proposal;
 
     // Ensure Input case_id and Proposal index is Correct
-    require!(proposal.case_id == case_id, CuraChainError::NoProposalMade);
+    require!(proposal.case_id == case_number(&case_id), CuraChainError::NoProposalMade);
     require!(proposal.proposal_index == proposal_index, CuraChainError::InvalidProposalIndex);
 
     // Check That Multisig Member Can Only Vote Once
@@ -116,7 +126,7 @@ pub fn proposal_approve(ctx: Context<ApproveProposal>, case_id: String, proposal
         }
     }
     // Check If Approval threshold Has Reached
-    if true_count >= MULTISIG_THRESHOLD {
+    if true_count >= ctx.accounts.multisig.required_threshold {
         proposal.approved = true;
     }
     
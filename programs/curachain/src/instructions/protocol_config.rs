@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{constants::*, contexts::*, errors::*, events::*, AmendmentPolicy, AmountTier, EscalationPolicy, ExpiryPolicy, UrgencyPolicy}, verification_policy::VerificationStrategy};
+
+
+// Admin Creates The Protocol Config On First Call, And Overwrites It On Every Later Call
+pub fn update_protocol_config(ctx: Context<UpdateProtocolConfig>, config_args: ProtocolConfigArgs) -> Result<()> {
+
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    require!(config_args.max_campaign_duration > 0, CuraChainError::InvalidCampaignDuration);
+    require!(config_args.appeal_window >= 0, CuraChainError::InvalidAppealWindow);
+    for urgency_policy in config_args.urgency_policies.iter() {
+        urgency_policy.validate()?;
+    }
+
+    // Tiers Are Looked Up From The Top, So They Must Be Sorted By Strictly Increasing min_amount
+    require!(config_args.amount_tiers.len() <= MAX_AMOUNT_TIERS, CuraChainError::InvalidAmountTier);
+    for amount_tier in config_args.amount_tiers.iter() {
+        amount_tier.validate()?;
+    }
+    require!(
+        config_args.amount_tiers.windows(2).all(|pair| pair[0].min_amount < pair[1].min_amount),
+        CuraChainError::InvalidAmountTier
+    );
+    config_args.escalation_policy.validate()?;
+    if config_args.escalation_policy.grey_zone_min_bps > 0 {
+        let panel_size = ctx.accounts.senior_panel.as_ref().map_or(0, |senior_panel| senior_panel.members.len());
+        require!(
+            config_args.escalation_policy.senior_quorum as usize <= panel_size,
+            CuraChainError::SeniorPanelBelowQuorum
+        );
+    }
+    require!(config_args.rejected_close_bounty_bps <= MAX_REJECTED_CLOSE_BOUNTY_BPS, CuraChainError::InvalidCloseBounty);
+
+    validate_submission_bond(config_args.submission_bond_amount, config_args.submission_bond_mint, Rent::get()?.minimum_balance(0))?;
+
+    protocol_config.amendment_policy = config_args.amendment_policy;
+    protocol_config.max_campaign_duration = config_args.max_campaign_duration;
+    protocol_config.expiry_policy = config_args.expiry_policy;
+    protocol_config.rejected_close_bounty_bps = config_args.rejected_close_bounty_bps;
+    protocol_config.submission_bond_amount = config_args.submission_bond_amount;
+    protocol_config.submission_bond_mint = config_args.submission_bond_mint;
+    protocol_config.bond_forfeit_pool = config_args.bond_forfeit_pool;
+    protocol_config.appeal_window = config_args.appeal_window;
+    protocol_config.urgency_policies = config_args.urgency_policies;
+    protocol_config.amount_tiers = config_args.amount_tiers;
+    protocol_config.verification_strategy = config_args.verification_strategy;
+    protocol_config.allow_early_approval = config_args.allow_early_approval;
+    protocol_config.escalation_policy = config_args.escalation_policy;
+    protocol_config.config_bump = ctx.bumps.protocol_config;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let message = format!("Protocol Config Has Been Updated By Admin {} At Time, {}", ctx.accounts.admin.key(), current_time);
+
+    emit!(ProtocolConfigUpdated {
+        message,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// Every Admin-Tunable Policy Of The Protocol
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ProtocolConfigArgs {
+    pub amendment_policy: AmendmentPolicy,
+
+    pub max_campaign_duration: i64,
+
+    pub expiry_policy: ExpiryPolicy,
+
+    pub rejected_close_bounty_bps: u16,
+
+    pub submission_bond_amount: u64,
+
+    pub submission_bond_mint: Pubkey,
+
+    pub bond_forfeit_pool: Pubkey,
+
+    pub appeal_window: i64,
+
+    // Elective, Urgent And Emergency, In That Order
+    pub urgency_policies: [UrgencyPolicy; URGENCY_LEVELS],
+
+    pub amount_tiers: Vec<AmountTier>,
+
+    pub verification_strategy: VerificationStrategy,
+
+    pub allow_early_approval: bool,
+
+    pub escalation_policy: EscalationPolicy,
+}
+
+
+// A SOL Bond Vault Is A Plain System Account, So A SOL Bond Must Cover At Least Its Rent-Exempt Minimum
+fn validate_submission_bond(amount: u64, mint: Pubkey, rent_minimum: u64) -> Result<()> {
+    if amount > 0 && mint == NATIVE_SOL_ADDRESS {
+        require!(amount >= rent_minimum, CuraChainError::InvalidBondConfig);
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENT_MINIMUM: u64 = 890_880;
+
+    #[test]
+    fn sol_bond_below_the_vault_rent_minimum_is_refused() {
+        assert_eq!(
+            validate_submission_bond(RENT_MINIMUM - 1, NATIVE_SOL_ADDRESS, RENT_MINIMUM).unwrap_err(),
+            error!(CuraChainError::InvalidBondConfig)
+        );
+        assert!(validate_submission_bond(RENT_MINIMUM, NATIVE_SOL_ADDRESS, RENT_MINIMUM).is_ok());
+    }
+
+    #[test]
+    fn no_bond_and_spl_bonds_skip_the_sol_rent_check() {
+        assert!(validate_submission_bond(0, NATIVE_SOL_ADDRESS, RENT_MINIMUM).is_ok());
+
+        // SPL Bonds Sit In An ATA The Depositor Pays Rent For Separately
+        assert!(validate_submission_bond(1, Pubkey::new_unique(), RENT_MINIMUM).is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*, RecordsAccessGrant, SealedEnvelope, format_case_id};
+
+
+/*
+Medical Records Are Encrypted Off-Chain Under A Random Records Key Chosen By The Patient Or Their Representative.
+Nothing Secret Ever Touches The Chain:
+1. Each Verifier Publishes An x25519 Encryption Key On Their Verifier Account.
+2. The Case Manager Seals The Records Key To That Key Off-Chain And Stores The Envelope In A Per-Verifier Grant.
+3. Only That Verifier Can Open The Envelope; The Case Manager Can Revoke It By Closing The Grant.
+The Sealing And Opening Helpers Live In The `records_envelope` Module Behind The `client` Feature.
+*/
+pub fn set_verifier_encryption_key(ctx: Context<SetVerifierEncryptionKey>, encryption_key: [u8; 32]) -> Result<()> {
+
+    require!(encryption_key != [0u8; 32], CuraChainError::EncryptionKeyNotSet);
+
+    let verifier_account = &mut ctx.accounts.verifier_account;
+    verifier_account.encryption_key = encryption_key;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let verifier_address = ctx.accounts.verifier.key();
+
+    let message = format!("Verifier With Address, {} Has Set A New Encryption Key At Time, {}", verifier_address, current_time);
+
+    emit!(VerifierEncryptionKeySet {
+        message,
+        verifier_address,
+        encryption_key,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+pub fn grant_records_access(ctx: Context<GrantRecordsAccess>, _case_id: String, verifier_address: Pubkey, envelope: SealedEnvelope) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    // The Envelope Must Be Sealed To The Key The Verifier Currently Holds, Or They Could Never Open It
+    let verifier_key = ctx.accounts.verifier_account.encryption_key;
+    require!(verifier_key != [0u8; 32], CuraChainError::EncryptionKeyNotSet);
+    require!(envelope.recipient_key == verifier_key, CuraChainError::EnvelopeRecipientMismatch);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.records_access_grant.set_inner(RecordsAccessGrant {
+        case_id: ctx.accounts.patient_case.case_id,
+        granted_by: ctx.accounts.case_manager.key(),
+        verifier: verifier_address,
+        envelope,
+        granted_at: current_time,
+        grant_bump: ctx.bumps.records_access_grant,
+    });
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE MANAGER SHARES RECORDS ACCESS WITH A VERIFIER
+    let message = format!("Patient Case With ID, {} Has Granted Records Access To Verifier, {} At Time, {}", case_id, verifier_address, current_time);
+
+    emit!(RecordsAccessGranted {
+        message,
+        case_id,
+        verifier_address,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+pub fn revoke_records_access(ctx: Context<RevokeRecordsAccess>, _case_id: String, verifier_address: Pubkey) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    // The Grant Account Is Closed By The Context, Returning Its Rent To Whoever Granted It
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let message = format!("Patient Case With ID, {} Has Revoked Records Access From Verifier, {} At Time, {}", case_id, verifier_address, current_time);
+
+    emit!(RecordsAccessRevoked {
+        message,
+        case_id,
+        verifier_address,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -1,51 +1,81 @@
 use anchor_lang::{prelude::*, solana_program::{self, program_pack::Pack, rent::Rent}};
 use anchor_spl::{associated_token::{create_idempotent, get_associated_token_address, Create}, token::spl_token::state::Mint, token_interface::{transfer_checked, TransferChecked}};
 
-use crate::states::{contexts::*, errors::*, ReleaseOfFunds};
+use crate::states::{contexts::*, errors::*, events::*, CaseStatus, ReleaseRecord, MAX_RELEASE_RECORDS, NATIVE_SOL_ADDRESS, format_case_id};
+
+pub fn release_funds<'info>(ctx: Context<'_, '_, '_, 'info, ReleaseFunds<'info>>, _case_id: String, proposal_index: u64) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    let case_id_seed = ctx.accounts.patient_case.case_id.to_le_bytes();
 
-pub fn release_funds<'info>(ctx: Context<'_, '_, '_, 'info, ReleaseFunds<'info>>, case_id: String, proposal_index: u64) -> Result<()> {
-   
     // ENSURE RELEASE PROPOSAL IS APPROVED
     require!(ctx.accounts.proposal.approved == true, CuraChainError::ProposalNotApproved);
     require!(ctx.accounts.proposal.executed == false, CuraChainError::ProposalAlreadyExecuted);
-    require!(ctx.accounts.proposal.case_id == case_id, CuraChainError::NoProposalMade);
+    require!(ctx.accounts.proposal.case_id == ctx.accounts.patient_case.case_id, CuraChainError::NoProposalMade);
     require!(ctx.accounts.proposal.proposal_index == proposal_index, CuraChainError::InvalidProposalIndex);
+    // A Proposal Made Before The Case Started Refunding Must Not Drain What Donors Are Reclaiming
+    require!(ctx.accounts.patient_case.case_status != CaseStatus::Refunding, CuraChainError::CaseRefunding);
+
+    // Never Pay More Than Is Still Owed On The Invoice The Proposal References
+    let outstanding = ctx.accounts.invoice.outstanding();
+    require!(outstanding > 0, CuraChainError::InvoiceAlreadySettled);
 
+    // Milestone Cases Only Pay Towards The Next Milestone, And Only Once Its Proof Is Recorded
+    let mut release_cap = outstanding;
+    match (ctx.accounts.patient_case.treatment_plan, &ctx.accounts.treatment_plan) {
+        (None, _) => {},
+        (Some(plan_key), Some(treatment_plan)) => {
+            require!(treatment_plan.key() == plan_key, CuraChainError::TreatmentPlanRequired);
+
+            let milestone = treatment_plan.current_milestone()?;
+            require!(milestone.proof_hash.is_some(), CuraChainError::MilestoneProofMissing);
+
+            let milestone_remaining = milestone.amount.checked_sub(milestone.amount_released).ok_or(CuraChainError::UnderflowError)?;
+            release_cap = std::cmp::min(release_cap, milestone_remaining);
+        },
+        (Some(_), None) => return err!(CuraChainError::TreatmentPlanRequired),
+    }
 
-    // We Get The Escrow Balance Including Rent-exempt
-    let total_escrow_balance = ctx.accounts.patient_escrow.lamports();
-    let rent = Rent::get()?;
-    let rent_lamports = rent.minimum_balance(0);
+    // The Case Keeps A Record Of Every Release, So There Must Be Room For This One
+    require!(ctx.accounts.patient_case.releases.len() < MAX_RELEASE_RECORDS, CuraChainError::TooManyReleases);
 
-    let actual_escrow_balance;
-    // We Intend To Keep Patient Escrow Even When Case Is Fully Funded;
-    // We can always close the account later
-    actual_escrow_balance = total_escrow_balance.checked_sub(rent_lamports).ok_or(CuraChainError::UnderflowError)?;
-    require!(actual_escrow_balance > 0, CuraChainError::NonZeroAmount);
+    let invoice_mint = ctx.accounts.invoice.mint;
+    let amount_released;
 
-   
     //  ...............          SET UP FOR SOL TRANSFER VIA LOW-LEVEL SOLANA CALL         .............   //
-    // ----------  ONLY TRANSFER IF THERE WAS A SOL DONATION  ---------------- //
+    // ----------  SOL INVOICES ARE PAID FROM THE PATIENT ESCROW  ---------------- //
 
-    if ctx.accounts.patient_case.total_sol_raised > 0 {
+    if invoice_mint == NATIVE_SOL_ADDRESS {
+
+        // We Get The Escrow Balance Including Rent-exempt
+        let total_escrow_balance = ctx.accounts.patient_escrow.lamports();
+        let rent = Rent::get()?;
+        let rent_lamports = rent.minimum_balance(0);
+
+        // We Intend To Keep Patient Escrow Even When Case Is Fully Funded;
+        // We can always close the account later
+        let actual_escrow_balance = total_escrow_balance.checked_sub(rent_lamports).ok_or(CuraChainError::UnderflowError)?;
+        require!(actual_escrow_balance > 0, CuraChainError::NoFundsInInvoiceMint);
+
+        amount_released = std::cmp::min(actual_escrow_balance, release_cap);
 
         let patient_case_key = &ctx.accounts.patient_case.key();
- 
+
         let seeds = &[
             b"patient_escrow",
-            case_id.as_bytes().as_ref(),
+            case_id_seed.as_ref(),
             patient_case_key.as_ref(),
             &[ctx.accounts.case_lookup.patient_escrow_bump]
         ];
 
         let signer_seeds = &[&seeds[..]];
- 
+
         let transfer_ix = solana_program::system_instruction::transfer(
             &ctx.accounts.patient_escrow.key(),
             &ctx.accounts.facility_address.key(),
-            actual_escrow_balance
+            amount_released
         );
- 
+
         solana_program::program::invoke_signed(
             &transfer_ix,
             &[
@@ -56,93 +86,93 @@ pub fn release_funds<'info>(ctx: Context<'_, '_, '_, 'info, ReleaseFunds<'info>>
             signer_seeds
         )?;
 
-         // Update Patient Case With This Transferred Amount Only When There Was A Sol Transfer
+        // Update Patient Case With This Transferred Amount
         ctx.accounts.patient_case.total_sol_raised = ctx.accounts.patient_case.total_sol_raised
-            .checked_sub(actual_escrow_balance).ok_or(CuraChainError::UnderflowError)?;
+            .saturating_sub(amount_released);
 
-    }
+    } else {
+
+        // ------- TOKEN INVOICES ARE PAID FROM THE PATIENT TOKEN VAULT OF THAT MINT  ------- //
+        require!(ctx.remaining_accounts.len() >= 3, CuraChainError::InvalidMintsLength);
+
+        let token_mint_info = &ctx.remaining_accounts[0];
+        let patient_token_vault = &ctx.remaining_accounts[1];
+        let facility_token_ata = &ctx.remaining_accounts[2];
+
+        require!(token_mint_info.key() == invoice_mint, CuraChainError::InvalidRemainingMints);
 
-    // ------- SPL TOKENS TRANSFER TO TREATMENT FACILITY ATA IF THERE WERE SPL DONATIONS MADE  ------- //
-    if ctx.accounts.patient_case.spl_donations.len() > 0 {
-
-        // Iterate Through The Spl Donations, And For Each, Create A Facility ATA if it doesn't exist, and
-        // Transfer The Donated Tokens To The Facility ATA
-        let donations_size = ctx.accounts.patient_case.spl_donations.len();
-        for spl_donation in 0..donations_size {
-
-            require!(ctx.remaining_accounts.len() >= donations_size * 3, CuraChainError::InvalidMintsLength);
-
-            let token_mint_info = &ctx.remaining_accounts[spl_donation * 3 + 0];
-            let patient_token_vault = &ctx.remaining_accounts[spl_donation * 3 + 1];
-            let facility_token_ata = &ctx.remaining_accounts[spl_donation * 3 + 2];
-
-            let decimals = Mint::unpack(&token_mint_info.try_borrow_data()?)?.decimals;
-
-            let each_spl_donation = &mut ctx.accounts.patient_case.spl_donations[spl_donation];
-
-            require!(token_mint_info.key() == each_spl_donation.mint, CuraChainError::InvalidRemainingMints);
-            require!(patient_token_vault.key() == each_spl_donation.patient_token_vault, CuraChainError::InvalidRemainingVaults);
-            // 1. Get Patient Token Vault That Holds The Donated Tokens
-            // 2. Create Facility ATA for That Token If It Doesn't Exist
-            // 3. Initiate The Transfer From The Patient Token Vault to Facility ATA
-            let (patient_vault, _vault_bump) = Pubkey::find_program_address(
-                &[
-                    b"patient_token_vault",
-                    case_id.as_bytes().as_ref(),
-                    ctx.accounts.patient_escrow.key().as_ref(),
-                    each_spl_donation.mint.as_ref()
-                ],
-                ctx.program_id
-            );
-
-            require!(patient_token_vault.key() == patient_vault, CuraChainError::InvalidRemainingVaults);
-
-            // For No Explicit Check, We use the `create_idempotent` function which creates the ATA if 
-            // it doesn't exist, and does nothing if it exists, just like the init_if_needed anchor constraint
-            let facility_ata = get_associated_token_address(
-                &ctx.accounts.facility_address.key(),
-                &token_mint_info.key()
-            );
-            
-            let required_accounts = Create {
-                payer: ctx.accounts.transfer_authority.to_account_info(),
-                associated_token: facility_token_ata.clone(),
-                authority: ctx.accounts.facility_address.to_account_info(),
-                mint: token_mint_info.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info()
-            };
-            let token_cpi = ctx.accounts.associated_token.to_account_info();
-            let cpi_ctx = CpiContext::new(token_cpi, required_accounts);
-            // Call The create_idempotent function
-            create_idempotent(cpi_ctx)?;
-            require!(facility_token_ata.key() == facility_ata, CuraChainError::MismatchedFacilityAtas);
-
-            // After Creating, ====>>> Make Transfer 
-            let transfer_accounts = TransferChecked {
-                from: patient_token_vault.clone(),
-                mint: token_mint_info.clone(),
-                to: facility_token_ata.clone(),
-                authority: ctx.accounts.multisig.to_account_info()
-            };
-            let transfer_program = ctx.accounts.token_program.to_account_info();
-            let seeds = &[
+        let decimals = Mint::unpack(&token_mint_info.try_borrow_data()?)?.decimals;
+
+        let each_spl_donation = ctx.accounts.patient_case.spl_donations
+            .iter_mut()
+            .find(|donation| donation.mint == invoice_mint)
+            .ok_or(CuraChainError::NoFundsInInvoiceMint)?;
+
+        require!(patient_token_vault.key() == each_spl_donation.patient_token_vault, CuraChainError::InvalidRemainingVaults);
+        // 1. Get Patient Token Vault That Holds The Donated Tokens
+        // 2. Create Facility ATA for That Token If It Doesn't Exist
+        // 3. Initiate The Transfer From The Patient Token Vault to Facility ATA
+        let (patient_vault, _vault_bump) = Pubkey::find_program_address(
+            &[
+                b"patient_token_vault",
+                case_id_seed.as_ref(),
+                ctx.accounts.patient_escrow.key().as_ref(),
+                each_spl_donation.mint.as_ref()
+            ],
+            ctx.program_id
+        );
+
+        require!(patient_token_vault.key() == patient_vault, CuraChainError::InvalidRemainingVaults);
+
+        amount_released = std::cmp::min(each_spl_donation.total_mint_amount, release_cap);
+        require!(amount_released > 0, CuraChainError::NoFundsInInvoiceMint);
+
+        // For No Explicit Check, We use the `create_idempotent` function which creates the ATA if
+        // it doesn't exist, and does nothing if it exists, just like the init_if_needed anchor constraint
+        let facility_ata = get_associated_token_address(
+            &ctx.accounts.facility_address.key(),
+            &token_mint_info.key()
+        );
+
+        let required_accounts = Create {
+            payer: ctx.accounts.transfer_authority.to_account_info(),
+            associated_token: facility_token_ata.clone(),
+            authority: ctx.accounts.facility_address.to_account_info(),
+            mint: token_mint_info.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info()
+        };
+        let token_cpi = ctx.accounts.associated_token.to_account_info();
+        let cpi_ctx = CpiContext::new(token_cpi, required_accounts);
+        // Call The create_idempotent function
+        create_idempotent(cpi_ctx)?;
+        require!(facility_token_ata.key() == facility_ata, CuraChainError::MismatchedFacilityAtas);
+
+        // After Creating, ====>>> Make Transfer
+        let transfer_accounts = TransferChecked {
+            from: patient_token_vault.clone(),
+            mint: token_mint_info.clone(),
+            to: facility_token_ata.clone(),
+            authority: ctx.accounts.multisig.to_account_info()
+        };
+        let transfer_program = ctx.accounts.token_program.to_account_info();
+        let seeds = &[
             b"multisig",
             b"escrow-authority".as_ref(),
             &[ctx.accounts.multisig.multisig_bump]
         ];
-            let multisig_seeds = &[&seeds[..]];
-            let transfer_cpi = CpiContext::new_with_signer(transfer_program, transfer_accounts, multisig_seeds);
-            transfer_checked(transfer_cpi, each_spl_donation.total_mint_amount, decimals)?;
+        let multisig_seeds = &[&seeds[..]];
+        let transfer_cpi = CpiContext::new_with_signer(transfer_program, transfer_accounts, multisig_seeds);
+        transfer_checked(transfer_cpi, amount_released, decimals)?;
 
-            // Let's Update The Accounting of spl Donations on the Patient Case
-            each_spl_donation.total_mint_amount = 0;
-        }
+        // Let's Update The Accounting of spl Donations on the Patient Case
+        each_spl_donation.total_mint_amount = each_spl_donation.total_mint_amount
+            .checked_sub(amount_released).ok_or(CuraChainError::UnderflowError)?;
     }
 
-    // For total_amount_needed, only subtract the minimum of (actual_escrow_balance, total_amount_needed) to
+    // For total_amount_needed, only subtract the minimum of (amount_released, total_amount_needed) to
     // prevent underflow
-    let amount_to_subtract = std::cmp::min(actual_escrow_balance, ctx.accounts.patient_case.total_amount_needed);
+    let amount_to_subtract = std::cmp::min(amount_released, ctx.accounts.patient_case.total_amount_needed);
     ctx.accounts.patient_case.total_amount_needed = ctx.accounts.patient_case.total_amount_needed
         .checked_sub(amount_to_subtract).ok_or(CuraChainError::UnderflowError)?;
 
@@ -151,25 +181,82 @@ pub fn release_funds<'info>(ctx: Context<'_, '_, '_, 'info, ReleaseFunds<'info>>
         ctx.accounts.patient_case.case_funded = false;
     }
 
+    ctx.accounts.patient_case.total_released = ctx.accounts.patient_case.total_released
+        .checked_add(amount_released).ok_or(CuraChainError::OverflowError)?;
+
     // Mark Proposal As Executed To Prevent Replaying
     ctx.accounts.proposal.executed = true;
 
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    let invoice_key = ctx.accounts.invoice.key();
+    ctx.accounts.patient_case.releases.push(ReleaseRecord {
+        proposal_index,
+        invoice: invoice_key,
+        mint: invoice_mint,
+        amount: amount_released,
+        slot: clock.slot,
+        released_at: current_time,
+    });
+
+    // Advance The Treatment Plan, Moving To The Following Milestone Once This One Is Fully Paid
+    if let Some(treatment_plan) = ctx.accounts.treatment_plan.as_mut() {
+        let milestone_index = treatment_plan.next_milestone;
+        treatment_plan.record_release(amount_released)?;
+
+        let milestone = treatment_plan.milestones[milestone_index as usize];
+        let message = format!("Milestone {} Of Patient Case ID, {} Has Been Paid {} Of {} At Time, {}",
+            milestone_index, case_id, milestone.amount_released, milestone.amount, current_time);
+
+        emit!(MilestoneReleased {
+            message,
+            case_id: case_id.clone(),
+            milestone_index,
+            amount_released_now: amount_released,
+            milestone_amount_released: milestone.amount_released,
+            milestone_amount: milestone.amount,
+            plan_total_released: treatment_plan.total_released,
+            timestamp: current_time,
+        });
+    }
+
+    // Settle The Invoice, Fully Or Partially
+    let invoice = &mut ctx.accounts.invoice;
+    invoice.record_payment(amount_released)?;
+
 
     // EMIT AN EVENT FOR THIS INSTRUCTION ON-CHAIN ANYTIME THERE IS A RELEASE OF FUNDS
-    let current_time = Clock::get()?.unix_timestamp;
     let message = format!("Contributed Funds of amount {} has been released for patient case ID ,{} at time of ,{}",
-         actual_escrow_balance, case_id, current_time);
+         amount_released, case_id, current_time);
 
     emit!(
         ReleaseOfFunds{
             message,
             treatment_address: ctx.accounts.facility_address.key(),
-            transferred_amount: actual_escrow_balance,
-            case_id: case_id,
+            invoice: invoice.key(),
+            mint: invoice_mint,
+            transferred_amount: amount_released,
+            case_id: case_id.clone(),
             timestamp: current_time
         }
     );
 
+    let message = format!("Invoice, {} For Patient Case ID, {} Has Been Paid {} Of {} At Time, {}",
+        invoice.key(), case_id, invoice.amount_paid, invoice.amount, current_time);
+
+    emit!(InvoicePaid {
+        message,
+        case_id,
+        invoice: invoice.key(),
+        proposal_index,
+        amount_paid_now: amount_released,
+        total_paid: invoice.amount_paid,
+        outstanding: invoice.outstanding(),
+        status: invoice.status,
+        timestamp: current_time,
+    });
+
 
     Ok(())
 }
\ No newline at end of file
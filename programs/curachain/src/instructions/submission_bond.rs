@@ -0,0 +1,259 @@
+use anchor_lang::{prelude::*, solana_program};
+use anchor_spl::{
+    associated_token::{self, create_idempotent, get_associated_token_address_with_program_id, Create},
+    token_interface::{close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked}};
+
+use crate::states::{errors::*, events::*, format_case_id, BondOutcome, PatientCase, PostedBond, ProtocolConfig, NATIVE_SOL_ADDRESS};
+
+
+/*
+Every Case Submission Posts A Refundable Bond, So Filing Spam Cases Costs More Than Rent.
+1. SOL Bonds Sit In The Case's Bond Vault PDA; SPL Bonds Sit In That PDA's Associated Token Account.
+2. The Bond Is Refunded To The Submitter When The Case Is Verified, Or Withdrawn Before Any Vote.
+3. It Is Forfeited To The Configured Pool When Verifiers Reject The Case Or The Admin Flags It.
+4. Mints Of Either Token Program Are Accepted, Token-2022 Extensions Included; Settling Empties And Closes The
+   Bond Vault ATA, Returning Its Rent To Whoever Paid The Case Rent, Who Also Created The ATA When Posting.
+5. A SOL Bond Vault Is A Plain System Account. If The Bond Alone Would Leave It Below Its Rent-Exempt Minimum,
+   The Depositor Also Pays The Shortfall, Which Is Swept Out With The Bond On Settlement.
+
+Remaining Accounts For SPL Bonds:
+- Posting: Bond Mint, Depositor Token Account, Bond Vault ATA, Token Program, Associated Token Program
+- Settling: Bond Mint, Bond Vault ATA, Recipient Token Account, Token Program, Rent Payer
+*/
+pub fn post_submission_bond<'info>(
+    case_id: u64,
+    protocol_config: &ProtocolConfig,
+    depositor: &AccountInfo<'info>,
+    bond_vault: &AccountInfo<'info>,
+    vault_bump: u8,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>])
+    -> Result<Option<PostedBond>> {
+
+    let amount = protocol_config.submission_bond_amount;
+    if amount == 0 {
+        return Ok(None);
+    }
+    let mint = protocol_config.submission_bond_mint;
+
+    if mint == NATIVE_SOL_ADDRESS {
+
+        let rent_top_up = sol_bond_rent_top_up(bond_vault.lamports(), amount, Rent::get()?.minimum_balance(0));
+        let deposit = amount.checked_add(rent_top_up).ok_or(CuraChainError::OverflowError)?;
+
+        let transfer_ix = solana_program::system_instruction::transfer(&depositor.key(), &bond_vault.key(), deposit);
+        solana_program::program::invoke(
+            &transfer_ix,
+            &[depositor.clone(), bond_vault.clone(), system_program.clone()]
+        )?;
+
+    } else {
+
+        require!(remaining_accounts.len() >= 5, CuraChainError::InvalidMintsLength);
+
+        let mint_info = &remaining_accounts[0];
+        let depositor_token_account = &remaining_accounts[1];
+        let bond_vault_ata = &remaining_accounts[2];
+        let token_program = &remaining_accounts[3];
+        let associated_token_program = &remaining_accounts[4];
+
+        require!(mint_info.key() == mint, CuraChainError::InvalidRemainingMints);
+        require!(is_token_program(token_program), CuraChainError::InvalidTokenProgram);
+        require!(associated_token_program.key() == associated_token::ID, CuraChainError::InvalidTokenProgram);
+        require!(
+            bond_vault_ata.key() == get_associated_token_address_with_program_id(&bond_vault.key(), &mint, &token_program.key()),
+            CuraChainError::InvalidBondVault
+        );
+
+        // Create The Bond Vault's Token Account If This Is Its First Bond, Then Move The Bond Into It
+        let required_accounts = Create {
+            payer: depositor.clone(),
+            associated_token: bond_vault_ata.clone(),
+            authority: bond_vault.clone(),
+            mint: mint_info.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        };
+        create_idempotent(CpiContext::new(associated_token_program.clone(), required_accounts))?;
+
+        let decimals = mint_decimals(mint_info, token_program)?;
+        let transfer_accounts = TransferChecked {
+            from: depositor_token_account.clone(),
+            mint: mint_info.clone(),
+            to: bond_vault_ata.clone(),
+            authority: depositor.clone(),
+        };
+        transfer_checked(CpiContext::new(token_program.clone(), transfer_accounts), amount, decimals)?;
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let display_case_id = format_case_id(case_id);
+    let message = format!("A Submission Bond Of, {} Has Been Posted For Patient Case With ID, {} At Time, {}",
+        amount, display_case_id, current_time);
+
+    emit!(SubmissionBondPosted {
+        message,
+        case_id: display_case_id,
+        depositor: depositor.key(),
+        mint,
+        amount,
+        timestamp: current_time,
+    });
+
+    Ok(Some(PostedBond { mint, amount, vault_bump }))
+}
+
+
+// Pays Out Whatever Bond The Case Still Holds; Cases Submitted With No Bond Are Left As They Are
+pub fn settle_submission_bond<'info>(
+    patient_case: &mut PatientCase,
+    outcome: BondOutcome,
+    expected_recipient: Pubkey,
+    bond_vault: &AccountInfo<'info>,
+    bond_recipient: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>])
+    -> Result<()> {
+
+    let Some(bond) = patient_case.submission_bond else {
+        return Ok(());
+    };
+
+    require!(bond_recipient.key() == expected_recipient, CuraChainError::InvalidBondRecipient);
+
+    let case_id_seed = patient_case.case_id.to_le_bytes();
+    let seeds = &[b"bond_vault".as_ref(), case_id_seed.as_ref(), &[bond.vault_bump]];
+    let bond_vault_pda = Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| error!(CuraChainError::InvalidBondVault))?;
+    require!(bond_vault.key() == bond_vault_pda, CuraChainError::InvalidBondVault);
+
+    let signer_seeds = &[&seeds[..]];
+
+    if bond.mint == NATIVE_SOL_ADDRESS {
+
+        // Empty The Vault Entirely, So The Account Itself Is Removed
+        let transfer_ix = solana_program::system_instruction::transfer(&bond_vault.key(), &bond_recipient.key(), bond_vault.lamports());
+        solana_program::program::invoke_signed(
+            &transfer_ix,
+            &[bond_vault.clone(), bond_recipient.clone(), system_program.clone()],
+            signer_seeds
+        )?;
+
+    } else {
+
+        require!(remaining_accounts.len() >= 5, CuraChainError::InvalidMintsLength);
+
+        let mint_info = &remaining_accounts[0];
+        let bond_vault_ata = &remaining_accounts[1];
+        let recipient_token_account = &remaining_accounts[2];
+        let token_program = &remaining_accounts[3];
+        let rent_payer = &remaining_accounts[4];
+
+        require!(mint_info.key() == bond.mint, CuraChainError::InvalidRemainingMints);
+        require!(is_token_program(token_program), CuraChainError::InvalidTokenProgram);
+        require!(
+            bond_vault_ata.key() == get_associated_token_address_with_program_id(&bond_vault.key(), &bond.mint, &token_program.key()),
+            CuraChainError::InvalidBondVault
+        );
+        require!(rent_payer.key() == patient_case.rent_payer(), CuraChainError::InvalidBondRecipient);
+
+        let recipient_account = TokenAccount::try_deserialize(&mut &recipient_token_account.try_borrow_data()?[..])?;
+        require!(
+            recipient_account.owner == expected_recipient && recipient_account.mint == bond.mint,
+            CuraChainError::InvalidBondRecipient
+        );
+
+        // Pay Out Everything The Vault Holds, Which Is Less Than The Bond If The Mint Charged A Transfer Fee On Posting
+        let vault_balance = TokenAccount::try_deserialize(&mut &bond_vault_ata.try_borrow_data()?[..])?.amount;
+        if vault_balance > 0 {
+            let decimals = mint_decimals(mint_info, token_program)?;
+            let transfer_accounts = TransferChecked {
+                from: bond_vault_ata.clone(),
+                mint: mint_info.clone(),
+                to: recipient_token_account.clone(),
+                authority: bond_vault.clone(),
+            };
+            transfer_checked(
+                CpiContext::new_with_signer(token_program.clone(), transfer_accounts, signer_seeds),
+                vault_balance,
+                decimals
+            )?;
+        }
+
+        // The Empty Vault ATA Is Closed, Its Rent Going Back To Whoever Created It
+        let close_accounts = CloseAccount {
+            account: bond_vault_ata.clone(),
+            destination: rent_payer.clone(),
+            authority: bond_vault.clone(),
+        };
+        close_account(CpiContext::new_with_signer(token_program.clone(), close_accounts, signer_seeds))?;
+    }
+
+    patient_case.submission_bond = None;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A SUBMISSION BOND IS REFUNDED OR FORFEITED
+    let current_time = Clock::get()?.unix_timestamp;
+    let display_case_id = format_case_id(patient_case.case_id);
+    let message = format!("Submission Bond Of Patient Case With ID, {} Has Been {:?} To, {} At Time, {}",
+        display_case_id, outcome, expected_recipient, current_time);
+
+    emit!(SubmissionBondSettled {
+        message,
+        case_id: display_case_id,
+        outcome,
+        recipient: expected_recipient,
+        mint: bond.mint,
+        amount: bond.amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+fn is_token_program(token_program: &AccountInfo) -> bool {
+    token_program.key() == anchor_spl::token::ID || token_program.key() == anchor_spl::token_2022::ID
+}
+
+
+// Decoded With Room For Token-2022 Extensions, And Only From A Mint Owned By The Token Program Being Called
+fn mint_decimals(mint_info: &AccountInfo, token_program: &AccountInfo) -> Result<u8> {
+    require!(mint_info.owner == token_program.key, CuraChainError::InvalidTokenProgram);
+    Ok(Mint::try_deserialize(&mut &mint_info.try_borrow_data()?[..])?.decimals)
+}
+
+
+// What The Depositor Pays On Top Of A SOL Bond So The Vault Is Rent-Exempt Once The Bond Lands
+fn sol_bond_rent_top_up(vault_lamports: u64, amount: u64, rent_minimum: u64) -> u64 {
+    rent_minimum.saturating_sub(vault_lamports.saturating_add(amount))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENT_MINIMUM: u64 = 890_880;
+
+    #[test]
+    fn bond_below_the_rent_minimum_is_topped_up_to_it() {
+        let bond = 500_000;
+        let top_up = sol_bond_rent_top_up(0, bond, RENT_MINIMUM);
+
+        assert_eq!(top_up, RENT_MINIMUM - bond);
+        assert_eq!(bond + top_up, RENT_MINIMUM);
+    }
+
+    #[test]
+    fn bond_at_or_above_the_rent_minimum_needs_no_top_up() {
+        assert_eq!(sol_bond_rent_top_up(0, RENT_MINIMUM, RENT_MINIMUM), 0);
+        assert_eq!(sol_bond_rent_top_up(0, 10 * RENT_MINIMUM, RENT_MINIMUM), 0);
+    }
+
+    #[test]
+    fn lamports_already_in_the_vault_count_towards_the_minimum() {
+        assert_eq!(sol_bond_rent_top_up(RENT_MINIMUM - 100, 40, RENT_MINIMUM), 60);
+        assert_eq!(sol_bond_rent_top_up(RENT_MINIMUM, 1, RENT_MINIMUM), 0);
+        assert_eq!(sol_bond_rent_top_up(u64::MAX, u64::MAX, RENT_MINIMUM), 0);
+    }
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, contexts::*, errors::*, events::*, format_case_id, Milestone, ProofType, TreatmentPlan};
+
+
+/*
+Long Treatments Are Paid In Stages Instead Of One Lump Sum.
+1. Before Verification, The Case Manager Lays Out Ordered Milestones, Each With An Amount And The Proof It Needs.
+2. The Next Milestone's Proof Is Recorded Either By The Bound Facility Or By A Registered Verifier,
+   Only While The Case Is Verified And Neither Refunding Donors Nor Redirecting Its Funds.
+3. release_funds Then Pays At Most What Is Left On That Milestone, Moving On Once It Is Fully Paid.
+*/
+pub fn set_treatment_plan(ctx: Context<SetTreatmentPlan>, _case_id: String, milestones: Vec<MilestoneSpec>) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let patient_case = &mut ctx.accounts.patient_case;
+
+    // Verifiers Approve The Plan Along With The Case, So It Is Frozen Afterwards
+    require!(!patient_case.is_verified, CuraChainError::TreatmentPlanLocked);
+
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        CuraChainError::InvalidTreatmentPlan
+    );
+    require!(milestones.iter().all(|m| m.amount > 0), CuraChainError::InvalidTreatmentPlan);
+
+    let milestone_amounts: Vec<u64> = milestones.iter().map(|m| m.amount).collect();
+
+    ctx.accounts.treatment_plan.set_inner(TreatmentPlan {
+        case_id: patient_case.case_id,
+        milestones: milestones
+            .into_iter()
+            .map(|m| Milestone {
+                amount: m.amount,
+                required_proof: m.required_proof,
+                proof_hash: None,
+                attested_by: None,
+                attested_at: 0,
+                amount_released: 0,
+            })
+            .collect(),
+        next_milestone: 0,
+        total_released: 0,
+        plan_bump: ctx.bumps.treatment_plan,
+    });
+
+    patient_case.treatment_plan = Some(ctx.accounts.treatment_plan.key());
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let message = format!("Patient Case With ID, {} Now Has A Treatment Plan Of {} Milestones At Time, {}",
+        case_id, milestone_amounts.len(), current_time);
+
+    emit!(TreatmentPlanSet {
+        message,
+        case_id,
+        milestone_amounts,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+pub fn attest_milestone(ctx: Context<AttestMilestone>, _case_id: String, milestone_index: u8, proof_hash: [u8; 32]) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    ctx.accounts.patient_case.ensure_in_treatment()?;
+
+    let attested_by = ctx.accounts.facility_signer.key();
+    record_milestone_proof(&mut ctx.accounts.treatment_plan, case_id, milestone_index, ProofType::FacilityAttestation, proof_hash, attested_by)
+}
+
+
+pub fn sign_off_milestone(ctx: Context<SignOffMilestone>, _case_id: String, milestone_index: u8, proof_hash: [u8; 32]) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+    ctx.accounts.patient_case.ensure_in_treatment()?;
+
+    let attested_by = ctx.accounts.verifier.key();
+    record_milestone_proof(&mut ctx.accounts.treatment_plan, case_id, milestone_index, ProofType::VerifierSignOff, proof_hash, attested_by)
+}
+
+
+fn record_milestone_proof(
+    treatment_plan: &mut TreatmentPlan,
+    case_id: String,
+    milestone_index: u8,
+    proof_type: ProofType,
+    proof_hash: [u8; 32],
+    attested_by: Pubkey)
+    -> Result<()> {
+
+    // Proofs Are Recorded In Order, So A Later Stage Can't Be Confirmed Before The Current One
+    require!(milestone_index == treatment_plan.next_milestone, CuraChainError::MilestoneOutOfOrder);
+    require!(proof_hash != [0u8; 32], CuraChainError::EmptyProofHash);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let milestone = treatment_plan.milestones
+        .get_mut(milestone_index as usize)
+        .ok_or(CuraChainError::AllMilestonesReleased)?;
+
+    require!(milestone.required_proof == proof_type, CuraChainError::WrongMilestoneProof);
+    require!(milestone.proof_hash.is_none(), CuraChainError::MilestoneProofAlreadyRecorded);
+
+    milestone.proof_hash = Some(proof_hash);
+    milestone.attested_by = Some(attested_by);
+    milestone.attested_at = current_time;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A MILESTONE IS CONFIRMED
+    let message = format!("Milestone {} Of Patient Case With ID, {} Has Been Confirmed By, {} At Time, {}",
+        milestone_index, case_id, attested_by, current_time);
+
+    emit!(MilestoneProofRecorded {
+        message,
+        case_id,
+        milestone_index,
+        proof_type,
+        proof_hash,
+        attested_by,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// One Stage Of A Treatment Plan, As Laid Out By The Case Manager
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct MilestoneSpec {
+    pub amount: u64,
+
+    pub required_proof: ProofType,
+}
\ No newline at end of file
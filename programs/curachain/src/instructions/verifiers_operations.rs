@@ -18,6 +18,7 @@ pub fn initialize_verifiers_list(ctx: Context<InitializeVerifiersRegistryMultisi
 
 
     verifiers_registry.all_verifiers = Vec::new();
+    verifiers_registry.total_weight = 0;
     verifiers_registry.verifier_registry_bump = ctx.bumps.verifiers_registry_list;
 
     multisig.multisig_admin = ctx.accounts.admin.key();
@@ -43,11 +44,16 @@ pub fn add_verifier(ctx: Context<VerifierInfo>, verifier_address: Pubkey) -> Res
     verifier_info.is_verifier = true;
     verifier_info.verifier_bump = ctx.bumps.verifier;
 
+    // A Verifier Added Back Keeps The Weight It Had; A New One Starts At 1
+    if verifier_info.vote_weight == 0 {
+        verifier_info.vote_weight = 1;
+    }
+
     // Let's Add This Verifier Address To The Global Verifiers Registry
     let verifiers_registry = &mut ctx.accounts.verifiers_list;
 
     // Add the verifier PDA account, and not just the address
-    verifiers_registry.add_verifier_pda_to_list(verifier_info.key())?;
+    verifiers_registry.add_verifier_pda_to_list(verifier_info.key(), verifier_info.vote_weight)?;
     let current_time = Clock::get()?.unix_timestamp;
 
     let message = format!("A Verifier With address, {} has been initialized to Global Registry of Verifiers At Time, {}", verifier_address, current_time);
@@ -71,7 +77,7 @@ pub fn remove_verifier(ctx: Context<VerifierInfo>, verifier_address: Pubkey) ->
     // Remove Verifier PDA from the Global Registry
     let verifiers_registry = &mut ctx.accounts.verifiers_list;
 
-    verifiers_registry.remove_verifier_pda_from_list(&verifier_info.key())?;
+    verifiers_registry.remove_verifier_pda_from_list(&verifier_info.key(), verifier_info.vote_weight)?;
 
     // Let's set verifier status to false
     verifier_info.is_verifier = false;
@@ -88,6 +94,37 @@ pub fn remove_verifier(ctx: Context<VerifierInfo>, verifier_address: Pubkey) ->
 }
 
 
+// The Registry's Total Weight Only Follows Verifiers Currently Listed
+pub fn set_verifier_weight(ctx: Context<SetVerifierWeight>, verifier_address: Pubkey, vote_weight: u16) -> Result<()> {
+
+    require!(vote_weight > 0, CuraChainError::InvalidVerifierWeight);
+
+    let verifier_info = &mut ctx.accounts.verifier;
+    require!(verifier_info.verifier_key == verifier_address, CuraChainError::InvalidVerifierAddress);
+
+    let previous_weight = std::mem::replace(&mut verifier_info.vote_weight, vote_weight);
+
+    let verifiers_registry = &mut ctx.accounts.verifiers_list;
+    if verifiers_registry.all_verifiers.contains(&verifier_info.key()) {
+        verifiers_registry.total_weight = verifiers_registry.total_weight
+            .checked_sub(previous_weight as u64).ok_or(CuraChainError::UnderflowError)?
+            .checked_add(vote_weight as u64).ok_or(CuraChainError::OverflowError)?;
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let message = format!("The Verifier with address, {} now votes with a weight of, {} at Time, {}", verifier_address, vote_weight, current_time);
+    emit!(VerifierWeightUpdated {
+        message,
+        address: verifier_address,
+        previous_weight,
+        vote_weight,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub enum VerifierOperationType {
     Add,
@@ -2,110 +2,213 @@ use anchor_lang::{prelude::*, solana_program::{self, rent::Rent/*, system_progra
 
 use solana_program::pubkey::Pubkey;
 
-use crate::states::{constants::{SCALE, ALLOWED_VERIFICATION_TIME}, contexts::*, errors::*, PatientCaseVerificationStatus};
+use crate::{instructions::{record_appeal_outcome, record_rejected_vote, settle_submission_bond}, states::{contexts::*, errors::*, format_case_id, BondOutcome, CaseAppeal, CaseIDLookup, CaseStatus, PatientCase, PatientCaseVerificationStatus, RoundOutcome, VerificationAwaitingCoApproval}, verification_policy::TallyOutcome};
 
 
 // Let's Write The Actual Verification Instruction
 // Where The Verifiers Will Specify the CASE_ID of the original format,
 // and then vote on the verification status of the patient case.
-pub fn approve_patient_case(ctx: Context<VerifyPatientCase>, case_id: String, is_yes: bool) -> Result<()> {
+pub fn approve_patient_case<'info>(ctx: Context<'_, '_, '_, 'info, VerifyPatientCase<'info>>, _case_id: String, is_yes: bool) -> Result<()> {
+
     // let's get the accounts under this context
 
     let patient_details = &mut ctx.accounts.patient_case;
     let verifier_to_vote = ctx.accounts.verifier.key();
-    let total_verifiers = ctx.accounts.verifiers_list.all_verifiers.len();
+    // Verifiers Recused From An Appeal Neither Vote Nor Count Towards Its Quorum
+    require!(
+        !patient_details.is_recused(&ctx.accounts.verifier_account.key()),
+        CuraChainError::VerifierRecused
+    );
 
-    // Check if the voting period has expired (10 days after submission)
+    // Once A Split Vote Goes To The Senior Panel, Verifiers Have Had Their Say
+    require!(patient_details.escalation.is_none(), CuraChainError::CaseEscalated);
+
+    // Voting Waits While A Verifier's Question Is Still Open, Unless The Patient Has Let It Lapse
     let now = Clock::get()?.unix_timestamp;
+    patient_details.lapse_unanswered_info_request(now)?;
+    require!(patient_details.case_status != CaseStatus::InfoRequested, CuraChainError::InfoRequestPending);
+
+    // Check if the voting period has expired (10 days after submission, plus any time spent paused)
     require!(
-        now < patient_details.submission_time + ALLOWED_VERIFICATION_TIME as i64,
+        now < patient_details.verification_deadline(now)?,
         CuraChainError::VotingPeriodExpired
     );
 
     // first check that patient case has not been already verified
     require!(patient_details.is_verified == false, CuraChainError::CaseAlreadyVerified);
 
+    // A Passed Vote Waiting On The Multisig Is Settled, So No More Votes Are Taken
+    require!(!patient_details.awaiting_co_approval, CuraChainError::CaseAwaitingCoApproval);
+
     //Check if verifier has already voted on this particular case,
     require!(
         patient_details.voted_verifiers.contains(&verifier_to_vote) == false,
         CuraChainError::VerifierAlreadyVoted
     );
 
-    // Let's record the respective votes,    
+    // Let's record the respective votes, along with the weight the verifier votes with
+    let vote_weight = ctx.accounts.verifier_account.vote_weight as u64;
     match is_yes {
-        true => patient_details.verification_yes_votes = patient_details.verification_yes_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?,
-        false => patient_details.verification_no_votes = patient_details.verification_no_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?,
+        true => {
+            patient_details.verification_yes_votes = patient_details.verification_yes_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+            patient_details.yes_weight = patient_details.yes_weight.checked_add(vote_weight).ok_or(CuraChainError::OverflowError)?;
+        },
+        false => {
+            patient_details.verification_no_votes = patient_details.verification_no_votes.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+            patient_details.no_weight = patient_details.no_weight.checked_add(vote_weight).ok_or(CuraChainError::OverflowError)?;
+        },
     };
 
     // Let's add the verifier to the voted verifiers list.
     patient_details.voted_verifiers.push(verifier_to_vote);
+    patient_details.voter_weights.push(ctx.accounts.verifier_account.vote_weight);
+
+    // The Case's Verification Policy Decides Whether The Vote Is Settled Yet
+    let tally = patient_details.tally(&ctx.accounts.verifiers_list);
+    let outcome = patient_details.verification_policy().evaluate(&tally);
+
+    // Without Early Approval, Votes Only Count Towards The Verdict finalize_verification Reaches After The Window
+    if !patient_details.allow_early_approval {
+        return Ok(());
+    }
+
+    if outcome == TallyOutcome::Approved {
+        settle_passed_vote(
+            patient_details,
+            &mut ctx.accounts.case_lookup,
+            &ctx.accounts.patient_escrow,
+            ctx.accounts.case_appeal.as_mut(),
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.bond_recipient.to_account_info(),
+            &ctx.accounts.verifier.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            now,
+        )?;
+    } else if outcome == TallyOutcome::Rejected {
+        // If not, we keep the patient case as unverified, and then anybody can call close_rejected_case,
+        // Unless The Vote Was Split Closely Enough To Go To The Senior Panel
+        record_rejected_vote(patient_details, &tally, tally.is_complete(), now)?;
+    }
+
+    Ok(())
+}
 
-    // Let's get the total votes
-    let total_votes = patient_details.verification_yes_votes.checked_add(patient_details.verification_no_votes).ok_or(CuraChainError::OverflowError)?;
-
-    // Let's type cast both total_votes and total_verifiers to u32 and SCALE to avoid overflow and precision loss
-    let total_votes_u32_scaled = (total_votes as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
-    let total_verifiers_u32_scaled = (total_verifiers as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
-
-    //Let's get Half Verifiers
-    let half_verifiers_scaled = total_verifiers_u32_scaled.checked_mul(50).ok_or(CuraChainError::OverflowError)?
-        .checked_div(100).ok_or(CuraChainError::OverflowError)?;
-    // Now, if total votes is 50% >= total_verifiers, it means more than half have voted.
-    if total_votes_u32_scaled > half_verifiers_scaled {
-        // Now, let's check if yes votes is 70% of total votes, then we mark patient case as verified.
-        // Let's get a 70% approval threshold
-        let approval_threshold_70_scaled = total_votes_u32_scaled.checked_mul(70).ok_or(CuraChainError::OverflowError)?
-            .checked_div(100).ok_or(CuraChainError::OverflowError)?;
-
-        let yes_votes_scaled = (patient_details.verification_yes_votes as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
-
-        if yes_votes_scaled >= approval_threshold_70_scaled {
-            patient_details.is_verified = true;
-
-            // Go Ahead and create the Patient Escrow PDA Account
-            create_escrow_pda(ctx)?;
-
-            // CATCHING THIS EVENT ON-CHAIN ANYTIME THIS INSTRUCTION OCCURS
-            let message = format!("Patient Case With ID, {} has successfully been verified!!!", case_id);
-            let current_time = Clock::get()?.unix_timestamp;
-            emit!(
-                PatientCaseVerificationStatus{
-                    message,
-                    case_id,
-                    is_verified: true,
-                    timestamp: current_time,
-                }
-            );
-        } else {
-            // If not, we keep the patient case as unverified, and then anybody can call close_rejected_case
-            patient_details.is_verified = false;
-
-        }
-    } 
+// A Vote That Passed Either Verifies The Case, Or Waits On Multisig Co-Approval When Its Amount Tier Asks For It
+#[allow(clippy::too_many_arguments)]
+pub fn settle_passed_vote<'info>(
+    patient_case: &mut Account<'info, PatientCase>,
+    case_id_lookup: &mut Account<'info, CaseIDLookup>,
+    patient_escrow: &AccountInfo<'info>,
+    case_appeal: Option<&mut Account<'info, CaseAppeal>>,
+    bond_vault: &AccountInfo<'info>,
+    bond_recipient: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    now: i64)
+    -> Result<()> {
+
+    if !patient_case.verification_tier.requires_multisig {
+        return complete_verification(
+            patient_case, case_id_lookup, patient_escrow, case_appeal, bond_vault, bond_recipient, payer, system_program, remaining_accounts, now
+        );
+    }
+
+    // Cases In The Higher Tiers Also Need Multisig Members To Co-Approve Before They Are Verified
+    patient_case.awaiting_co_approval = true;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME VERIFIERS PASS A CASE THAT NEEDS CO-APPROVAL
+    let case_id = format_case_id(patient_case.case_id);
+    let message = format!("Patient Case With ID, {} Has Passed Verification And Awaits Multisig Co-Approval", case_id);
+    emit!(VerificationAwaitingCoApproval {
+        message,
+        case_id,
+        yes_votes: patient_case.verification_yes_votes,
+        no_votes: patient_case.verification_no_votes,
+        timestamp: now,
+    });
 
     Ok(())
 }
 
-fn create_escrow_pda(ctx: Context<VerifyPatientCase>) -> Result<()> {
+// Marks The Case Verified, Refunds Its Submission Bond And Creates The Escrow Donations Are Held In
+#[allow(clippy::too_many_arguments)]
+pub fn complete_verification<'info>(
+    patient_case: &mut Account<'info, PatientCase>,
+    case_id_lookup: &mut Account<'info, CaseIDLookup>,
+    patient_escrow: &AccountInfo<'info>,
+    case_appeal: Option<&mut Account<'info, CaseAppeal>>,
+    bond_vault: &AccountInfo<'info>,
+    bond_recipient: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    now: i64)
+    -> Result<()> {
+
+    patient_case.is_verified = true;
+    patient_case.case_status = CaseStatus::Verified;
+    patient_case.awaiting_co_approval = false;
+    patient_case.rejected_at = None;
+
+    // An Appealed Case Records That Its Second Round Went Through
+    record_appeal_outcome(patient_case, case_appeal, RoundOutcome::Verified, now)?;
+
+    // A Verified Case Gets Its Submission Bond Back, To Whoever Posted It
+    let rent_payer = patient_case.rent_payer();
+    settle_submission_bond(
+        patient_case,
+        BondOutcome::Refunded,
+        rent_payer,
+        bond_vault,
+        bond_recipient,
+        system_program,
+        remaining_accounts,
+    )?;
 
-    
-    let patient_case_key = ctx.accounts.patient_case.key();
+    // Go Ahead and create the Patient Escrow PDA Account
+    create_patient_escrow(patient_case, case_id_lookup, patient_escrow, payer, system_program)?;
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A CASE IS VERIFIED
+    let case_id = format_case_id(patient_case.case_id);
+    let message = format!("Patient Case With ID, {} has successfully been verified!!!", case_id);
+    emit!(PatientCaseVerificationStatus {
+        message,
+        case_id,
+        is_verified: true,
+        timestamp: now,
+    });
+
+    Ok(())
+}
 
-    let case_id_lookup = &mut ctx.accounts.case_lookup;
+// Creates The System-Owned Escrow PDA SOL Donations Are Held In, Recording Its Bump And Payer In The Lookup
+pub fn create_patient_escrow<'info>(
+    patient_case: &Account<'info, PatientCase>,
+    case_id_lookup: &mut Account<'info, CaseIDLookup>,
+    patient_escrow: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>)
+    -> Result<()> {
+
+    let patient_case_key = patient_case.key();
+    let case_id_seed = patient_case.case_id.to_le_bytes();
 
     // Get Escrow PDA address using find_program_address
     let (patient_escrow_pda, _patient_escrow_bump) = Pubkey::find_program_address(
-        &[b"patient_escrow", ctx.accounts.patient_case.case_id.as_bytes(), patient_case_key.as_ref()],
-        ctx.program_id
+        &[b"patient_escrow", case_id_seed.as_ref(), patient_case_key.as_ref()],
+        &crate::ID
     );
 
     // Verify passed PDA account matches derived one
     require!(
-        *ctx.accounts.patient_escrow.key == patient_escrow_pda, CuraChainError::InvalidEscrowPDA
+        *patient_escrow.key == patient_escrow_pda, CuraChainError::InvalidEscrowPDA
     );
     
     // Let's store the patient_escrow pda bump into a field in the case_lookup 
     case_id_lookup.patient_escrow_bump = _patient_escrow_bump;
+    case_id_lookup.escrow_payer = payer.key();
 
     let rent = Rent::get()?;
     let space = 0;
@@ -113,7 +216,7 @@ fn create_escrow_pda(ctx: Context<VerifyPatientCase>) -> Result<()> {
 
       //Create the Escrow PDA Account, setting program_id as owner
     let create_escrow_ix = solana_program::system_instruction::create_account(
-        &ctx.accounts.verifier.key(),
+        &payer.key(),
         &patient_escrow_pda,
         lamports,
         0,
@@ -121,14 +224,14 @@ fn create_escrow_pda(ctx: Context<VerifyPatientCase>) -> Result<()> {
     );
 
     let accounts_needed = &[
-        ctx.accounts.verifier.to_account_info(),
-        ctx.accounts.patient_escrow.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
+        payer.clone(),
+        patient_escrow.clone(),
+        system_program.clone(),
     ];
 
     let seeds = &[
         b"patient_escrow",
-        ctx.accounts.patient_case.case_id.as_bytes().as_ref(),
+        case_id_seed.as_ref(),
         patient_case_key.as_ref(),
         &[_patient_escrow_bump],
     ];
@@ -147,4 +250,4 @@ fn create_escrow_pda(ctx: Context<VerifyPatientCase>) -> Result<()> {
     Ok(())
 
 
-}
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, format_case_id, CaseSummary, DonorSummary, PatientCase, ProposalStatus, ProtocolStats};
+
+
+/*
+Read-Only Views, Meant To Be Simulated Rather Than Sent.
+None Of Them Needs A Signer Or Writes To Any Account; Each Returns A Borsh-Encoded Struct Through
+The Transaction's Return Data, Decoded With The Matching Type In crate::states::views.
+*/
+pub fn get_case_summary(ctx: Context<GetCaseSummary>, _case_id: String) -> Result<CaseSummary> {
+
+    Ok(case_summary(&ctx.accounts.patient_case))
+}
+
+
+// Deprecated In Favour Of get_case_summary; Kept So Existing Clients Still Get The Case Logged
+pub fn view_patient_case(ctx: Context<PatientDetails>, _case_id: String) -> Result<()> {
+
+    let summary = case_summary(&ctx.accounts.patient_case);
+
+    msg!("--------------------- PATIENT CASE DETAILS -------------------------");
+
+    msg!("Specified Case ID is: {}", format_case_id(summary.case_id));
+    msg!("Patient Case Manifest URI: {}", summary.manifest.uri);
+    msg!("Patient Case Manifest Hash: {:?}", summary.manifest.hash);
+    msg!("Patient Case Manifest Version: {}", summary.manifest.version);
+    msg!("Patient Case Verification Status: {}", summary.is_verified);
+
+    msg!("Total Amount Needed For Case: {}", summary.total_amount_needed);
+    msg!("Total Amount Raised So Far: {}", summary.total_sol_raised);
+    msg!("Patient Case Funding Status: {}", summary.case_funded);
+
+    Ok(())
+}
+
+
+fn case_summary(patient_case: &PatientCase) -> CaseSummary {
+
+    CaseSummary {
+        case_id: patient_case.case_id,
+        beneficiary: patient_case.patient_pubkey,
+        submitter: patient_case.submitter,
+        sponsor: patient_case.sponsor,
+        case_manager: patient_case.case_manager,
+        urgency: patient_case.urgency,
+        manifest: patient_case.manifest.clone(),
+        case_status: patient_case.case_status,
+        is_verified: patient_case.is_verified,
+        verification_yes_votes: patient_case.verification_yes_votes,
+        verification_no_votes: patient_case.verification_no_votes,
+        funding_mode: patient_case.funding_mode,
+        funding_deadline: patient_case.funding_deadline,
+        total_amount_needed: patient_case.total_amount_needed,
+        total_sol_raised: patient_case.total_sol_raised,
+        spl_donations: patient_case.spl_donations.clone(),
+        goal_reached: patient_case.goal_reached,
+        case_funded: patient_case.case_funded,
+        total_released: patient_case.total_released,
+        facility: patient_case.facility,
+        treatment_plan: patient_case.treatment_plan,
+        submission_time: patient_case.submission_time,
+        update_count: patient_case.update_count,
+        duplicate_of: patient_case.duplicate_of,
+        escalation: patient_case.escalation.clone(),
+    }
+}
+
+
+pub fn get_donor_summary(ctx: Context<GetDonorSummary>, _donor: Pubkey) -> Result<DonorSummary> {
+
+    let donor_account = &ctx.accounts.donor_account;
+
+    Ok(DonorSummary {
+        donor_address: donor_account.donor_address,
+        total_donations: donor_account.total_donations,
+        donated_cases: donor_account.donated_cases.clone(),
+    })
+}
+
+
+pub fn get_proposal_status(ctx: Context<GetProposalStatus>, _case_id: String, _proposal_index: u64) -> Result<ProposalStatus> {
+
+    let proposal = &ctx.accounts.proposal;
+
+    let approvals = proposal.voted_multisig.iter().filter(|vote| vote.approval).count() as u8;
+    let rejections = proposal.voted_multisig.len() as u8 - approvals;
+
+    Ok(ProposalStatus {
+        case_id: proposal.case_id,
+        proposal_index: proposal.proposal_index,
+        invoice: proposal.invoice,
+        approvals,
+        rejections,
+        required_approvals: ctx.accounts.multisig.required_threshold,
+        approved: proposal.approved,
+        executed: proposal.executed,
+    })
+}
+
+
+pub fn get_protocol_stats(ctx: Context<GetProtocolStats>) -> Result<ProtocolStats> {
+
+    let protocol_config = &ctx.accounts.protocol_config;
+
+    Ok(ProtocolStats {
+        total_cases_submitted: ctx.accounts.case_counter.current_id,
+        total_verifiers: ctx.accounts.verifiers_list.all_verifiers.len() as u32,
+        multisig_members: ctx.accounts.multisig.multisig_members.len() as u8,
+        amendment_policy: protocol_config.amendment_policy,
+        expiry_policy: protocol_config.expiry_policy,
+        max_campaign_duration: protocol_config.max_campaign_duration,
+        submission_bond_amount: protocol_config.submission_bond_amount,
+        submission_bond_mint: protocol_config.submission_bond_mint,
+    })
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+
+use crate::{instructions::settle_submission_bond, states::{contexts::*, errors::*, events::*, BondOutcome, CaseStatus, PatientCase, RecordsFingerprint, format_case_id}};
+
+
+/*
+A Case That No Longer Needs Funding Can Be Withdrawn By Its Case Manager.
+1. Before Verification, The Patient Case And Its Case ID Lookup Are Closed, And Their Rent Goes Back To The Submitter.
+   The Submission Bond Is Refunded If No Verifier Has Voted Yet, And Forfeited Otherwise.
+   The Case Leaves Its Records' Live List, So Refiling The Same Records Is Not Flagged Against It.
+2. After Verification, Donors May Already Have Given, So The Case Stays Open And Moves Into Either
+   A Refunding State (Donors Reclaim Their Contributions) Or A Redirecting State (Multisig Redirects The Funds).
+*/
+pub fn withdraw_patient_case<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawPatientCase<'info>>, _case_id: String, outcome: WithdrawalOutcome) -> Result<()> {
+
+    let case_id = format_case_id(ctx.accounts.patient_case.case_id);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let patient_address = ctx.accounts.patient_case.patient_pubkey;
+
+    require!(is_withdrawable(ctx.accounts.patient_case.case_status), CuraChainError::CaseAlreadyWithdrawn);
+
+    let (case_closed, new_status) = if !ctx.accounts.patient_case.is_verified {
+
+        let (bond_outcome, bond_recipient) = release_unverified_case(
+            &ctx.accounts.patient_case,
+            &mut ctx.accounts.records_fingerprint,
+            ctx.accounts.protocol_config.bond_forfeit_pool,
+        );
+        settle_submission_bond(
+            &mut ctx.accounts.patient_case,
+            bond_outcome,
+            bond_recipient,
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.bond_recipient.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+
+        let submitter = ctx.accounts.submitter.to_account_info();
+        ctx.accounts.patient_case.close(submitter.clone())?;
+        ctx.accounts.case_lookup.close(submitter)?;
+
+        (true, None)
+    } else {
+
+        let new_status = outcome.case_status();
+        ctx.accounts.patient_case.case_status = new_status;
+
+        (false, Some(new_status))
+    };
+
+    // CATCHING THIS EVENT ON-CHAIN ANYTIME A PATIENT WITHDRAWS THEIR CASE
+    let message = format!("Patient Case With ID, {} Has Been Withdrawn By Its Case Manager At Time, {}", case_id, current_time);
+
+    emit!(PatientCaseWithdrawn {
+        message,
+        case_id,
+        patient_address,
+        case_closed,
+        new_status,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+
+// Cases Still Under Review Or Verified But Not Yet Wound Down Can Be Withdrawn
+fn is_withdrawable(case_status: CaseStatus) -> bool {
+    matches!(
+        case_status,
+        CaseStatus::PendingVerification | CaseStatus::InfoRequested | CaseStatus::Escalated | CaseStatus::Verified
+    )
+}
+
+
+// Withdrawing Before Any Vote Refunds The Bond; Pulling A Case Verifiers Have Started On Forfeits It
+fn withdrawal_bond_outcome(patient_case: &PatientCase, bond_forfeit_pool: Pubkey) -> (BondOutcome, Pubkey) {
+    if patient_case.voted_verifiers.is_empty() {
+        (BondOutcome::Refunded, patient_case.rent_payer())
+    } else {
+        (BondOutcome::Forfeited, bond_forfeit_pool)
+    }
+}
+
+
+// Takes An Unverified Case Off Its Records' Live List And Works Out Where Its Bond Goes
+fn release_unverified_case(patient_case: &PatientCase, records_fingerprint: &mut RecordsFingerprint, bond_forfeit_pool: Pubkey) -> (BondOutcome, Pubkey) {
+    records_fingerprint.release_case(patient_case.case_id);
+    withdrawal_bond_outcome(patient_case, bond_forfeit_pool)
+}
+
+
+// What Should Happen To Raised Funds When A Verified Case Is Withdrawn
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawalOutcome {
+    RefundDonors,
+
+    RedirectFunds,
+}
+
+impl WithdrawalOutcome {
+    // State The Verified Case Moves Into Once Withdrawn
+    pub fn case_status(self) -> CaseStatus {
+        match self {
+            WithdrawalOutcome::RefundDonors => CaseStatus::Refunding,
+            WithdrawalOutcome::RedirectFunds => CaseStatus::Redirecting,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::fixtures::{cast_vote, patient_case};
+
+    #[test]
+    fn only_open_cases_can_be_withdrawn() {
+        for status in [CaseStatus::PendingVerification, CaseStatus::InfoRequested, CaseStatus::Escalated, CaseStatus::Verified] {
+            assert!(is_withdrawable(status), "{:?} should be withdrawable", status);
+        }
+        for status in [CaseStatus::Refunding, CaseStatus::Redirecting, CaseStatus::Expired] {
+            assert!(!is_withdrawable(status), "{:?} should not be withdrawable", status);
+        }
+    }
+
+    #[test]
+    fn bond_is_refunded_to_the_rent_payer_before_any_vote() {
+        let forfeit_pool = Pubkey::new_unique();
+        let mut case = patient_case();
+        assert_eq!(withdrawal_bond_outcome(&case, forfeit_pool), (BondOutcome::Refunded, case.submitter));
+
+        // A Sponsored Case Refunds The Sponsor, Who Posted The Bond
+        let sponsor = Pubkey::new_unique();
+        case.sponsor = Some(sponsor);
+        assert_eq!(withdrawal_bond_outcome(&case, forfeit_pool), (BondOutcome::Refunded, sponsor));
+    }
+
+    #[test]
+    fn bond_is_forfeited_once_a_verifier_has_voted() {
+        let forfeit_pool = Pubkey::new_unique();
+        let mut case = patient_case();
+        cast_vote(&mut case, false);
+
+        assert_eq!(withdrawal_bond_outcome(&case, forfeit_pool), (BondOutcome::Forfeited, forfeit_pool));
+    }
+
+    fn records() -> RecordsFingerprint {
+        RecordsFingerprint {
+            fingerprint: [2; 32],
+            live_case_ids: Vec::new(),
+            submissions: 0,
+            first_submitted_at: 1_000,
+            fingerprint_bump: 255,
+        }
+    }
+
+    #[test]
+    fn refiling_withdrawn_records_is_not_flagged() {
+        let forfeit_pool = Pubkey::new_unique();
+        let mut records = records();
+        let case = patient_case();
+        assert_eq!(records.file_case(case.case_id).unwrap(), None);
+
+        assert_eq!(release_unverified_case(&case, &mut records, forfeit_pool), (BondOutcome::Refunded, case.submitter));
+
+        let mut refiled = patient_case();
+        refiled.case_id = case.case_id + 1;
+        assert_eq!(records.file_case(refiled.case_id).unwrap(), None);
+        assert_eq!(records.live_case_ids, vec![refiled.case_id]);
+        assert_eq!(records.submissions, 2);
+    }
+
+    #[test]
+    fn withdrawing_a_voted_case_forfeits_the_bond_but_still_frees_the_records() {
+        let forfeit_pool = Pubkey::new_unique();
+        let mut records = records();
+        let mut case = patient_case();
+        records.file_case(case.case_id).unwrap();
+        cast_vote(&mut case, false);
+
+        // Dodging A Looming Rejection Costs The Bond Each Time
+        assert_eq!(release_unverified_case(&case, &mut records, forfeit_pool), (BondOutcome::Forfeited, forfeit_pool));
+        assert!(records.live_case_ids.is_empty());
+
+        let mut refiled = patient_case();
+        refiled.case_id = case.case_id + 1;
+        assert_eq!(records.file_case(refiled.case_id).unwrap(), None);
+    }
+
+    #[test]
+    fn withdrawing_one_duplicate_leaves_the_other_flagged() {
+        let forfeit_pool = Pubkey::new_unique();
+        let mut records = records();
+        let first = patient_case();
+        let mut second = patient_case();
+        second.case_id = first.case_id + 1;
+        records.file_case(first.case_id).unwrap();
+        assert_eq!(records.file_case(second.case_id).unwrap(), Some(first.case_id));
+
+        release_unverified_case(&second, &mut records, forfeit_pool);
+
+        // The First Filing Is Still Live, So A Third Filing Is Flagged Against It
+        assert_eq!(records.live_case_ids, vec![first.case_id]);
+        assert_eq!(records.file_case(second.case_id + 1).unwrap(), Some(first.case_id));
+    }
+
+    #[test]
+    fn withdrawal_outcome_maps_to_the_wind_down_status() {
+        assert_eq!(WithdrawalOutcome::RefundDonors.case_status(), CaseStatus::Refunding);
+        assert_eq!(WithdrawalOutcome::RedirectFunds.case_status(), CaseStatus::Redirecting);
+    }
+}
@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 
 pub mod instructions;
+pub mod manifest;
+#[cfg(feature = "client")]
+pub mod records_envelope;
 pub mod states;
+pub mod verification_policy;
 
 use instructions::*;
 use states::*;
+use verification_policy::TallyOutcome;
 
 declare_id!("J6DDahS4mKkJwJ6i7dcqmhj1oRVxCd1nLPwWF6Bhjyf5");
 
@@ -42,6 +47,14 @@ pub mod curachain {
         Ok(())
     }
 
+    // Admin Sets How Much A Verifier's Vote Counts Under The Weighted Strategy
+    pub fn set_verifier_weight(ctx: Context<SetVerifierWeight>, verifier_address: Pubkey, vote_weight: u16) -> Result<()> {
+
+        instructions::verifiers_operations::set_verifier_weight(ctx, verifier_address, vote_weight)?;
+
+        Ok(())
+    }
+
 
     // Update Multisig Here
     pub fn update_multisig(ctx: Context<AddorRemoveMultisigMember>, member_addresses: Vec<Pubkey>, multisig_op_type: MultisigOperationType) -> Result<()> {
@@ -50,15 +63,57 @@ pub mod curachain {
         Ok(())
     }
 
+    // Admin Creates Or Updates The Protocol-Wide Config
+    pub fn update_protocol_config(ctx: Context<UpdateProtocolConfig>, config_args: ProtocolConfigArgs) -> Result<()> {
+
+        instructions::update_protocol_config(ctx, config_args)?;
+        Ok(())
+    }
+
     // Patient Submit Cases Here
-    pub fn submit_cases(ctx: Context<InitializePatientCase>, case_description: String, total_amount_needed: u64, 
-        link_to_records: String) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_cases<'info>(ctx: Context<'_, '_, '_, 'info, InitializePatientCase<'info>>, manifest: ManifestRef, records_fingerprint: [u8; 32],
+        manifest_body_hash: [u8; 32], total_amount_needed: u64, campaign_duration: i64, funding_mode: FundingMode, urgency: UrgencyLevel) -> Result<()> {
+
+        instructions::initialize_patient(ctx, manifest, records_fingerprint, manifest_body_hash, total_amount_needed, campaign_duration, funding_mode, urgency)?;
+        Ok(())
+    }
+
+    // A Guardian, Social Worker Or NGO Submits A Case On Behalf Of A Beneficiary
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_case_on_behalf<'info>(ctx: Context<'_, '_, '_, 'info, InitializeRepresentedPatientCase<'info>>, beneficiary: Pubkey, manifest: ManifestRef,
+        records_fingerprint: [u8; 32], manifest_body_hash: [u8; 32], total_amount_needed: u64, campaign_duration: i64, funding_mode: FundingMode,
+        urgency: UrgencyLevel) -> Result<()> {
+
+        instructions::initialize_represented_patient(ctx, beneficiary, manifest, records_fingerprint, manifest_body_hash, total_amount_needed, campaign_duration,
+            funding_mode, urgency)?;
+        Ok(())
+    }
+
+    // Beneficiary Takes Over Management Of A Case Submitted On Their Behalf
+    pub fn assume_case_control(ctx: Context<AssumeCaseControl>, case_id: String) -> Result<()> {
+
+        instructions::case_control::assume_case_control(ctx, case_id)?;
+        Ok(())
+    }
+
+    // Case Manager Amends The Case Before It Is Verified
+    pub fn amend_case(ctx: Context<AmendPatientCase>, case_id: String, new_manifest: Option<ManifestRef>,
+        new_total_amount_needed: Option<u64>, new_manifest_body_hash: Option<[u8; 32]>) -> Result<()> {
 
-        instructions::initialize_patient(ctx, case_description, total_amount_needed, link_to_records)?;
+        instructions::amend_patient_case(ctx, case_id, new_manifest, new_total_amount_needed, new_manifest_body_hash)?;
+        Ok(())
+    }
+
+    // Case Manager Withdraws The Case When Funding Is No Longer Needed
+    pub fn withdraw_case<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawPatientCase<'info>>, case_id: String, outcome: WithdrawalOutcome) -> Result<()> {
+
+        instructions::withdraw_patient_case(ctx, case_id, outcome)?;
         Ok(())
     }
 
     // A View Instruction To Get All Necessary Details About A Case
+    // Deprecated: Logs A Subset Of get_case_summary, Which New Clients Should Use Instead
     pub fn view_case_details(ctx: Context<PatientDetails>, case_id: String) -> Result<()> {
 
         instructions::view_patient_case(ctx, case_id)?;
@@ -66,8 +121,29 @@ pub mod curachain {
         Ok(())
     }
 
+    // Read-Only Views; Each Returns A Borsh-Encoded Struct Through Return Data And Needs No Signer
+    pub fn get_case_summary(ctx: Context<GetCaseSummary>, case_id: String) -> Result<CaseSummary> {
+
+        instructions::views::get_case_summary(ctx, case_id)
+    }
+
+    pub fn get_donor_summary(ctx: Context<GetDonorSummary>, donor: Pubkey) -> Result<DonorSummary> {
+
+        instructions::views::get_donor_summary(ctx, donor)
+    }
+
+    pub fn get_proposal_status(ctx: Context<GetProposalStatus>, case_id: String, proposal_index: u64) -> Result<ProposalStatus> {
+
+        instructions::views::get_proposal_status(ctx, case_id, proposal_index)
+    }
+
+    pub fn get_protocol_stats(ctx: Context<GetProtocolStats>) -> Result<ProtocolStats> {
+
+        instructions::views::get_protocol_stats(ctx)
+    }
+
     // Verifier attempts to approve a patient case
-    pub fn verify_patient(ctx: Context<VerifyPatientCase>, case_id: String, is_yes: bool) -> Result<()> {
+    pub fn verify_patient<'info>(ctx: Context<'_, '_, '_, 'info, VerifyPatientCase<'info>>, case_id: String, is_yes: bool) -> Result<()> {
 
         instructions::approve_patient_case(ctx, case_id, is_yes)?;
 
@@ -75,7 +151,7 @@ pub mod curachain {
     }
 
     // Admin Can Override Verification If Verifiers Are Delaying
-    pub fn admin_override_case(ctx: Context<AdminOverrideCase>, case_id: String, is_verified: bool) -> Result<()> {
+    pub fn admin_override_case<'info>(ctx: Context<'_, '_, '_, 'info, AdminOverrideCase<'info>>, case_id: String, is_verified: bool) -> Result<()> {
         instructions::admin_override_case(ctx, case_id, is_verified)?;
         Ok(())
     }
@@ -83,7 +159,7 @@ pub mod curachain {
 
     // Clearly Rejected Patient Case Must Be Closed
     #[access_control(check_patient_case_closable(&ctx, &case_id))]
-    pub fn close_rejected_case(ctx: Context<ClosePatientCase>, case_id: String) -> Result<()> {
+    pub fn close_rejected_case<'info>(ctx: Context<'_, '_, '_, 'info, ClosePatientCase<'info>>, case_id: String) -> Result<()> {
 
         instructions::close_rejected_case(ctx, case_id)?;
 
@@ -105,6 +181,107 @@ pub mod curachain {
         Ok(())
     }
 
+    // Anybody Can Expire A Case Whose Campaign Deadline Has Passed
+    pub fn expire_case(ctx: Context<ExpirePatientCase>, case_id: String) -> Result<()> {
+
+        instructions::expire_patient_case(ctx, case_id)?;
+        Ok(())
+    }
+
+    // Donors Reclaim Their SOL Contribution From A Refunding Case
+    pub fn claim_sol_refund(ctx: Context<ClaimSolRefund>, case_id: String) -> Result<()> {
+
+        instructions::claim_sol_refund(ctx, case_id)?;
+        Ok(())
+    }
+
+    // Donors Reclaim Their SPL Contribution From A Refunding Case
+    pub fn claim_token_refund(ctx: Context<ClaimTokenRefund>, case_id: String) -> Result<()> {
+
+        instructions::claim_token_refund(ctx, case_id)?;
+        Ok(())
+    }
+
+    // Verifier Publishes The x25519 Key Patients Seal Records Keys To
+    pub fn set_verifier_encryption_key(ctx: Context<SetVerifierEncryptionKey>, encryption_key: [u8; 32]) -> Result<()> {
+
+        instructions::records_access::set_verifier_encryption_key(ctx, encryption_key)?;
+        Ok(())
+    }
+
+    // Case Manager Shares The Sealed Records Key With One Verifier
+    pub fn grant_records_access(ctx: Context<GrantRecordsAccess>, case_id: String, verifier_address: Pubkey, envelope: SealedEnvelope) -> Result<()> {
+
+        instructions::records_access::grant_records_access(ctx, case_id, verifier_address, envelope)?;
+        Ok(())
+    }
+
+    // Case Manager Revokes A Verifier's Records Access
+    pub fn revoke_records_access(ctx: Context<RevokeRecordsAccess>, case_id: String, verifier_address: Pubkey) -> Result<()> {
+
+        instructions::records_access::revoke_records_access(ctx, case_id, verifier_address)?;
+        Ok(())
+    }
+
+    // Admin Or A Multisig Member Registers A Treatment Facility And Its Payout Wallet
+    pub fn register_facility(ctx: Context<RegisterFacility>, facility_key: Pubkey, name: String, payout_wallet: Pubkey) -> Result<()> {
+
+        instructions::facility_registry::register_facility(ctx, facility_key, name, payout_wallet)?;
+        Ok(())
+    }
+
+    // Admin Or A Multisig Member Changes A Facility's Payout Wallet Or Verification Status
+    pub fn update_facility(ctx: Context<UpdateFacility>, facility_key: Pubkey, new_payout_wallet: Option<Pubkey>,
+        new_status: Option<FacilityStatus>) -> Result<()> {
+
+        instructions::facility_registry::update_facility(ctx, facility_key, new_payout_wallet, new_status)?;
+        Ok(())
+    }
+
+    // Binds A Case To The Registered Facility Its Funds Will Be Paid To
+    pub fn bind_case_facility(ctx: Context<BindCaseFacility>, case_id: String, facility_key: Pubkey) -> Result<()> {
+
+        instructions::facility_registry::bind_case_facility(ctx, case_id, facility_key)?;
+        Ok(())
+    }
+
+    // The Facility A Case Is Bound To Bills The Case For Treatment
+    pub fn submit_invoice(ctx: Context<SubmitInvoice>, case_id: String, invoice_number_hash: [u8; 32], document_hash: [u8; 32],
+        mint: Pubkey, amount: u64, due_date: i64) -> Result<()> {
+
+        instructions::invoices::submit_invoice(ctx, case_id, invoice_number_hash, document_hash, mint, amount, due_date)?;
+        Ok(())
+    }
+
+    // Case Manager Splits Disbursement Into Ordered Milestones Before Verification
+    pub fn set_treatment_plan(ctx: Context<SetTreatmentPlan>, case_id: String, milestones: Vec<MilestoneSpec>) -> Result<()> {
+
+        instructions::treatment_plan::set_treatment_plan(ctx, case_id, milestones)?;
+        Ok(())
+    }
+
+    // The Bound Facility Confirms The Next Milestone Took Place
+    pub fn attest_milestone(ctx: Context<AttestMilestone>, case_id: String, milestone_index: u8, proof_hash: [u8; 32]) -> Result<()> {
+
+        instructions::treatment_plan::attest_milestone(ctx, case_id, milestone_index, proof_hash)?;
+        Ok(())
+    }
+
+    // A Verifier Signs Off On The Next Milestone
+    pub fn sign_off_milestone(ctx: Context<SignOffMilestone>, case_id: String, milestone_index: u8, proof_hash: [u8; 32]) -> Result<()> {
+
+        instructions::treatment_plan::sign_off_milestone(ctx, case_id, milestone_index, proof_hash)?;
+        Ok(())
+    }
+
+    // The Patient, Case Manager Or Bound Facility Posts A Progress Update For Donors
+    pub fn post_case_update(ctx: Context<PostCaseUpdate>, case_id: String, update_type: UpdateType, content_hash: [u8; 32],
+        uri: String) -> Result<()> {
+
+        instructions::case_updates::post_case_update(ctx, case_id, update_type, content_hash, uri)?;
+        Ok(())
+    }
+
     // ADMINISTRATOR/MULTISIG CREATES THE CURACHAIN COLLECTION NFT ON-CHAIN
     pub fn create_nft_collection(ctx: Context<InitializeNftCollection>, nft_uri: String) -> Result<()> {
 
@@ -140,6 +317,70 @@ pub mod curachain {
 
         Ok(())
     }
+
+    // ANYBODY ARCHIVES A FULLY RELEASED CASE INTO A COMPACT RECEIPT, RETURNING ITS RENT
+    pub fn finalize_and_archive_case<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeAndArchiveCase<'info>>, case_id: String) -> Result<()> {
+
+        instructions::finalize_and_archive_case(ctx, case_id)?;
+
+        Ok(())
+    }
+
+    // Patient Or Case Manager Appeals A Rejected Case Into A Second Verification Round
+    pub fn appeal_case(ctx: Context<AppealPatientCase>, case_id: String, evidence: ManifestRef) -> Result<()> {
+
+        instructions::appeal_patient_case(ctx, case_id, evidence)?;
+
+        Ok(())
+    }
+
+    // Multisig Member Co-Approves A Passed Case Whose Amount Tier Requires It
+    pub fn co_approve_verification<'info>(ctx: Context<'_, '_, '_, 'info, CoApproveVerification<'info>>, case_id: String) -> Result<()> {
+
+        instructions::co_approve_verification(ctx, case_id)?;
+
+        Ok(())
+    }
+
+    // Anybody Settles A Case's Verification Once Its Window Has Closed
+    pub fn finalize_verification<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeVerification<'info>>, case_id: String) -> Result<()> {
+
+        instructions::finalize_case_verification(ctx, case_id)?;
+
+        Ok(())
+    }
+
+    // Admin Adds Or Removes A Senior Verifier, Who Decides Split Votes Escalated To The Senior Panel
+    pub fn add_or_remove_senior_verifier(ctx: Context<UpdateSeniorPanel>, member: Pubkey, operation_type: VerifierOperationType) -> Result<()> {
+
+        instructions::update_senior_panel(ctx, member, operation_type)?;
+
+        Ok(())
+    }
+
+    // Senior Verifier Votes On A Case Escalated To The Senior Panel
+    pub fn senior_panel_vote<'info>(ctx: Context<'_, '_, '_, 'info, SeniorPanelVote<'info>>, case_id: String, is_yes: bool) -> Result<()> {
+
+        instructions::senior_panel_vote(ctx, case_id, is_yes)?;
+
+        Ok(())
+    }
+
+    // Verifier Pauses Verification To Ask The Patient For More Information
+    pub fn request_info(ctx: Context<RequestCaseInfo>, case_id: String, question_hash: [u8; 32]) -> Result<()> {
+
+        instructions::request_case_info(ctx, case_id, question_hash)?;
+
+        Ok(())
+    }
+
+    // Patient Or Case Manager Answers The Open Request, Resuming Verification
+    pub fn respond_to_info_request(ctx: Context<RespondToInfoRequest>, case_id: String, answer_hash: [u8; 32]) -> Result<()> {
+
+        instructions::respond_to_info_request(ctx, case_id, answer_hash)?;
+
+        Ok(())
+    }
     
 }
 
@@ -154,37 +395,46 @@ fn check_patient_case_closable(ctx: &Context<ClosePatientCase>, _case_id: &Strin
     // Check That Case Has Not Been Verified
     require!(patient_case.is_verified == false, CuraChainError::CaseAlreadyVerified);
 
-    // Get Total No and Yes Votes Cast On This Patient Case, and The Total Length of Eligible Verifiers
-    let patient_yes_votes = patient_case.verification_yes_votes;
-    let patient_no_votes = patient_case.verification_no_votes;
-    let total_verifiers = verifiers_registry.all_verifiers.len();
-
-    // Total Votes Cast On Patient Case
-    let patient_total_votes = patient_yes_votes + patient_no_votes;// Pretty Solid No Overflow Will Occur Here
-
-    // If No Votes Has Been Cast, It Means Patient Case Has Not Yet Been Verified At All
-    require!(patient_total_votes > 0, CuraChainError::CaseNotYetVerified);
-
-    // ................      We Need To Ensure At Least 50% Verifiers Have Cast Their Votes            ................//
-
-    // Let's type cast both total_votes and total_verifiers to u32 and SCALE to avoid overflow and precision loss
-    let total_votes_u32_scaled = (patient_total_votes as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
-    let total_verifiers_u32_scaled = (total_verifiers as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
-
-    //Let's get Half Verifiers
-    let half_verifiers_scaled = total_verifiers_u32_scaled.checked_mul(50).ok_or(CuraChainError::OverflowError)?
-        .checked_div(100).ok_or(CuraChainError::OverflowError)?;
-
-    require!( total_votes_u32_scaled >= half_verifiers_scaled, CuraChainError::NotEnoughVerifiers);
+    // A Split Vote Escalated To The Senior Panel Is Settled By The Panel Alone, And Its Rejection Is Final
+    if let Some(escalation) = &patient_case.escalation {
+        return match escalation.resolution {
+            None => err!(CuraChainError::CaseEscalated),
+            Some(resolution) if resolution.approved => err!(CuraChainError::CasePassedApproval),
+            Some(_) => Ok(()),
+        };
+    }
 
-    // We Need To Make Sure 70% quorum for Verification Approval Was Not Reached
-    let approval_threshold_70_scaled = total_votes_u32_scaled.checked_mul(70).ok_or(CuraChainError::OverflowError)?
-            .checked_div(100).ok_or(CuraChainError::OverflowError)?;
+    let tally = patient_case.tally(verifiers_registry);
+    let policy = patient_case.verification_policy();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Once The Window Has Closed The Verdict Is Final, Whether Or Not Anybody Voted
+    let outcome = if current_time >= patient_case.verification_deadline(current_time)? {
+        policy.finalize(&tally)
+    } else {
+        // If No Votes Has Been Cast, It Means Patient Case Has Not Yet Been Verified At All
+        require!(tally.votes_cast() > 0, CuraChainError::CaseNotYetVerified);
+        policy.evaluate(&tally)
+    };
+
+    // Only A Vote The Case's Verification Policy Has Settled As Rejected Can Be Closed
+    match outcome {
+        TallyOutcome::Pending => return err!(CuraChainError::NotEnoughVerifiers),
+        TallyOutcome::Approved => return err!(CuraChainError::CasePassedApproval),
+        TallyOutcome::Rejected => {},
+    }
 
-    let yes_votes_scaled = (patient_case.verification_yes_votes as u32).checked_mul(SCALE).ok_or(CuraChainError::OverflowError)?;
+    // The Rejection Must Have Been Recorded, By A Vote Or By finalize_verification, So Its Appeal Window Has Started
+    require!(patient_case.rejected_at.is_some(), CuraChainError::VerificationNotFinalized);
 
-    // Let's ensure that total yes votes was indeed less than the required 70%
-    require!(yes_votes_scaled < approval_threshold_70_scaled, CuraChainError::CasePassedApproval);
+    // A First-Round Rejection Stays Open Until The Patient's Appeal Window Has Passed
+    if patient_case.verification_round == 1 {
+        if let Some(rejected_at) = patient_case.rejected_at {
+            let appeal_deadline = rejected_at
+                .checked_add(ctx.accounts.protocol_config.appeal_window).ok_or(CuraChainError::OverflowError)?;
+            require!(current_time >= appeal_deadline, CuraChainError::AppealWindowOpen);
+        }
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,292 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::states::errors::*;
+
+
+/*
+CANONICAL CASE MANIFEST
+The Full Medical Case Lives Off-Chain, While PatientCase Only Keeps Its Version, URI And Hash.
+This Module Is The Single Definition Of That Manifest, Shared By The Program And Off-Chain Tools:
+1. The Manifest Is Borsh-Encoded, Which Is Deterministic For A Given Value.
+2. Canonical Rules Remove The Remaining Freedom: Attachments Are Sorted By Content Hash With No Duplicates.
+3. The Body Hash Is sha256 Over A Domain Tag, The Version, And The Canonical Bytes.
+4. The Hash PatientCase Stores Commits To The Body Hash And The Records Fingerprint Together,
+   So A Submission's Fingerprint Can Be Checked On-Chain Without The Manifest Itself.
+*/
+
+pub const MANIFEST_VERSION: u16 = 1;
+
+pub const MANIFEST_HASH_DOMAIN: &[u8] = b"curachain:case-manifest";
+
+pub const RECORDS_FINGERPRINT_DOMAIN: &[u8] = b"curachain:records-fingerprint";
+
+pub const MANIFEST_COMMITMENT_DOMAIN: &[u8] = b"curachain:case-manifest-commitment";
+
+pub const MAX_DIAGNOSIS_SUMMARY_LEN: usize = 2000;
+pub const MAX_COST_ITEMS: usize = 32;
+pub const MAX_ATTACHMENTS: usize = 32;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CaseManifest {
+    pub version: u16,
+
+    pub diagnosis_summary: String,
+
+    pub cost_breakdown: Vec<CostItem>,
+
+    pub facility: FacilityDetails,
+
+    pub attachments: Vec<Attachment>,
+}
+
+// One Line Of The Treatment Cost Estimate, In The Same Unit As total_amount_needed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CostItem {
+    pub label: String,
+
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FacilityDetails {
+    pub name: String,
+
+    pub location: String,
+
+    pub contact: String,
+}
+
+// A Supporting Document, Pinned By The Hash Of Its Contents
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Attachment {
+    pub label: String,
+
+    pub media_type: String,
+
+    pub uri: String,
+
+    pub content_hash: [u8; 32],
+}
+
+
+pub fn is_supported_version(version: u16) -> bool {
+    version == MANIFEST_VERSION
+}
+
+
+impl CaseManifest {
+    // Check The Canonical Rules Every Manifest Must Follow Before It Is Hashed
+    pub fn validate(&self) -> Result<()> {
+        require!(is_supported_version(self.version), CuraChainError::UnsupportedManifestVersion);
+
+        require!(
+            !self.diagnosis_summary.is_empty() && self.diagnosis_summary.len() <= MAX_DIAGNOSIS_SUMMARY_LEN,
+            CuraChainError::InvalidManifest
+        );
+
+        require!(
+            !self.cost_breakdown.is_empty() && self.cost_breakdown.len() <= MAX_COST_ITEMS,
+            CuraChainError::InvalidManifest
+        );
+        require!(self.cost_breakdown.iter().all(|item| item.amount > 0), CuraChainError::InvalidManifest);
+        self.total_cost()?;
+
+        require!(!self.facility.name.is_empty(), CuraChainError::InvalidManifest);
+
+        require!(self.attachments.len() <= MAX_ATTACHMENTS, CuraChainError::InvalidManifest);
+        require!(
+            self.attachments.windows(2).all(|pair| pair[0].content_hash < pair[1].content_hash),
+            CuraChainError::InvalidManifest
+        );
+
+        Ok(())
+    }
+
+    // Put The Manifest Into Canonical Order, So Off-Chain Tools Don't Have To Sort By Hand
+    pub fn canonicalize(&mut self) {
+        self.attachments.sort_by_key(|attachment| attachment.content_hash);
+        self.attachments.dedup_by(|a, b| a.content_hash == b.content_hash);
+    }
+
+    // Sum Of The Cost Breakdown, Which Off-Chain Tools Can Compare To total_amount_needed
+    pub fn total_cost(&self) -> Result<u64> {
+        self.cost_breakdown
+            .iter()
+            .try_fold(0u64, |total, item| total.checked_add(item.amount))
+            .ok_or(error!(CuraChainError::OverflowError))
+    }
+
+    pub fn to_canonical_bytes(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        self.try_to_vec().map_err(|_| error!(CuraChainError::InvalidManifest))
+    }
+
+    pub fn body_hash(&self) -> Result<[u8; 32]> {
+        let canonical_bytes = self.to_canonical_bytes()?;
+        Ok(hash_canonical_bytes(self.version, &canonical_bytes))
+    }
+
+    // The Hash Stored On The Patient Case
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(manifest_commitment(&self.body_hash()?, &self.records_fingerprint()?))
+    }
+
+    // Identifies The Records Bundle Alone: Rewording The Summary Or Costs Leaves It Unchanged,
+    // So The Same Documents Filed Again Under A Different Manifest Still Match
+    pub fn records_fingerprint(&self) -> Result<[u8; 32]> {
+        self.validate()?;
+        require!(!self.attachments.is_empty(), CuraChainError::InvalidManifest);
+
+        let mut parts: Vec<&[u8]> = vec![RECORDS_FINGERPRINT_DOMAIN];
+        parts.extend(self.attachments.iter().map(|attachment| attachment.content_hash.as_ref()));
+        Ok(hashv(&parts).to_bytes())
+    }
+
+    // Decode A Manifest Fetched From Its URI, Rejecting Any Encoding That Isn't The Canonical One
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self> {
+        let manifest = CaseManifest::try_from_slice(bytes).map_err(|_| error!(CuraChainError::InvalidManifest))?;
+        require!(manifest.to_canonical_bytes()? == bytes, CuraChainError::InvalidManifest);
+        Ok(manifest)
+    }
+}
+
+
+pub fn hash_canonical_bytes(version: u16, canonical_bytes: &[u8]) -> [u8; 32] {
+    hashv(&[MANIFEST_HASH_DOMAIN, &version.to_le_bytes(), canonical_bytes]).to_bytes()
+}
+
+
+pub fn manifest_commitment(body_hash: &[u8; 32], records_fingerprint: &[u8; 32]) -> [u8; 32] {
+    hashv(&[MANIFEST_COMMITMENT_DOMAIN, body_hash, records_fingerprint]).to_bytes()
+}
+
+
+// Check Manifest Bytes Fetched Off-Chain Against The Hash Stored On The Patient Case
+pub fn verify_manifest(bytes: &[u8], expected_hash: &[u8; 32]) -> Result<CaseManifest> {
+    let manifest = CaseManifest::from_canonical_bytes(bytes)?;
+    let body_hash = hash_canonical_bytes(manifest.version, bytes);
+    require!(
+        manifest_commitment(&body_hash, &manifest.records_fingerprint()?) == *expected_hash,
+        CuraChainError::ManifestHashMismatch
+    );
+    Ok(manifest)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(label: &str, hash_byte: u8) -> Attachment {
+        Attachment {
+            label: label.to_string(),
+            media_type: "application/pdf".to_string(),
+            uri: format!("ipfs://{}", label),
+            content_hash: [hash_byte; 32],
+        }
+    }
+
+    // A Valid Manifest Whose Attachments Are Given In Whatever Order The Caller Lists Them
+    fn manifest(attachments: Vec<Attachment>) -> CaseManifest {
+        CaseManifest {
+            version: MANIFEST_VERSION,
+            diagnosis_summary: "Stage II Lymphoma, Six Cycles Of Chemotherapy".to_string(),
+            cost_breakdown: vec![
+                CostItem { label: "Chemotherapy".to_string(), amount: 12_000 },
+                CostItem { label: "Admission".to_string(), amount: 3_000 },
+            ],
+            facility: FacilityDetails {
+                name: "Lagos University Teaching Hospital".to_string(),
+                location: "Lagos".to_string(),
+                contact: "+234 000 0000".to_string(),
+            },
+            attachments,
+        }
+    }
+
+    fn canonical(mut manifest: CaseManifest) -> CaseManifest {
+        manifest.canonicalize();
+        manifest
+    }
+
+    #[test]
+    fn canonical_bytes_round_trip_to_the_same_manifest_and_hash() {
+        let original = canonical(manifest(vec![attachment("scan", 2), attachment("referral", 1)]));
+        let bytes = original.to_canonical_bytes().unwrap();
+
+        let decoded = CaseManifest::from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(decoded, original);
+        assert_eq!(decoded.hash().unwrap(), original.hash().unwrap());
+
+        // The Stored Hash Is The Commitment To The Body Hash And The Records Fingerprint
+        let expected = manifest_commitment(&hash_canonical_bytes(MANIFEST_VERSION, &bytes), &original.records_fingerprint().unwrap());
+        assert_eq!(original.hash().unwrap(), expected);
+        assert_eq!(verify_manifest(&bytes, &expected).unwrap(), original);
+    }
+
+    #[test]
+    fn attachment_order_does_not_change_the_hash_once_canonical() {
+        let forward = canonical(manifest(vec![attachment("referral", 1), attachment("scan", 2), attachment("invoice", 3)]));
+        let reversed = canonical(manifest(vec![attachment("invoice", 3), attachment("scan", 2), attachment("referral", 1)]));
+
+        assert_eq!(forward.to_canonical_bytes().unwrap(), reversed.to_canonical_bytes().unwrap());
+        assert_eq!(forward.hash().unwrap(), reversed.hash().unwrap());
+        assert_eq!(forward.records_fingerprint().unwrap(), reversed.records_fingerprint().unwrap());
+    }
+
+    #[test]
+    fn canonicalize_drops_duplicate_attachments() {
+        let with_duplicate = canonical(manifest(vec![attachment("scan", 2), attachment("scan-copy", 2), attachment("referral", 1)]));
+
+        assert_eq!(with_duplicate.attachments.len(), 2);
+        assert!(with_duplicate.validate().is_ok());
+    }
+
+    #[test]
+    fn unsorted_attachments_are_not_canonical() {
+        let unsorted = manifest(vec![attachment("scan", 2), attachment("referral", 1)]);
+
+        assert_eq!(unsorted.hash().unwrap_err(), error!(CuraChainError::InvalidManifest));
+
+        // Bytes Encoding The Same Manifest In A Non-Canonical Order Are Refused Outright
+        let bytes = unsorted.try_to_vec().unwrap();
+        assert_eq!(CaseManifest::from_canonical_bytes(&bytes).unwrap_err(), error!(CuraChainError::InvalidManifest));
+    }
+
+    #[test]
+    fn verify_manifest_rejects_a_hash_it_does_not_match() {
+        let original = canonical(manifest(vec![attachment("referral", 1), attachment("scan", 2)]));
+        let bytes = original.to_canonical_bytes().unwrap();
+
+        let mut reworded = original.clone();
+        reworded.diagnosis_summary = "Stage III Lymphoma".to_string();
+
+        assert_eq!(verify_manifest(&bytes, &reworded.hash().unwrap()).unwrap_err(), error!(CuraChainError::ManifestHashMismatch));
+        assert_eq!(verify_manifest(&bytes, &[7u8; 32]).unwrap_err(), error!(CuraChainError::ManifestHashMismatch));
+    }
+
+    #[test]
+    fn rewording_keeps_the_records_fingerprint_but_new_records_change_it() {
+        let original = canonical(manifest(vec![attachment("referral", 1), attachment("scan", 2)]));
+
+        let mut reworded = original.clone();
+        reworded.diagnosis_summary = "Stage III Lymphoma".to_string();
+        reworded.cost_breakdown[0].amount = 15_000;
+
+        let mut new_records = original.clone();
+        new_records.attachments.push(attachment("biopsy", 9));
+
+        assert_ne!(reworded.hash().unwrap(), original.hash().unwrap());
+        assert_eq!(reworded.records_fingerprint().unwrap(), original.records_fingerprint().unwrap());
+        assert_ne!(new_records.records_fingerprint().unwrap(), original.records_fingerprint().unwrap());
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let mut future = canonical(manifest(vec![attachment("referral", 1)]));
+        future.version = MANIFEST_VERSION + 1;
+
+        assert_eq!(future.hash().unwrap_err(), error!(CuraChainError::UnsupportedManifestVersion));
+    }
+}
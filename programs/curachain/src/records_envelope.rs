@@ -0,0 +1,141 @@
+use aes_gcm::{aead::{Aead, KeyInit}, Aes256Gcm, Nonce};
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use rand_core::{OsRng, RngCore};
+use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+
+use crate::states::{errors::*, SealedEnvelope};
+
+
+/*
+OFF-CHAIN RECORDS KEY ENVELOPES
+Used By Patient And Verifier Tools, Never By The On-Chain Program:
+1. The Patient Encrypts Their Records With A Random 32-Byte Records Key.
+2. To Share It, An Ephemeral x25519 Key Is Agreed With The Verifier's Published Encryption Key.
+3. The Shared Secret Is Hashed With A Domain Tag And Both Public Keys Into An AES-256-GCM Key,
+   Which Seals The Records Key Under A Fresh Random Nonce.
+4. The Verifier Repeats The Agreement With Their Secret Key To Open The Envelope.
+*/
+
+pub const ENVELOPE_KEY_DOMAIN: &[u8] = b"curachain:records-envelope";
+
+pub const RECORDS_KEY_LEN: usize = 32;
+
+
+pub fn generate_records_key() -> [u8; RECORDS_KEY_LEN] {
+    let mut records_key = [0u8; RECORDS_KEY_LEN];
+    OsRng.fill_bytes(&mut records_key);
+    records_key
+}
+
+
+// A Fresh x25519 Secret For A Verifier, Kept Off-Chain By The Verifier
+pub fn generate_encryption_secret() -> [u8; 32] {
+    StaticSecret::random_from_rng(OsRng).to_bytes()
+}
+
+
+// The Public Half Of A Verifier's Secret, As Passed To set_verifier_encryption_key
+pub fn encryption_public_key(secret: &[u8; 32]) -> [u8; 32] {
+    PublicKey::from(&StaticSecret::from(*secret)).to_bytes()
+}
+
+
+pub fn seal_records_key(records_key: &[u8; RECORDS_KEY_LEN], recipient_key: &[u8; 32]) -> Result<SealedEnvelope> {
+    let ephemeral_secret = EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_public_key = PublicKey::from(&ephemeral_secret).to_bytes();
+
+    let shared_secret = ephemeral_secret.diffie_hellman(&PublicKey::from(*recipient_key));
+    require!(shared_secret.was_contributory(), CuraChainError::EncryptionError);
+
+    let cipher = envelope_cipher(shared_secret.as_bytes(), &ephemeral_public_key, recipient_key)?;
+
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut nonce);
+
+    let sealed = cipher
+        .encrypt(Nonce::from_slice(&nonce), records_key.as_slice())
+        .map_err(|_| error!(CuraChainError::EncryptionError))?;
+
+    Ok(SealedEnvelope {
+        recipient_key: *recipient_key,
+        ephemeral_public_key,
+        nonce,
+        ciphertext: sealed.try_into().map_err(|_| error!(CuraChainError::EncryptionError))?,
+    })
+}
+
+
+pub fn open_records_key(envelope: &SealedEnvelope, recipient_secret: &[u8; 32]) -> Result<[u8; RECORDS_KEY_LEN]> {
+    let recipient_secret = StaticSecret::from(*recipient_secret);
+    require!(
+        PublicKey::from(&recipient_secret).to_bytes() == envelope.recipient_key,
+        CuraChainError::EnvelopeRecipientMismatch
+    );
+
+    let shared_secret = recipient_secret.diffie_hellman(&PublicKey::from(envelope.ephemeral_public_key));
+    require!(shared_secret.was_contributory(), CuraChainError::DecryptionError);
+
+    let cipher = envelope_cipher(shared_secret.as_bytes(), &envelope.ephemeral_public_key, &envelope.recipient_key)?;
+
+    let records_key = cipher
+        .decrypt(Nonce::from_slice(&envelope.nonce), envelope.ciphertext.as_slice())
+        .map_err(|_| error!(CuraChainError::DecryptionError))?;
+
+    records_key.try_into().map_err(|_| error!(CuraChainError::DecryptionError))
+}
+
+
+// Bind The Derived Key To Both Public Keys So An Envelope Can't Be Replayed Under Another Recipient
+fn envelope_cipher(shared_secret: &[u8; 32], ephemeral_public_key: &[u8; 32], recipient_key: &[u8; 32]) -> Result<Aes256Gcm> {
+    let envelope_key = hashv(&[ENVELOPE_KEY_DOMAIN, shared_secret, ephemeral_public_key, recipient_key]).to_bytes();
+    Aes256Gcm::new_from_slice(&envelope_key).map_err(|_| error!(CuraChainError::EncryptionError))
+}
+
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifier_opens_the_records_key_sealed_to_them() {
+        let verifier_secret = generate_encryption_secret();
+        let records_key = generate_records_key();
+
+        let envelope = seal_records_key(&records_key, &encryption_public_key(&verifier_secret)).unwrap();
+
+        assert_eq!(envelope.recipient_key, encryption_public_key(&verifier_secret));
+        assert_eq!(open_records_key(&envelope, &verifier_secret).unwrap(), records_key);
+    }
+
+    #[test]
+    fn a_different_key_cannot_open_the_envelope() {
+        let verifier_secret = generate_encryption_secret();
+        let other_secret = generate_encryption_secret();
+        let envelope = seal_records_key(&generate_records_key(), &encryption_public_key(&verifier_secret)).unwrap();
+
+        assert_eq!(open_records_key(&envelope, &other_secret).unwrap_err(), error!(CuraChainError::EnvelopeRecipientMismatch));
+
+        // Re-Addressing The Envelope To The Other Key Doesn't Help, Since The Cipher Is Bound To The Original Recipient
+        let mut readdressed = envelope;
+        readdressed.recipient_key = encryption_public_key(&other_secret);
+        assert_eq!(open_records_key(&readdressed, &other_secret).unwrap_err(), error!(CuraChainError::DecryptionError));
+    }
+
+    #[test]
+    fn a_tampered_envelope_fails_to_open() {
+        let verifier_secret = generate_encryption_secret();
+        let envelope = seal_records_key(&generate_records_key(), &encryption_public_key(&verifier_secret)).unwrap();
+
+        let mut tampered_ciphertext = envelope;
+        tampered_ciphertext.ciphertext[0] ^= 1;
+        assert_eq!(open_records_key(&tampered_ciphertext, &verifier_secret).unwrap_err(), error!(CuraChainError::DecryptionError));
+
+        let mut tampered_tag = envelope;
+        tampered_tag.ciphertext[47] ^= 1;
+        assert_eq!(open_records_key(&tampered_tag, &verifier_secret).unwrap_err(), error!(CuraChainError::DecryptionError));
+
+        let mut tampered_nonce = envelope;
+        tampered_nonce.nonce[0] ^= 1;
+        assert_eq!(open_records_key(&tampered_nonce, &verifier_secret).unwrap_err(), error!(CuraChainError::DecryptionError));
+    }
+}
@@ -2,7 +2,7 @@ use std::u8;
 
 use anchor_lang::prelude::*;
 
-use crate::states::errors::*;
+use crate::{manifest, states::{constants::*, errors::*}, verification_policy::{PolicyParams, Tally, VerificationPolicy, VerificationStrategy}};
 
 
 // CREATE THE ADMINISTRATOR ACCOUNT HERE
@@ -30,6 +30,227 @@ pub struct Multisig {
     pub multisig_bump: u8
 }
 
+impl Multisig {
+    // The Admin And Every Multisig Member Can Manage The Facility Registry
+    pub fn is_admin_or_member(&self, key: &Pubkey) -> bool {
+        self.multisig_admin == *key || self.multisig_members.contains(key)
+    }
+}
+
+
+// CREATE THE SENIOR VERIFIER PANEL HERE, WHICH SETTLES SPLIT VOTES ESCALATED TO IT
+#[account]
+#[derive(InitSpace)]
+pub struct SeniorPanel {
+    #[max_len(MAX_SENIOR_VERIFIERS)]
+    pub members: Vec<Pubkey>,
+
+    pub panel_bump: u8,
+}
+
+
+// CREATE A FACILITY PDA PER TREATMENT FACILITY, HOLDING WHERE ITS FUNDS MAY BE PAID
+#[account]
+#[derive(InitSpace)]
+pub struct Facility {
+    // Key The Facility Itself Signs With
+    pub facility_key: Pubkey,
+
+    #[max_len(64)]
+    pub name: String,
+
+    // The Only Wallet Released Funds (And Its ATAs) Can Be Paid To
+    pub payout_wallet: Pubkey,
+
+    // Who Last Set The Payout Wallet; Someone Else Has To Verify The Facility
+    pub payout_wallet_set_by: Pubkey,
+
+    pub status: FacilityStatus,
+
+    pub registered_at: i64,
+
+    pub updated_at: i64,
+
+    pub facility_bump: u8,
+}
+
+// CREATE CASE UPDATE PDAS PER CASE, EACH A PAGE OF UP TO MAX_UPDATES_PER_PAGE PROGRESS UPDATES
+#[account]
+#[derive(InitSpace)]
+pub struct CaseUpdate {
+    pub case_id: u64,
+
+    pub page_index: u32,
+
+    #[max_len(MAX_UPDATES_PER_PAGE)]
+    pub updates: Vec<ProgressUpdate>,
+
+    pub page_bump: u8,
+}
+
+// One Entry In A Case's Progress Timeline; The Details Live Off-Chain At The URI
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace, PartialEq, Eq, Debug)]
+pub struct ProgressUpdate {
+    pub update_type: UpdateType,
+
+    pub content_hash: [u8; 32],
+
+    #[max_len(MAX_MANIFEST_URI_LEN)]
+    pub uri: String,
+
+    pub posted_by: Pubkey,
+
+    pub posted_at: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum UpdateType {
+    TreatmentStarted,
+
+    SurgeryDone,
+
+    Discharged,
+}
+
+
+// CREATE A TREATMENT PLAN PDA PER CASE, SPLITTING DISBURSEMENT INTO ORDERED MILESTONES
+#[account]
+#[derive(InitSpace)]
+pub struct TreatmentPlan {
+    pub case_id: u64,
+
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+
+    // Index Of The Only Milestone That Can Currently Be Released
+    pub next_milestone: u8,
+
+    pub total_released: u64,
+
+    pub plan_bump: u8,
+}
+
+impl TreatmentPlan {
+    pub fn current_milestone(&self) -> Result<&Milestone> {
+        self.milestones.get(self.next_milestone as usize).ok_or(error!(CuraChainError::AllMilestonesReleased))
+    }
+
+    // Record A Release Against The Current Milestone, Moving On Once It Is Fully Paid
+    pub fn record_release(&mut self, amount: u64) -> Result<()> {
+        let index = self.next_milestone as usize;
+        let milestone = self.milestones.get_mut(index).ok_or(CuraChainError::AllMilestonesReleased)?;
+
+        milestone.amount_released = milestone.amount_released.checked_add(amount).ok_or(CuraChainError::OverflowError)?;
+        require!(milestone.amount_released <= milestone.amount, CuraChainError::OverflowError);
+
+        if milestone.amount_released == milestone.amount {
+            self.next_milestone = self.next_milestone.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+        }
+
+        self.total_released = self.total_released.checked_add(amount).ok_or(CuraChainError::OverflowError)?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct Milestone {
+    pub amount: u64,
+
+    pub required_proof: ProofType,
+
+    // Hash Of The Off-Chain Proof, Set Once The Proof Is Recorded
+    pub proof_hash: Option<[u8; 32]>,
+
+    pub attested_by: Option<Pubkey>,
+
+    pub attested_at: i64,
+
+    pub amount_released: u64,
+}
+
+// Who Must Confirm A Milestone Before Its Funds Can Be Released
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum ProofType {
+    // The Bound Facility Attests The Treatment Step Took Place
+    FacilityAttestation,
+
+    // A Registered Verifier Signs Off On The Treatment Step
+    VerifierSignOff,
+}
+
+
+// CREATE AN INVOICE PDA PER CASE AND INVOICE NUMBER, SUBMITTED BY THE CASE'S FACILITY
+#[account]
+#[derive(InitSpace)]
+pub struct Invoice {
+    pub case_id: u64,
+
+    // Facility PDA That Issued The Invoice
+    pub facility: Pubkey,
+
+    // Hash Of The Facility's Own Invoice Number, So It Isn't Published In Clear
+    pub invoice_number_hash: [u8; 32],
+
+    // Hash Of The Invoice Document Held Off-Chain
+    pub document_hash: [u8; 32],
+
+    // Currency Of The Invoice; NATIVE_SOL_ADDRESS For SOL
+    pub mint: Pubkey,
+
+    pub amount: u64,
+
+    pub amount_paid: u64,
+
+    pub due_date: i64,
+
+    pub status: InvoiceStatus,
+
+    pub submitted_at: i64,
+
+    pub invoice_bump: u8,
+}
+
+impl Invoice {
+    pub fn outstanding(&self) -> u64 {
+        self.amount.saturating_sub(self.amount_paid)
+    }
+
+    // Record A Payment And Move The Invoice To PartiallyPaid Or Settled
+    pub fn record_payment(&mut self, amount: u64) -> Result<()> {
+        self.amount_paid = self.amount_paid.checked_add(amount).ok_or(CuraChainError::OverflowError)?;
+        require!(self.amount_paid <= self.amount, CuraChainError::OverflowError);
+
+        self.status = if self.amount_paid == self.amount {
+            InvoiceStatus::Settled
+        } else {
+            InvoiceStatus::PartiallyPaid
+        };
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum InvoiceStatus {
+    Open,
+
+    PartiallyPaid,
+
+    Settled,
+}
+
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum FacilityStatus {
+    // Registered But Not Yet Vetted; Cases Cannot Bind To It
+    Unverified,
+
+    // Vetted; Cases Can Bind To It And Receive Payouts
+    Verified,
+
+    // Payouts Paused, E.g. While A Payout Wallet Change Is Investigated
+    Suspended,
+}
+
 
 // CREATE A CASE COUNTER PDA THAT WILL INCREMENT AND ASSIGN EACH CASE AN ID
 // OF THE FORMAT, CASE + (RANDOM 4 NUMBER)
@@ -39,14 +260,189 @@ pub struct CaseCounter {
     pub counter_bump: u8,
 }
 
+// CREATE THE PROTOCOL CONFIG PDA HERE, HOLDING ADMIN-TUNABLE POLICIES
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    pub amendment_policy: AmendmentPolicy,
+
+    // Longest Fundraising Campaign A Patient Can Ask For, In Seconds
+    pub max_campaign_duration: i64,
+
+    pub expiry_policy: ExpiryPolicy,
+
+    // Share Of A Rejected Case's Rent Paid To Whoever Closes It, In Basis Points; 0 Disables The Bounty
+    pub rejected_close_bounty_bps: u16,
+
+    // Bond Every Case Submission Posts Against Spam; 0 Disables It
+    pub submission_bond_amount: u64,
+
+    // Currency Of The Bond; NATIVE_SOL_ADDRESS For SOL
+    pub submission_bond_mint: Pubkey,
+
+    // Wallet Forfeited Bonds Are Paid To
+    pub bond_forfeit_pool: Pubkey,
+
+    // How Long, In Seconds, A Rejected Case Can Still Be Appealed Before Anyone May Close It
+    pub appeal_window: i64,
+
+    // Verification Window And Quorum For Each Urgency Level, Indexed By UrgencyLevel
+    pub urgency_policies: [UrgencyPolicy; URGENCY_LEVELS],
+
+    // Verification Requirements By Amount Needed, Sorted By min_amount; Empty Means DEFAULT_AMOUNT_TIER For All
+    #[max_len(MAX_AMOUNT_TIERS)]
+    pub amount_tiers: Vec<AmountTier>,
+
+    // How Verification Votes Are Judged Once A Case's Quorum Has Voted
+    pub verification_strategy: VerificationStrategy,
+
+    // Whether A Case Is Settled The Moment Its Vote Passes Or Fails; When Off, Only finalize_verification Settles It After The Window
+    pub allow_early_approval: bool,
+
+    // Which Split Votes Go To The Senior Panel Instead Of Being Rejected, And How Many Senior Votes Settle Them
+    pub escalation_policy: EscalationPolicy,
+
+    pub config_bump: u8,
+}
+
+impl ProtocolConfig {
+    pub fn urgency_policy(&self, urgency: UrgencyLevel) -> UrgencyPolicy {
+        self.urgency_policies[urgency as usize]
+    }
+
+    // The Highest Tier Whose min_amount The Case Reaches
+    pub fn amount_tier(&self, total_amount_needed: u64) -> AmountTier {
+        self.amount_tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_amount <= total_amount_needed)
+            .copied()
+            .unwrap_or(DEFAULT_AMOUNT_TIER)
+    }
+}
+
+// Verification Requirements For Cases Asking For At Least min_amount
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct AmountTier {
+    pub min_amount: u64,
+
+    // Share Of Eligible Verifiers That Must Vote, In Basis Points
+    pub participation_bps: u16,
+
+    // Share Of Votes Cast That Must Be Yes, In Basis Points
+    pub approval_bps: u16,
+
+    // Yes Votes Needed However Many Verifiers There Are
+    pub min_yes_votes: u8,
+
+    // Whether A Passing Vote Still Needs Multisig Members To Co-Approve Before The Case Is Verified
+    pub requires_multisig: bool,
+}
+
+// The Original Rule Every Case Followed: Half The Verifiers Vote, 70% Of Them Yes
+pub const DEFAULT_AMOUNT_TIER: AmountTier = AmountTier {
+    min_amount: 0,
+    participation_bps: 5000,
+    approval_bps: 7000,
+    min_yes_votes: 0,
+    requires_multisig: false,
+};
+
+impl AmountTier {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.participation_bps > 0 && self.participation_bps <= 10_000, CuraChainError::InvalidAmountTier);
+        require!(self.approval_bps > 0 && self.approval_bps <= 10_000, CuraChainError::InvalidAmountTier);
+        Ok(())
+    }
+}
+
+// How Quickly A Case Is Verified, Set Once At Submission
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum UrgencyLevel {
+    Elective,
+
+    Urgent,
+
+    Emergency,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct UrgencyPolicy {
+    // Seconds Verifiers Have To Vote Before The Admin May Override
+    pub verification_window: i64,
+
+    // Share Of Eligible Verifiers That Must Vote, In Basis Points
+    pub quorum_bps: u16,
+}
+
+impl UrgencyPolicy {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.verification_window > 0 && self.verification_window <= ALLOWED_VERIFICATION_TIME as i64,
+            CuraChainError::InvalidUrgencyPolicy
+        );
+        require!(self.quorum_bps > 0 && self.quorum_bps <= 10_000, CuraChainError::InvalidUrgencyPolicy);
+        Ok(())
+    }
+}
+
+// A Rejected Vote Whose Yes Share Reached grey_zone_min_bps Goes To The Senior Panel; A Floor Of 0 Disables Escalation
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct EscalationPolicy {
+    pub grey_zone_min_bps: u16,
+
+    // Senior Votes Needed Before The Panel's Majority Decides; Ties Reject
+    pub senior_quorum: u8,
+
+    // Seconds The Panel Has To Reach Quorum, After Which finalize_verification Settles On The Senior Votes Cast So Far
+    pub decision_window: i64,
+}
+
+impl EscalationPolicy {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.grey_zone_min_bps <= 10_000, CuraChainError::InvalidEscalationPolicy);
+        if self.grey_zone_min_bps > 0 {
+            require!(
+                self.senior_quorum > 0 && self.senior_quorum as usize <= MAX_SENIOR_VERIFIERS,
+                CuraChainError::InvalidEscalationPolicy
+            );
+            require!(self.decision_window > 0, CuraChainError::InvalidEscalationPolicy);
+        }
+        Ok(())
+    }
+}
+
+// What Happens To Existing Votes When A Patient Amends A Case Under Verification
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum AmendmentPolicy {
+    // Amendments Are Only Allowed Before Any Verifier Has Voted
+    RejectIfVoted,
+
+    // Amendments Wipe All Votes Cast So Far And Restart The Verification Window
+    ResetVotes,
+}
+
+// What Happens To Funds Still Held For A Case Once Its Campaign Deadline Passes
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum ExpiryPolicy {
+    // Whatever Was Raised Can Still Be Released To Treatment By Multisig
+    ReleaseRaised,
+
+    // Donors Reclaim Their Contributions
+    RefundDonors,
+}
+
 // CREATE THE PATIENT ACCOUNT HERE
 #[account]
 #[derive(InitSpace)]
 pub struct PatientCase {
     pub patient_pubkey: Pubkey,
 
-    #[max_len(50)]
-    pub case_description: String,
+    // Kept Right After patient_pubkey At A Fixed Offset, So Clients Can List Cases By Urgency With A memcmp Filter
+    pub urgency: UrgencyLevel,
+
+    // Version, Hash And URI Of The Off-Chain Case Manifest
+    pub manifest: ManifestRef,
 
     pub total_amount_needed: u64,
 
@@ -55,14 +451,17 @@ pub struct PatientCase {
     #[max_len(20)]
     pub spl_donations: Vec<SplDonations>,
 
-    #[max_len(10)]
-    pub case_id: String,
+    pub case_id: u64,
     
     pub verification_yes_votes:u8,
     // list of voted verifiers on a case
     #[max_len(25)]
     pub voted_verifiers: Vec<Pubkey>,
 
+    // Vote Weight Each Entry Of voted_verifiers Voted With, In The Same Order
+    #[max_len(25)]
+    pub voter_weights: Vec<u16>,
+
     pub verification_no_votes: u8,
     
     pub is_verified: bool,
@@ -73,17 +472,602 @@ pub struct PatientCase {
 
     pub submission_time: i64,
 
-    #[max_len(64)]
-    pub link_to_records: String,
+    pub amendment_count: u8,
+
+    pub case_status: CaseStatus,
+
+    // Donations Are Only Accepted Before This Time
+    pub funding_deadline: i64,
+
+    // Snapshot Of The Protocol Expiry Policy At Submission
+    pub expiry_policy: ExpiryPolicy,
+
+    // SOL Contributions Not Yet Refunded, Used To Size Pro-Rata Refunds
+    pub total_sol_contributed: u64,
+
+    pub funding_mode: FundingMode,
+
+    // Latched Once Donations First Cover total_amount_needed, So Later Releases Don't Undo It
+    pub goal_reached: bool,
+
+    // Who Filed The Case; Equal To patient_pubkey For Self-Submitted Cases
+    pub submitter: Pubkey,
+
+    // NGO Or Relayer That Paid The Rent And Bond Of A Self-Submitted Case, If Not The Patient
+    pub sponsor: Option<Pubkey>,
+
+    // Who May Amend, Withdraw And Share Records; The Submitter Until The Beneficiary Takes Over
+    pub case_manager: Pubkey,
+
+    // Facility PDA Released Funds Are Paid To, Bound Before Or At Verification
+    pub facility: Option<Pubkey>,
+
+    // Treatment Plan PDA, When Funds Are Disbursed In Milestones
+    pub treatment_plan: Option<Pubkey>,
+
+    // Everything Paid Out To The Facility So Far, Across All Mints
+    pub total_released: u64,
+
+    // Every Release Made For This Case, Carried Over Into Its CaseReceipt On Archival
+    #[max_len(MAX_RELEASE_RECORDS)]
+    pub releases: Vec<ReleaseRecord>,
+
+    // Anti-Spam Bond Still Held For This Case; Cleared Once It Is Refunded Or Forfeited
+    pub submission_bond: Option<PostedBond>,
+
+    // Progress Updates Posted So Far; Also Locates The CaseUpdate Page The Next One Goes Into
+    pub update_count: u32,
+
+    // 1 For The First Verification Round, 2 Once The Case Has Been Appealed
+    pub verification_round: u8,
+
+    // When The Votes Cast First Left The Case Rejected; Starts The Appeal Window
+    pub rejected_at: Option<i64>,
+
+    // Appeal PDA Recording Both Rounds, Once The Case Has Been Appealed
+    pub appeal: Option<Pubkey>,
+
+    // Verifier PDAs That Voted In The First Round And Sit Out The Second, With The Weight They Voted With
+    #[max_len(25)]
+    pub recused_verifiers: Vec<RecusedVerifier>,
+
+    // Open Question From A Verifier; The Verification Clock Is Paused Until The Patient Responds
+    pub info_request: Option<InfoRequest>,
+
+    // Seconds The Current Round Has Spent Paused On Answered Requests, Added Onto Its Deadline
+    pub paused_duration: i64,
+
+    pub info_requests_this_round: u8,
+
+    // Fingerprint Of The Records Bundle, As Computed By manifest::CaseManifest::records_fingerprint
+    pub records_fingerprint: [u8; 32],
+
+    // Earlier Case Still Open With The Same Records; Flags This One For Verifiers To Scrutinise
+    pub duplicate_of: Option<u64>,
+
+    // Copied From The Urgency Policy At Submission, So Config Changes Don't Move A Running Case's Goalposts
+    pub verification_window: i64,
+
+    pub quorum_bps: u16,
+
+    // Copied From The Amount Tier At Submission, And Again Whenever The Amount Needed Is Amended
+    pub verification_tier: AmountTier,
+
+    // Set Once Verifiers Pass A Case Whose Tier Requires Multisig Co-Approval
+    pub awaiting_co_approval: bool,
+
+    #[max_len(MAX_CO_APPROVALS)]
+    pub co_approvals: Vec<Pubkey>,
+
+    // Copied From The Protocol Config At Submission, Like The Urgency Policy And Amount Tier
+    pub verification_strategy: VerificationStrategy,
+
+    // Vote Weight Behind The Yes And No Votes Of The Current Round
+    pub yes_weight: u64,
+
+    pub no_weight: u64,
+
+    // Copied From The Protocol Config At Submission
+    pub allow_early_approval: bool,
+
+    pub escalation_policy: EscalationPolicy,
+
+    // Set Once A Split Vote Is Escalated To The Senior Panel; A Case Is Escalated At Most Once
+    pub escalation: Option<Escalation>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct RecusedVerifier {
+    pub verifier: Pubkey,
+
+    pub vote_weight: u16,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct InfoRequest {
+    // Hash Of The Question, Which Is Shared With The Patient Off-Chain
+    pub question_hash: [u8; 32],
+
+    pub requested_by: Pubkey,
+
+    pub requested_at: i64,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace, PartialEq, Copy)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace, PartialEq, Eq, Debug)]
+pub struct Escalation {
+    pub escalated_at: i64,
+
+    // Yes Share Of The Verifiers' Vote That Put The Case In The Grey Zone
+    pub yes_share_bps: u16,
+
+    // Copied From The Escalation Policy When The Case Was Escalated
+    pub senior_quorum: u8,
+
+    // Past This, An Undecided Escalation Can Be Settled By finalize_verification
+    pub decide_by: i64,
+
+    pub senior_yes_votes: u8,
+
+    pub senior_no_votes: u8,
+
+    #[max_len(MAX_SENIOR_VERIFIERS)]
+    pub senior_voters: Vec<Pubkey>,
+
+    // The Panel's Final Decision, Once Its Quorum Has Voted
+    pub resolution: Option<EscalationResolution>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct EscalationResolution {
+    pub approved: bool,
+
+    pub resolved_at: i64,
+}
+
+// A Submission Bond Held In The Case's Bond Vault PDA, Or In That PDA's Token Account For SPL Bonds
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct PostedBond {
+    pub mint: Pubkey,
+
+    pub amount: u64,
+
+    pub vault_bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum BondOutcome {
+    // Case Was Verified, Or Withdrawn Before Any Vote: The Bond Goes Back To The Submitter
+    Refunded,
+
+    // Case Was Rejected Or Flagged: The Bond Goes To The Forfeit Pool
+    Forfeited,
+}
+
+// One Executed Release; The Slot Lets Indexers Find The Transaction That Made It
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct ReleaseRecord {
+    pub proposal_index: u64,
+
+    pub invoice: Pubkey,
+
+    pub mint: Pubkey,
+
+    pub amount: u64,
+
+    pub slot: u64,
+
+    pub released_at: i64,
+}
+
+
+// CREATE A CASE RECEIPT PDA PER ARCHIVED CASE, THE COMPACT RECORD LEFT ONCE ITS PATIENT CASE IS CLOSED
+#[account]
+#[derive(InitSpace)]
+pub struct CaseReceipt {
+    pub case_id: u64,
+
+    pub beneficiary: Pubkey,
+
+    pub submitter: Pubkey,
+
+    pub sponsor: Option<Pubkey>,
+
+    pub facility: Option<Pubkey>,
+
+    // Hash Of The Last Case Manifest, So The Off-Chain Details Can Still Be Checked
+    pub manifest_hash: [u8; 32],
+
+    pub outcome: CaseOutcome,
+
+    #[max_len(MAX_RECEIPT_MINTS)]
+    pub mint_totals: Vec<MintTotal>,
+
+    #[max_len(MAX_RELEASE_RECORDS)]
+    pub releases: Vec<ReleaseRecord>,
+
+    pub submitted_at: i64,
+
+    pub funding_deadline: i64,
+
+    pub archived_at: i64,
+
+    pub receipt_bump: u8,
+}
+
+// What Was Contributed And Released In One Mint; NATIVE_SOL_ADDRESS For SOL
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct MintTotal {
+    pub mint: Pubkey,
+
+    pub total_contributed: u64,
+
+    pub total_released: u64,
+}
+
+// How An Archived Case Ended
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum CaseOutcome {
+    // The Funding Goal Was Met Before Everything Was Released
+    Funded,
+
+    // Released Without Ever Meeting The Funding Goal
+    PartiallyFunded,
+
+    // Withdrawn, With The Raised Funds Redirected By The Multisig
+    Redirected,
+}
+
+// How A Case Treats Partial Funding, Chosen By The Patient At Submission
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum FundingMode {
+    // Whatever Is Raised Can Be Released At Any Time
+    KeepWhatYouRaise,
+
+    // Nothing Is Released Until The Goal Is Met; If The Deadline Passes Unmet, Donors Are Refunded
+    AllOrNothing,
+}
+
+// Lifecycle Of A Patient Case, Tracked Alongside is_verified
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum CaseStatus {
+    // Submitted And Waiting On Verifiers
+    PendingVerification,
+
+    // Verified And Open For Donations
+    Verified,
+
+    // Withdrawn After Donations: Donors Get Their Contributions Back
+    Refunding,
+
+    // Withdrawn After Donations: Multisig Redirects The Raised Funds
+    Redirecting,
+
+    // Campaign Deadline Passed: No More Donations, Raised Funds Can Still Be Released
+    Expired,
+
+    // A Verifier Asked For More Information: Voting Is Paused Until The Patient Responds
+    InfoRequested,
+
+    // A Split Vote Went To The Senior Panel: Verifiers No Longer Vote, The Panel Decides
+    Escalated,
+}
+
+impl PatientCase {
+    // Whether Any SOL Or SPL Donation Is Still Held For This Case
+    pub fn has_donations(&self) -> bool {
+        self.total_sol_raised > 0 || self.spl_donations.iter().any(|donation| donation.total_mint_amount > 0)
+    }
+
+    // Sum Of SOL And SPL Amounts Held For This Case
+    // Known ISSUE: SOL And Each Token Are Added Up As Raw Amounts, Regardless Of Their Prices
+    pub fn total_raised(&self) -> Result<u64> {
+        let mut grand_donations = self.total_sol_raised;
+        for each_spl in self.spl_donations.iter() {
+            grand_donations = grand_donations
+                .checked_add(each_spl.total_mint_amount)
+                .ok_or(CuraChainError::OverflowError)?;
+        }
+        Ok(grand_donations)
+    }
+
+    // Latch goal_reached The First Time Donations Cover The Amount Needed
+    pub fn update_goal_reached(&mut self) -> Result<()> {
+        if !self.goal_reached && self.total_raised()? >= self.total_amount_needed {
+            self.goal_reached = true;
+        }
+        Ok(())
+    }
+
+    // Who Paid The Case Rent And Bond, And So Gets Them Back: The Sponsor If Any, Otherwise The Submitter
+    pub fn rent_payer(&self) -> Pubkey {
+        self.sponsor.unwrap_or(self.submitter)
+    }
+
+    // End Of The Verification Window, Pushed Back By Every Pause Including One Still Open
+    pub fn verification_deadline(&self, now: i64) -> Result<i64> {
+        let open_pause = self.open_pause(now)?;
+
+        self.submission_time
+            .checked_add(self.verification_window)
+            .and_then(|deadline| deadline.checked_add(self.paused_duration))
+            .and_then(|deadline| deadline.checked_add(open_pause))
+            .ok_or(error!(CuraChainError::OverflowError))
+    }
+
+    // Milestone Proofs Only Count For A Verified Case Still Headed For Treatment, Not One Being Wound Down
+    pub fn ensure_in_treatment(&self) -> Result<()> {
+        require!(self.is_verified, CuraChainError::CaseNotYetVerified);
+        require!(self.case_status != CaseStatus::Refunding, CuraChainError::CaseRefunding);
+        require!(self.case_status != CaseStatus::Redirecting, CuraChainError::CaseAlreadyWithdrawn);
+        Ok(())
+    }
+
+    // Time The Open Question Has Paused The Clock, Capped At The Response Window So Silence Cannot Stall The Case
+    pub fn open_pause(&self, now: i64) -> Result<i64> {
+        match self.info_request {
+            Some(request) => {
+                let open_for = now.checked_sub(request.requested_at).ok_or(CuraChainError::UnderflowError)?;
+                Ok(open_for.min(INFO_REQUEST_RESPONSE_WINDOW))
+            }
+            None => Ok(0),
+        }
+    }
+
+    // Closes The Open Question, Banking The Time It Paused The Clock And Resuming Verification
+    pub fn close_info_request(&mut self, now: i64) -> Result<Option<(InfoRequest, i64)>> {
+        let paused_for = self.open_pause(now)?;
+        let Some(request) = self.info_request.take() else {
+            return Ok(None);
+        };
+
+        self.paused_duration = self.paused_duration.checked_add(paused_for).ok_or(CuraChainError::OverflowError)?;
+        self.case_status = CaseStatus::PendingVerification;
+        Ok(Some((request, paused_for)))
+    }
+
+    // A Question The Patient Let Run Past The Response Window Stops Holding Up Votes And Finalization
+    pub fn lapse_unanswered_info_request(&mut self, now: i64) -> Result<bool> {
+        let lapsed = match self.info_request {
+            Some(request) => now.checked_sub(request.requested_at).ok_or(CuraChainError::UnderflowError)? >= INFO_REQUEST_RESPONSE_WINDOW,
+            None => false,
+        };
+        if lapsed {
+            self.close_info_request(now)?;
+        }
+        Ok(lapsed)
+    }
+
+    // Starts A Fresh Verification Window, Dropping Any Time Paused Or Co-Approval Given In The Previous One.
+    // A Question Still Open Carries Over, But Only Pauses The New Window From Its Start
+    pub fn restart_verification_clock(&mut self, now: i64) {
+        self.submission_time = now;
+        self.paused_duration = 0;
+        self.info_requests_this_round = 0;
+        self.awaiting_co_approval = false;
+        self.co_approvals.clear();
+        if let Some(request) = self.info_request.as_mut() {
+            request.requested_at = now;
+        }
+    }
+
+    // Share Of Eligible Verifiers That Must Vote: Whichever Of The Urgency Quorum And The Amount Tier Asks For More
+    pub fn participation_bps(&self) -> u16 {
+        std::cmp::max(self.quorum_bps, self.verification_tier.participation_bps)
+    }
+
+    // Registered Verifiers Who May Vote In The Current Round; Quorum Is Measured Against These
+    pub fn eligible_verifier_count(&self, verifiers_list: &VerifiersList) -> usize {
+        verifiers_list.all_verifiers
+            .iter()
+            .filter(|verifier| !self.is_recused(verifier))
+            .count()
+    }
+
+    pub fn is_recused(&self, verifier: &Pubkey) -> bool {
+        self.recused_verifiers.iter().any(|recused| recused.verifier == *verifier)
+    }
+
+    // Weight Of The Recused Verifiers Still In The Registry; One Removed Since Has Already Left total_weight
+    pub fn registered_recused_weight(&self, verifiers_list: &VerifiersList) -> u64 {
+        self.recused_verifiers
+            .iter()
+            .filter(|recused| verifiers_list.all_verifiers.contains(&recused.verifier))
+            .map(|recused| recused.vote_weight as u64)
+            .sum()
+    }
+
+    // The Case's Votes Alongside The Panel Eligible To Cast Them, Ready For The Verification Policy
+    pub fn tally(&self, verifiers_list: &VerifiersList) -> Tally {
+        Tally {
+            yes_votes: self.verification_yes_votes as u32,
+            no_votes: self.verification_no_votes as u32,
+            yes_weight: self.yes_weight,
+            no_weight: self.no_weight,
+            eligible_voters: self.eligible_verifier_count(verifiers_list) as u32,
+            eligible_weight: verifiers_list.total_weight.saturating_sub(self.registered_recused_weight(verifiers_list)),
+        }
+    }
+
+    pub fn verification_policy(&self) -> VerificationPolicy {
+        VerificationPolicy::new(
+            self.verification_strategy,
+            PolicyParams {
+                participation_bps: self.participation_bps(),
+                approval_bps: self.verification_tier.approval_bps,
+                min_yes_votes: self.verification_tier.min_yes_votes,
+            },
+        )
+    }
+
+    // Drops Every Vote Of The Current Round, Ahead Of A Fresh One
+    pub fn clear_votes(&mut self) {
+        self.verification_yes_votes = 0;
+        self.verification_no_votes = 0;
+        self.yes_weight = 0;
+        self.no_weight = 0;
+        self.voted_verifiers.clear();
+        self.voter_weights.clear();
+    }
+}
+
+
+// CREATE A RECORDS FINGERPRINT PDA PER RECORDS BUNDLE, SHARED BY EVERY CASE FILED WITH THOSE RECORDS
+#[account]
+#[derive(InitSpace)]
+pub struct RecordsFingerprint {
+    pub fingerprint: [u8; 32],
+
+    // Cases Filed With These Records Whose Lookup Is Still Open, Oldest First; A Case Leaves When It Is Closed Or Archived.
+    // Anyone Can File Against Any Records Hash, So This Is Only A Duplicate Signal And Never Blocks A Filing
+    #[max_len(MAX_LIVE_CASES_PER_RECORDS)]
+    pub live_case_ids: Vec<u64>,
+
+    // Every Case Filed With These Records, Flagged Or Not
+    pub submissions: u32,
+
+    pub first_submitted_at: i64,
+
+    pub fingerprint_bump: u8,
+}
+
+impl RecordsFingerprint {
+    // The Newest Case Still Open On These Records, Which A New Filing Is Flagged Against
+    pub fn active_case_id(&self) -> Option<u64> {
+        self.live_case_ids.last().copied()
+    }
+
+    // Adds A New Filing As The Newest Live Case, Returning The Case It Duplicates If Any.
+    // A Full List Drops Its Oldest Entry, So Pre-Filed Cases Can Only Flag A Filing, Not Block It
+    pub fn file_case(&mut self, case_id: u64) -> Result<Option<u64>> {
+        let duplicate_of = self.active_case_id();
+        if self.live_case_ids.len() >= MAX_LIVE_CASES_PER_RECORDS {
+            self.live_case_ids.remove(0);
+        }
+        self.live_case_ids.push(case_id);
+        self.submissions = self.submissions.checked_add(1).ok_or(CuraChainError::OverflowError)?;
+        Ok(duplicate_of)
+    }
+
+    pub fn release_case(&mut self, case_id: u64) {
+        self.live_case_ids.retain(|live_case_id| *live_case_id != case_id);
+    }
+}
+
+
+// CREATE A CASE APPEAL PDA PER APPEALED CASE, KEEPING THE NEW EVIDENCE AND THE OUTCOME OF BOTH ROUNDS
+#[account]
+#[derive(InitSpace)]
+pub struct CaseAppeal {
+    pub case_id: u64,
+
+    // Pointer To The Additional Evidence Supplied With The Appeal
+    pub evidence: ManifestRef,
+
+    pub appealed_at: i64,
+
+    pub first_round: RoundResult,
+
+    // Set Once The Second Round Ends In Verification Or Rejection
+    pub second_round: Option<RoundResult>,
+
+    // Whether First-Round Voters Were Excluded From The Second Round
+    pub recusal_applied: bool,
+
+    pub appeal_bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct RoundResult {
+    pub yes_votes: u8,
+
+    pub no_votes: u8,
+
+    pub outcome: RoundOutcome,
+
+    pub concluded_at: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum RoundOutcome {
+    Verified,
+
+    Rejected,
+}
+
+// Pointer To The Off-Chain Case Manifest, Whose Format Is Defined In crate::manifest
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace, PartialEq, Eq, Debug)]
+pub struct ManifestRef {
+    pub version: u16,
+
+    pub hash: [u8; 32],
+
+    #[max_len(200)]
+    pub uri: String,
+}
+
+impl ManifestRef {
+    pub fn validate(&self) -> Result<()> {
+        require!(manifest::is_supported_version(self.version), CuraChainError::UnsupportedManifestVersion);
+        require!(self.hash != [0u8; 32], CuraChainError::InvalidManifest);
+        require!(!self.uri.is_empty() && self.uri.len() <= MAX_MANIFEST_URI_LEN, CuraChainError::ManifestUriTooLong);
+        Ok(())
+    }
+
+    // A Claimed Records Fingerprint Must Be The One The Manifest Hash Commits To, Alongside The Manifest's Body Hash
+    pub fn verify_records_fingerprint(&self, body_hash: &[u8; 32], records_fingerprint: &[u8; 32]) -> Result<()> {
+        require!(
+            manifest::manifest_commitment(body_hash, records_fingerprint) == self.hash,
+            CuraChainError::RecordsFingerprintMismatch
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace, PartialEq, Eq, Copy, Debug)]
 pub struct SplDonations {
     pub mint: Pubkey,
 
     pub total_mint_amount: u64,
 
     pub patient_token_vault: Pubkey,
+
+    // Contributions In This Mint Not Yet Refunded, Used To Size Pro-Rata Refunds
+    pub total_contributed: u64,
+
+    // Donor Whose First Donation In This Mint Created (And Paid For) The Vault
+    pub vault_payer: Pubkey,
+}
+
+
+// CASE IDS ARE PLAIN NUMBERS ON-CHAIN; "CASE0001" IS ONLY HOW THEY ARE DISPLAYED
+// Instructions Still Take The Case ID As A String, Accepting Both "CASE0001" And "1"
+pub fn parse_case_id(case_id: &str) -> Option<u64> {
+    let digits = case_id.strip_prefix(CASE_ID_PREFIX).unwrap_or(case_id);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|number| *number > 0)
+}
+
+// Used In Account Seeds And Constraints; Malformed IDs Map To 0, Which No Case Ever Has
+pub fn case_number(case_id: &str) -> u64 {
+    parse_case_id(case_id).unwrap_or(0)
+}
+
+pub fn format_case_id(case_id: u64) -> String {
+    format!("{}{:04}", CASE_ID_PREFIX, case_id)
+}
+
+
+// CREATE A TOMBSTONE PDA PER CLOSED REJECTED CASE, SO ITS ID IS NEVER MISTAKEN FOR ONE THAT NEVER EXISTED
+#[account]
+#[derive(InitSpace)]
+pub struct RejectedCaseTombstone {
+    pub case_id: u64,
+
+    pub rejected_at: i64,
+
+    pub tombstone_bump: u8,
 }
 
 
@@ -91,8 +1075,7 @@ pub struct SplDonations {
 #[account]
 #[derive(InitSpace)]
 pub struct CaseIDLookup{
-    #[max_len(10)]
-    pub case_id_in_lookup: String,
+    pub case_id_in_lookup: u64,
 
     pub patient_pda: Pubkey,
 
@@ -101,6 +1084,9 @@ pub struct CaseIDLookup{
     pub case_lookup_bump: u8,
 
     pub patient_escrow_bump: u8,
+
+    // Verifier Or Admin Whose Verification Created (And Paid For) The Escrow
+    pub escrow_payer: Pubkey,
 }
 
 
@@ -111,6 +1097,44 @@ pub struct Verifier{
     pub verifier_key: Pubkey,
     pub is_verifier: bool,
     pub verifier_bump: u8,
+    // x25519 Public Key Patients Seal Records Keys To; All Zeroes Until The Verifier Sets It
+    pub encryption_key: [u8; 32],
+    // How Much The Verifier's Vote Counts Under The Weighted Strategy; 1 Unless The Admin Changes It
+    pub vote_weight: u16,
+}
+
+
+// CREATE A RECORDS ACCESS GRANT PER CASE AND VERIFIER, HOLDING THE PATIENT'S RECORDS KEY
+// SEALED TO THAT VERIFIER'S ENCRYPTION KEY
+#[account]
+#[derive(InitSpace)]
+pub struct RecordsAccessGrant {
+    pub case_id: u64,
+
+    // The Case Manager Who Granted Access And Paid The Grant Rent
+    pub granted_by: Pubkey,
+
+    pub verifier: Pubkey,
+
+    pub envelope: SealedEnvelope,
+
+    pub granted_at: i64,
+
+    pub grant_bump: u8,
+}
+
+// A 32-Byte Records Key Encrypted With AES-256-GCM Under A Key Agreed Via x25519
+// Between A One-Time Ephemeral Key And The Recipient's Encryption Key
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub struct SealedEnvelope {
+    pub recipient_key: [u8; 32],
+
+    pub ephemeral_public_key: [u8; 32],
+
+    pub nonce: [u8; 12],
+
+    // Encrypted Records Key Followed By The 16-Byte Authentication Tag
+    pub ciphertext: [u8; 48],
 }
 
 
@@ -119,23 +1143,27 @@ pub struct Verifier{
 pub struct VerifiersList {
     pub all_verifiers: Vec<Pubkey>,
     pub verifier_registry_bump: u8,
+    // Sum Of The Vote Weights Of Every Listed Verifier
+    pub total_weight: u64,
 }
 
 impl VerifiersList {
     // Function to Add verifier Onto The Verifiers List
-    pub fn add_verifier_pda_to_list(&mut self, verifier_to_add: Pubkey) -> Result<()> {
+    pub fn add_verifier_pda_to_list(&mut self, verifier_to_add: Pubkey, vote_weight: u16) -> Result<()> {
         require!(!self.all_verifiers.contains(&verifier_to_add), CuraChainError::VerifierAlreadyExists);
 
         self.all_verifiers.push(verifier_to_add);
+        self.total_weight = self.total_weight.checked_add(vote_weight as u64).ok_or(CuraChainError::OverflowError)?;
         Ok(())
     }
 
     // Function to Remove Verifier From The Verifiers List
-    pub fn remove_verifier_pda_from_list(&mut self, verifier_to_remove: &Pubkey) -> Result<()> {
+    pub fn remove_verifier_pda_from_list(&mut self, verifier_to_remove: &Pubkey, vote_weight: u16) -> Result<()> {
         //require!(self.all_verifiers.contains(&verifier_to_remove), MedifundError::VerifierNotFound);
 
         if let Some(index) = self.all_verifiers.iter().position(|x| x == verifier_to_remove) {
             self.all_verifiers.remove(index);
+            self.total_weight = self.total_weight.checked_sub(vote_weight as u64).ok_or(CuraChainError::UnderflowError)?;
             Ok(())
         } else {
             err!(CuraChainError::VerifierNotFound)
@@ -148,8 +1176,7 @@ impl VerifiersList {
 #[account]
 #[derive(InitSpace)]
 pub struct Proposal {
-    #[max_len(10)]
-    pub case_id: String,
+    pub case_id: u64,
 
     pub proposal_index: u64,
 
@@ -161,6 +1188,9 @@ pub struct Proposal {
     pub executed: bool,
 
     pub proposal_bump: u8,
+
+    // The Facility Invoice This Proposal Pays Towards
+    pub invoice: Pubkey,
 }
 
 
@@ -170,6 +1200,23 @@ pub struct MultisigApprovals {
 
     pub approval: bool,
 }
+// CREATE A CONTRIBUTION PDA PER DONOR, CASE AND MINT, SO DONORS CAN RECLAIM WHAT THEY GAVE
+// SOL Contributions Use NATIVE_SOL_ADDRESS As Their Mint
+#[account]
+#[derive(InitSpace)]
+pub struct Contribution {
+    pub donor: Pubkey,
+
+    pub case_id: u64,
+
+    pub mint: Pubkey,
+
+    pub amount: u64,
+
+    pub contribution_bump: u8,
+}
+
+
 // CREATE A DONOR INFO PDA HERE
 #[account]
 #[derive(InitSpace)]
@@ -181,6 +1228,231 @@ pub struct DonorInfo {
     pub total_donations: u64,
 
     #[max_len(1000)]
-    pub donated_cases: Vec<[u8;8]>,
+    pub donated_cases: Vec<u64>,
+}
+   
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice(amount: u64) -> Invoice {
+        Invoice {
+            case_id: 1,
+            facility: Pubkey::new_unique(),
+            invoice_number_hash: [1u8; 32],
+            document_hash: [2u8; 32],
+            mint: NATIVE_SOL_ADDRESS,
+            amount,
+            amount_paid: 0,
+            due_date: 0,
+            status: InvoiceStatus::Open,
+            submitted_at: 0,
+            invoice_bump: 255,
+        }
+    }
+
+    fn treatment_plan(amounts: &[u64]) -> TreatmentPlan {
+        let milestones = amounts.iter().map(|&amount| Milestone {
+            amount,
+            required_proof: ProofType::FacilityAttestation,
+            proof_hash: None,
+            attested_by: None,
+            attested_at: 0,
+            amount_released: 0,
+        }).collect();
+
+        TreatmentPlan { case_id: 1, milestones, next_milestone: 0, total_released: 0, plan_bump: 255 }
+    }
+
+    fn records_fingerprint() -> RecordsFingerprint {
+        RecordsFingerprint { fingerprint: [7u8; 32], live_case_ids: vec![], submissions: 0, first_submitted_at: 1_000, fingerprint_bump: 255 }
+    }
+
+    #[test]
+    fn refiling_after_a_withdrawal_is_not_flagged() {
+        let mut records = records_fingerprint();
+        assert_eq!(records.file_case(1).unwrap(), None);
+
+        // Withdrawn Before Verification: Its Lookup Is Closed, So It Leaves The Live List
+        records.release_case(1);
+        assert_eq!(records.file_case(2).unwrap(), None);
+        assert_eq!(records.live_case_ids, vec![2]);
+        assert_eq!(records.submissions, 2);
+    }
+
+    #[test]
+    fn repeated_withdrawals_never_lock_the_records() {
+        let mut records = records_fingerprint();
+        for case_id in 1..=(MAX_LIVE_CASES_PER_RECORDS as u64 * 2) {
+            assert_eq!(records.file_case(case_id).unwrap(), None);
+            records.release_case(case_id);
+        }
+        assert!(records.live_case_ids.is_empty());
+    }
+
+    #[test]
+    fn filing_alongside_a_live_case_is_flagged_against_the_newest() {
+        let mut records = records_fingerprint();
+        records.file_case(1).unwrap();
+        assert_eq!(records.file_case(2).unwrap(), Some(1));
+        assert_eq!(records.file_case(3).unwrap(), Some(2));
+
+        // Closing The Newest Falls Back To The Next Newest Still Live
+        records.release_case(3);
+        assert_eq!(records.file_case(4).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn records_squatted_by_other_filers_can_still_be_filed() {
+        let mut records = records_fingerprint();
+        for squatter_case in 1..=MAX_LIVE_CASES_PER_RECORDS as u64 {
+            records.file_case(squatter_case).unwrap();
+        }
+
+        // The Genuine Filing Goes Through, Flagged So Verifiers See The Overlap
+        let genuine_case = MAX_LIVE_CASES_PER_RECORDS as u64 + 1;
+        assert_eq!(records.file_case(genuine_case).unwrap(), Some(genuine_case - 1));
+        assert_eq!(records.live_case_ids.len(), MAX_LIVE_CASES_PER_RECORDS);
+        assert_eq!(records.active_case_id(), Some(genuine_case));
+        assert!(!records.live_case_ids.contains(&1));
+        assert_eq!(records.submissions, genuine_case as u32);
+
+        // Closing A Case Already Dropped From The List Is Harmless
+        records.release_case(1);
+        assert_eq!(records.live_case_ids.len(), MAX_LIVE_CASES_PER_RECORDS);
+    }
+
+    // An Appeal Round With Two First-Round Verifiers Recused, Weighing 3 And 2, Out Of A Registry Of 10
+    fn appeal_round() -> (PatientCase, VerifiersList, [Pubkey; 2]) {
+        let recused = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let fresh = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut verifiers_list = VerifiersList { all_verifiers: vec![], verifier_registry_bump: 255, total_weight: 0 };
+        verifiers_list.add_verifier_pda_to_list(recused[0], 3).unwrap();
+        verifiers_list.add_verifier_pda_to_list(recused[1], 2).unwrap();
+        verifiers_list.add_verifier_pda_to_list(fresh[0], 4).unwrap();
+        verifiers_list.add_verifier_pda_to_list(fresh[1], 1).unwrap();
+
+        let mut case = crate::states::fixtures::patient_case();
+        case.verification_round = 2;
+        case.recused_verifiers = vec![
+            RecusedVerifier { verifier: recused[0], vote_weight: 3 },
+            RecusedVerifier { verifier: recused[1], vote_weight: 2 },
+        ];
+        (case, verifiers_list, recused)
+    }
+
+    #[test]
+    fn recused_weight_is_taken_off_the_eligible_weight() {
+        let (case, verifiers_list, _) = appeal_round();
+        let tally = case.tally(&verifiers_list);
+
+        assert_eq!(tally.eligible_voters, 2);
+        assert_eq!(tally.eligible_weight, 5);
+    }
+
+    #[test]
+    fn recused_verifier_removed_from_the_registry_is_not_subtracted_twice() {
+        let (case, mut verifiers_list, recused) = appeal_round();
+
+        // Removing It Already Took Its Weight Off total_weight
+        verifiers_list.remove_verifier_pda_from_list(&recused[0], 3).unwrap();
+        assert_eq!(verifiers_list.total_weight, 7);
+
+        assert_eq!(case.registered_recused_weight(&verifiers_list), 2);
+        assert_eq!(case.tally(&verifiers_list).eligible_weight, 5);
+        assert_eq!(case.tally(&verifiers_list).eligible_voters, 2);
+    }
+
+    #[test]
+    fn invoice_is_partially_paid_then_settled() {
+        let mut invoice = invoice(1_000);
+        assert_eq!(invoice.outstanding(), 1_000);
+
+        invoice.record_payment(400).unwrap();
+        assert_eq!(invoice.status, InvoiceStatus::PartiallyPaid);
+        assert_eq!(invoice.outstanding(), 600);
+
+        invoice.record_payment(600).unwrap();
+        assert_eq!(invoice.status, InvoiceStatus::Settled);
+        assert_eq!(invoice.outstanding(), 0);
+    }
+
+    #[test]
+    fn invoice_settles_in_a_single_payment() {
+        let mut invoice = invoice(1_000);
+        invoice.record_payment(1_000).unwrap();
+
+        assert_eq!(invoice.status, InvoiceStatus::Settled);
+        assert_eq!(invoice.amount_paid, 1_000);
+    }
+
+    #[test]
+    fn invoice_refuses_to_be_overpaid() {
+        let mut partly_paid = invoice(1_000);
+        partly_paid.record_payment(700).unwrap();
+        assert_eq!(partly_paid.record_payment(301).unwrap_err(), error!(CuraChainError::OverflowError));
+
+        // A Running Total That Would Wrap Is Refused Too
+        let mut saturated = invoice(1_000);
+        saturated.amount_paid = u64::MAX;
+        assert_eq!(saturated.record_payment(1).unwrap_err(), error!(CuraChainError::OverflowError));
+    }
+
+    #[test]
+    fn milestone_proofs_need_a_verified_case_still_in_treatment() {
+        let mut case = crate::states::fixtures::patient_case();
+        assert_eq!(case.ensure_in_treatment().unwrap_err(), error!(CuraChainError::CaseNotYetVerified));
+
+        case.is_verified = true;
+        case.case_status = CaseStatus::Verified;
+        assert!(case.ensure_in_treatment().is_ok());
+
+        // Funds Still Raised Before Expiry Can Be Released, So Proofs Still Count
+        case.case_status = CaseStatus::Expired;
+        assert!(case.ensure_in_treatment().is_ok());
+
+        // A Case Refunding Donors, Or Withdrawn With Its Funds Redirected, Pays Nothing Out Against Its Plan
+        case.case_status = CaseStatus::Refunding;
+        assert_eq!(case.ensure_in_treatment().unwrap_err(), error!(CuraChainError::CaseRefunding));
+
+        case.case_status = CaseStatus::Redirecting;
+        assert_eq!(case.ensure_in_treatment().unwrap_err(), error!(CuraChainError::CaseAlreadyWithdrawn));
+    }
+
+    #[test]
+    fn milestone_advances_only_once_fully_released() {
+        let mut plan = treatment_plan(&[500, 300]);
+        assert_eq!(plan.current_milestone().unwrap().amount, 500);
+
+        plan.record_release(200).unwrap();
+        assert_eq!(plan.next_milestone, 0);
+        assert_eq!(plan.current_milestone().unwrap().amount_released, 200);
+
+        plan.record_release(300).unwrap();
+        assert_eq!(plan.next_milestone, 1);
+        assert_eq!(plan.current_milestone().unwrap().amount, 300);
+        assert_eq!(plan.total_released, 500);
+    }
+
+    #[test]
+    fn releasing_past_the_last_milestone_is_rejected() {
+        let mut plan = treatment_plan(&[500, 300]);
+        plan.record_release(500).unwrap();
+        plan.record_release(300).unwrap();
+
+        assert_eq!(plan.total_released, 800);
+        assert_eq!(plan.current_milestone().unwrap_err(), error!(CuraChainError::AllMilestonesReleased));
+        assert_eq!(plan.record_release(1).unwrap_err(), error!(CuraChainError::AllMilestonesReleased));
+    }
+
+    #[test]
+    fn release_cannot_exceed_the_current_milestone() {
+        let mut plan = treatment_plan(&[500, 300]);
+        plan.record_release(400).unwrap();
+
+        // The Surplus Does Not Spill Into The Next Milestone
+        assert_eq!(plan.record_release(101).unwrap_err(), error!(CuraChainError::OverflowError));
+    }
 }
-   
\ No newline at end of file
@@ -9,4 +9,42 @@ pub const NATIVE_SOL_ADDRESS: Pubkey = Pubkey::new_from_array([0u8;32]);
 pub const MULTISIG_THRESHOLD: u8 = 3;
 pub const DONATION_BUFFER: u64 = 1_000_000_000 * (10_u64.pow(6));
 
-pub const ALLOWED_VERIFICATION_TIME: u64 =  864_000;
\ No newline at end of file
+// Longest Verification Window Any Urgency Level Can Be Given
+pub const ALLOWED_VERIFICATION_TIME: u64 =  864_000;
+
+pub const URGENCY_LEVELS: usize = 3;
+
+pub const MAX_AMOUNT_TIERS: usize = 5;
+
+// Matches The Multisig's Member Cap, Since Only Members Co-Approve
+pub const MAX_CO_APPROVALS: usize = 5;
+
+// Largest Senior Verifier Panel Split Votes Can Be Escalated To
+pub const MAX_SENIOR_VERIFIERS: usize = 7;
+
+// Most Live Cases Tracked Per Records Bundle; Past This The Oldest Is Dropped From The List, Never The New Filing
+pub const MAX_LIVE_CASES_PER_RECORDS: usize = 8;
+
+// Caps How Often Verifiers Can Pause A Single Verification Round
+pub const MAX_INFO_REQUESTS_PER_ROUND: u8 = 3;
+
+// A Question Left Unanswered This Long Stops Pausing The Verification Clock
+pub const INFO_REQUEST_RESPONSE_WINDOW: i64 = 7 * 24 * 60 * 60;
+// Must Stay In Sync With The max_len Attribute On ManifestRef
+pub const MAX_MANIFEST_URI_LEN: usize = 200;
+
+pub const MAX_FACILITY_NAME_LEN: usize = 64;
+
+pub const MAX_MILESTONES: usize = 12;
+
+pub const MAX_RELEASE_RECORDS: usize = 16;
+
+pub const MAX_UPDATES_PER_PAGE: usize = 8;
+
+// SOL Plus Every SPL Mint A Case Can Hold
+pub const MAX_RECEIPT_MINTS: usize = 21;
+
+// Highest Share Of A Rejected Case's Rent The Admin Can Award To Whoever Closes It
+pub const MAX_REJECTED_CLOSE_BOUNTY_BPS: u16 = 500;
+
+pub const CASE_ID_PREFIX: &str = "CASE";
\ No newline at end of file
@@ -6,7 +6,7 @@ use anchor_spl::{
     metadata::{ MasterEditionAccount, Metadata, MetadataAccount}, 
     token_interface::{ Mint, TokenAccount, TokenInterface}};
 
-use crate::states::{accounts::*, errors::*};
+use crate::states::{accounts::*, constants::*, errors::*};
 
 
 
@@ -52,7 +52,7 @@ pub struct VerifierInfo<'info> {
     #[account(
         init_if_needed,
         payer = admin,
-        space = 8 + 32 + 1 + 1,
+        space = 8 + 32 + 1 + 1 + 32 + 2,
         seeds = [b"verifier_role", verifier_address.key().as_ref()],
         bump,
     )]
@@ -70,6 +70,55 @@ pub struct VerifierInfo<'info> {
 }
 
 
+// ADMIN CHANGES HOW MUCH A VERIFIER'S VOTE COUNTS UNDER THE WEIGHTED STRATEGY
+#[derive(Accounts)]
+#[instruction(verifier_address: Pubkey)]
+pub struct SetVerifierWeight<'info> {
+    #[account(
+        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin", admin.key().as_ref()],
+        bump = admin_account.bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_role", verifier_address.key().as_ref()],
+        bump = verifier.verifier_bump,
+    )]
+    pub verifier: Account<'info, Verifier>,
+
+    #[account(
+        mut,
+        seeds = [b"verifiers_list"],
+        bump = verifiers_list.verifier_registry_bump,
+    )]
+    pub verifiers_list: Account<'info, VerifiersList>,
+}
+
+
+// VERIFIER PUBLISHES THE ENCRYPTION KEY PATIENTS SEAL RECORDS KEYS TO
+#[derive(Accounts)]
+pub struct SetVerifierEncryptionKey<'info> {
+    #[account(
+        constraint = verifier.key() == verifier_account.verifier_key.key() @ CuraChainError::OnlyVerifier,
+    )]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_role", verifier.key().as_ref()],
+        bump = verifier_account.verifier_bump,
+        constraint = verifier_account.is_verifier @ CuraChainError::OnlyVerifier,
+    )]
+    pub verifier_account: Account<'info, Verifier>,
+}
+
+
 // INITIALIZING OUR NFT COLLECTION MINT SO EACH REGULAR NFTS MINTED TO DONORS CAN BE VERIFIED AGAINST THIS
 #[derive(Accounts)]
 pub struct InitializeNftCollection<'info> {
@@ -166,7 +215,7 @@ pub struct InitializeVerifiersRegistryMultisigAndCaseCounter<'info> {
         payer = admin,
         seeds = [b"verifiers_list"],
         bump,
-        space = 8 + 4 + (32 * 100) + 1,
+        space = 8 + 4 + (32 * 100) + 1 + 8,
     )]
     pub verifiers_registry_list: Account<'info, VerifiersList>,
 
@@ -220,16 +269,55 @@ pub struct AddorRemoveMultisigMember<'info> {
     
 }
 
+// ADMIN CREATES OR UPDATES THE PROTOCOL CONFIG
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin", admin.key().as_ref()],
+        bump = admin_account.bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [b"protocol_config"],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // Only Needed When Escalation Is Enabled, To Check The Panel Can Meet Its Quorum
+    #[account(
+        seeds = [b"senior_panel"],
+        bump = senior_panel.panel_bump,
+    )]
+    pub senior_panel: Option<Account<'info, SeniorPanel>>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // INITIALIZE PATIENT CASE context
 #[derive(Accounts)]
+#[instruction(manifest: ManifestRef, fingerprint: [u8; 32])]
 pub struct InitializePatientCase<'info> {
     // Signer is patient
-    #[account(mut)]
     pub patient: Signer<'info>,
 
+    // Pays The Rent And Bond: The Patient Themselves, Or An NGO Or Relayer Sponsoring The Submission
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         init,
-        payer = patient,
+        payer = payer,
         space = 8 + PatientCase::INIT_SPACE,
         seeds = [b"patient", patient.key().as_ref()],
         bump
@@ -247,118 +335,117 @@ pub struct InitializePatientCase<'info> {
     // Let's Bring Up The Case ID Lookup PDA here
     #[account(
         init,
-        payer = patient,
+        payer = payer,
         space = 8 + CaseIDLookup::INIT_SPACE,
         seeds = [b"case_lookup",
-        format!("CASE{:04}", case_counter.current_id + 1).as_bytes()],
+        (case_counter.current_id + 1).to_le_bytes().as_ref()],
         bump
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
-    pub system_program: Program<'info, System>,
-}
-
-
-
-// A VIEW INSTRUCTION FOR TRACKING PATIENT CASE STATUS ON-CHAIN
-#[derive(Accounts)]
-#[instruction(case_id: String)]
-pub struct PatientDetails<'info> {
-    #[account(mut)]
-    pub caller: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
-    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
+    // Holds The Anti-Spam Bond; Its Associated Token Account Holds SPL Bonds
+    /// CHECK: System Account PDA That Only Ever Holds The Bond, Verified By Its Seeds
     #[account(
-        seeds = [b"case_lookup", case_id.as_bytes()],
-        bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        mut,
+        seeds = [b"bond_vault", (case_counter.current_id + 1).to_le_bytes().as_ref()],
+        bump
     )]
-    pub case_lookup: Account<'info, CaseIDLookup>,
+    pub bond_vault: UncheckedAccount<'info>,
 
+    // One Per Records Bundle, Shared By Every Case Filed With It
     #[account(
-        seeds = [b"patient", case_lookup.patient_address.as_ref()],
-        bump = patient_case.patient_case_bump,
-        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        init_if_needed,
+        payer = payer,
+        space = 8 + RecordsFingerprint::INIT_SPACE,
+        seeds = [b"records_fingerprint", fingerprint.as_ref()],
+        bump
     )]
-    pub patient_case: Account<'info, PatientCase>,
+    pub records_fingerprint: Account<'info, RecordsFingerprint>,
 
+    pub system_program: Program<'info, System>,
 }
 
-// INITIALIZE THE VERIFICATION INSTRUCTION
+
+// GUARDIAN, SOCIAL WORKER OR NGO SUBMITS A CASE ON BEHALF OF A BENEFICIARY
 #[derive(Accounts)]
-#[instruction(case_id: String)]
-pub struct VerifyPatientCase<'info> {
+#[instruction(beneficiary: Pubkey, manifest: ManifestRef, fingerprint: [u8; 32])]
+pub struct InitializeRepresentedPatientCase<'info> {
+    // Signer Is The Submitter, Who Pays For And Manages The Case
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
     #[account(
-        mut,
-        constraint = verifier.key() == verifier_account.verifier_key.key() @ CuraChainError::OnlyVerifier,
+        init,
+        payer = submitter,
+        space = 8 + PatientCase::INIT_SPACE,
+        seeds = [b"patient", beneficiary.as_ref()],
+        bump
     )]
-    pub verifier: Signer<'info>,
+    pub patient_case: Account<'info, PatientCase>,
 
     #[account(
         mut,
-        seeds = [b"verifier_role", verifier.key().as_ref()],
-        bump = verifier_account.verifier_bump
+        seeds = [b"case_counter"],
+        bump = case_counter.counter_bump,
     )]
-    pub verifier_account: Account<'info, Verifier>,
+    pub case_counter: Account<'info, CaseCounter>,
 
-    // I think i should add the global verifiers registry so that i can query it for the total votes cast
     #[account(
-        mut,
-        seeds = [b"verifiers_list"],
-        bump = verifiers_list.verifier_registry_bump,
+        init,
+        payer = submitter,
+        space = 8 + CaseIDLookup::INIT_SPACE,
+        seeds = [b"case_lookup",
+        (case_counter.current_id + 1).to_le_bytes().as_ref()],
+        bump
     )]
-    pub verifiers_list: Account<'info, VerifiersList>,
+    pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
     #[account(
-        mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
-        bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
     )]
-    pub case_lookup: Account<'info, CaseIDLookup>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
+    // Holds The Anti-Spam Bond; Its Associated Token Account Holds SPL Bonds
+    /// CHECK: System Account PDA That Only Ever Holds The Bond, Verified By Its Seeds
     #[account(
         mut,
-        seeds = [b"patient", case_lookup.patient_address.as_ref()],
-        bump = patient_case.patient_case_bump,
-        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        seeds = [b"bond_vault", (case_counter.current_id + 1).to_le_bytes().as_ref()],
+        bump
     )]
-    pub patient_case: Account<'info, PatientCase>,
+    pub bond_vault: UncheckedAccount<'info>,
 
-    /// CHECKED: This account does not exist yet, and may be created upon successful verification
+    // One Per Records Bundle, Shared By Every Case Filed With It
     #[account(
-        mut,
+        init_if_needed,
+        payer = submitter,
+        space = 8 + RecordsFingerprint::INIT_SPACE,
+        seeds = [b"records_fingerprint", fingerprint.as_ref()],
+        bump
     )]
-    pub patient_escrow: AccountInfo<'info>,
+    pub records_fingerprint: Account<'info, RecordsFingerprint>,
 
     pub system_program: Program<'info, System>,
 }
 
 
+// CASE MANAGER AMENDS THE CASE BEFORE IT IS VERIFIED
 #[derive(Accounts)]
 #[instruction(case_id: String)]
-pub struct AdminOverrideCase<'info> {
-    #[account(
-        mut,
-        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
-    )]
-    pub admin: Signer<'info>,
-
-    #[account(
-        mut,
-        seeds = [b"admin", admin.key().as_ref()],
-        bump = admin_account.bump
-    )]
-    pub admin_account: Account<'info, Administrator>,
+pub struct AmendPatientCase<'info> {
+    #[account(mut)]
+    pub case_manager: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
@@ -367,491 +454,1834 @@ pub struct AdminOverrideCase<'info> {
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_manager == case_manager.key() @ CuraChainError::OnlyCaseManager,
     )]
     pub patient_case: Account<'info, PatientCase>,
 
-    /// CHECK: This account does not exist yet and will be created as the escrow PDA for the patient case. It is safe because the PDA is derived and checked in the instruction.
     #[account(
-        mut,
-        // This account does not exist yet, and may be created upon admin override verification
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
     )]
-    pub patient_escrow: AccountInfo<'info>,
-
-    pub system_program: Program<'info, System>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
-// IF CASE FAILS VERIFICATION, WE CALL THIS INSTRUCTION TO CLOSE THE PATIENT CASE PDA
+// PATIENT OR CASE MANAGER APPEALS A REJECTED CASE INTO A SECOND VERIFICATION ROUND
 #[derive(Accounts)]
 #[instruction(case_id: String)]
-
-pub struct ClosePatientCase<'info> {
-
-    // Anybody can call this instruction to close the patient case
+pub struct AppealPatientCase<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub appellant: Signer<'info>,
 
-    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
     #[account(
-        mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
     #[account(
         mut,
-        close = user,// I would like the lamports to return to the person closing this account.
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = appellant.key() == patient_case.patient_pubkey || appellant.key() == patient_case.case_manager
+            @ CuraChainError::UnauthorizedAppellant,
     )]
     pub patient_case: Account<'info, PatientCase>,
 
-    // Have The Verifier Registry So I Can Query The Expected Number Of Verifiers To Have Voted
+    // Only One Appeal Per Case, So This Can Only Ever Be Created Once
+    #[account(
+        init,
+        payer = appellant,
+        space = 8 + CaseAppeal::INIT_SPACE,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub case_appeal: Account<'info, CaseAppeal>,
+
     #[account(
-        mut,
         seeds = [b"verifiers_list"],
         bump = verifiers_list.verifier_registry_bump,
     )]
     pub verifiers_list: Account<'info, VerifiersList>,
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
-
-
-
-
-// DONOR'S CONTEXT STRUCT
+// CASE MANAGER WITHDRAWS THE CASE
 #[derive(Accounts)]
 #[instruction(case_id: String)]
-pub struct SolDonation<'info> {
-    #[account(mut)]
-    pub donor: Signer<'info>,
+pub struct WithdrawPatientCase<'info> {
+    pub case_manager: Signer<'info>,
 
-    // Donor Info PDA here
+    // Rent Of Closed Accounts Goes Back To Whoever Paid For The Case: The Sponsor, Or Else The Submitter
+    /// CHECK: Only Receives Lamports, And Must Match The Rent Payer Recorded On The Case
     #[account(
-        init_if_needed,
-        payer = donor,
-        seeds = [b"donor", donor.key().as_ref()],
-        bump,
-        space = 8 + DonorInfo::INIT_SPACE,
+        mut,
+        constraint = submitter.key() == patient_case.rent_payer() @ CuraChainError::InvalidSubmitter,
     )]
-    pub donor_account: Account<'info, DonorInfo>,
+    pub submitter: UncheckedAccount<'info>,
 
-    // Get Case Lookup pda using specified Case ID
     #[account(
         mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // We Use the case_lookup to find the Patient case
     #[account(
         mut,
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_manager == case_manager.key() @ CuraChainError::OnlyCaseManager,
     )]
     pub patient_case: Account<'info, PatientCase>,
 
-    /// CHECKED: This account has already been created and it's safe now. 
+    // Releases The Records When A Case Withdrawn Before Verification Is Closed
     #[account(
         mut,
-        //seeds = [b"patient_escrow", patient_case.case_id.as_bytes() ,patient_case.key().as_ref(),],
-        //bump = case_lookup.patient_escrow_bump,
+        seeds = [b"records_fingerprint", patient_case.records_fingerprint.as_ref()],
+        bump = records_fingerprint.fingerprint_bump,
     )]
-    pub patient_escrow: AccountInfo<'info>,
+    pub records_fingerprint: Account<'info, RecordsFingerprint>,
+
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// CHECK: The Submitter On A Refund, Or The Forfeit Pool On A Forfeit; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
 
     #[account(
-        mut,
-        seeds = [b"multisig", b"escrow-authority".as_ref()],
-        bump = multisig.multisig_bump
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
     )]
-    pub multisig: Account<'info, Multisig>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
 
-/*
-@. 1. Donor Can Donate Any Preferred token to a particular case
-@. 2. Donor Account That Tracks Donations Across All Cases
-@. 3. Recognition NFT for Donor
- */
 
-// DONOR'S CONTEXT STRUCT
+// A VIEW INSTRUCTION FOR TRACKING PATIENT CASE STATUS ON-CHAIN; DEPRECATED IN FAVOUR OF GetCaseSummary
 #[derive(Accounts)]
 #[instruction(case_id: String)]
-pub struct SplDonation<'info> {
+pub struct PatientDetails<'info> {
     #[account(mut)]
-    pub donor: Signer<'info>,
+    pub caller: Signer<'info>,
 
-    // Donor Info PDA here
+    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
     #[account(
-        init_if_needed,
-        payer = donor,
-        seeds = [b"donor", donor.key().as_ref()],
-        bump,
-        space = 8 + DonorInfo::INIT_SPACE,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
-    pub donor_account: Account<'info, DonorInfo>,
-
-    pub donation_token: InterfaceAccount<'info, Mint>,
+    pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // Donor ATA From Which To Donate Token
     #[account(
-        mut,
-        associated_token::mint = donation_token,
-        associated_token::authority = donor.key()
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
     )]
-    pub donor_ata: InterfaceAccount<'info, TokenAccount>,
+    pub patient_case: Account<'info, PatientCase>,
+}
 
-    // Get Case Lookup pda using specified Case ID
+
+// READ-ONLY VIEW OF A PATIENT CASE, RETURNED AS A CaseSummary
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct GetCaseSummary<'info> {
     #[account(
-        mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // We Use the case_lookup to find the Patient case
     #[account(
-        mut,
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
     )]
     pub patient_case: Account<'info, PatientCase>,
+}
 
-    /// CHECKED: This account has already been created and it's safe now. 
+
+// READ-ONLY VIEW OF A DONOR, RETURNED AS A DonorSummary
+#[derive(Accounts)]
+#[instruction(donor: Pubkey)]
+pub struct GetDonorSummary<'info> {
     #[account(
-        mut,
-        //seeds = [b"patient_escrow", patient_case.case_id.as_bytes() ,patient_case.key().as_ref(),],
-        //bump = case_lookup.patient_escrow_bump,
+        seeds = [b"donor", donor.as_ref()],
+        bump = donor_account.donor_bump,
     )]
-    pub patient_escrow: AccountInfo<'info>,
+    pub donor_account: Account<'info, DonorInfo>,
+}
 
-    // Get Associated Token Vault For Specified Patient Case
+
+// READ-ONLY VIEW OF A RELEASE PROPOSAL, RETURNED AS A ProposalStatus
+#[derive(Accounts)]
+#[instruction(case_id: String, proposal_index: u64)]
+pub struct GetProposalStatus<'info> {
     #[account(
-        init_if_needed,
-        payer = donor,
-        seeds = [
-            b"patient_token_vault",
-            case_id.as_bytes().as_ref(),
-            patient_escrow.key().as_ref(),
-            donation_token.key().as_ref()
-        ],
-        bump,
-        token::authority = multisig,
-        token::mint = donation_token// Not using associated_token::mint and authority because of customized seeds
+        seeds = [b"proposal", case_number(&case_id).to_le_bytes().as_ref(), proposal_index.to_le_bytes().as_ref()],
+        bump = proposal.proposal_bump,
     )]
-    pub patient_token_vault: InterfaceAccount<'info, TokenAccount>,
+    pub proposal: Account<'info, Proposal>,
 
+    // Holds The Approval Threshold proposal_approve Enforces
     #[account(
-        mut,
         seeds = [b"multisig", b"escrow-authority".as_ref()],
         bump = multisig.multisig_bump
     )]
     pub multisig: Account<'info, Multisig>,
-
-    
-    pub associated_token_program: Program<'info, AssociatedToken>,
-
-    pub token_program: Interface<'info, TokenInterface>,
-
-    pub system_program: Program<'info, System>,
-
-    pub rent: Sysvar<'info, Rent>,
 }
 
 
+// READ-ONLY VIEW OF PROTOCOL-WIDE FIGURES, RETURNED AS ProtocolStats
 #[derive(Accounts)]
-#[instruction(case_id: String)]
-pub struct MintNFT<'info> {
-    #[account(mut)]
-    pub donor: Signer<'info>,
-
+pub struct GetProtocolStats<'info> {
     #[account(
-        mut,
-        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
+        seeds = [b"case_counter"],
+        bump = case_counter.counter_bump,
     )]
-    pub admin: Signer<'info>,
+    pub case_counter: Account<'info, CaseCounter>,
 
     #[account(
-        mut,
-        seeds = [b"admin", admin.key().as_ref()],
-        bump = admin_account.bump
+        seeds = [b"verifiers_list"],
+        bump = verifiers_list.verifier_registry_bump,
     )]
-    pub admin_account: Account<'info, Administrator>,
+    pub verifiers_list: Account<'info, VerifiersList>,
 
-    // Donor Info PDA here
-    #[account(
-        init_if_needed,
-        payer = donor,
-        seeds = [b"donor", donor.key().as_ref()],
-        bump,
-        space = 8 + DonorInfo::INIT_SPACE,
-    )]
-    pub donor_account: Box<Account<'info, DonorInfo>>,
-    
     #[account(
-        mut,
         seeds = [b"multisig", b"escrow-authority".as_ref()],
         bump = multisig.multisig_bump
     )]
-    pub multisig: Box<Account<'info, Multisig>>,
+    pub multisig: Account<'info, Multisig>,
 
-    // RECOGNITION NFT LOGIC
-    // Recognition Collection NFT for Curachain ----- Assumes Program's NFT Recognition Has Been Minted
-    pub parent_recognition_collection_nft: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
 
-    // donor nft is a regular nft, verified to belong to the collection nft of curachain
+// INITIALIZE THE VERIFICATION INSTRUCTION
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct VerifyPatientCase<'info> {
     #[account(
         mut,
-        seeds =[
-            b"metadata",
-            metadata_program.key().as_ref(),
-            parent_recognition_collection_nft.key().as_ref()
-        ],
-        seeds::program = metadata_program.key(),
-        bump,
+        constraint = verifier.key() == verifier_account.verifier_key.key() @ CuraChainError::OnlyVerifier,
     )]
-    pub parent_collection_nft_metadata: Account<'info, MetadataAccount>,
+    pub verifier: Signer<'info>,
 
     #[account(
         mut,
-        seeds =[
-            b"metadata",
-            metadata_program.key().as_ref(),
-            parent_recognition_collection_nft.key().as_ref(),
-            b"edition",
-        ],
-        seeds::program = metadata_program.key(),
-        bump,
+        seeds = [b"verifier_role", verifier.key().as_ref()],
+        bump = verifier_account.verifier_bump
     )]
-    pub parent_collection_master_edition: Account<'info, MasterEditionAccount>,
+    pub verifier_account: Account<'info, Verifier>,
 
-    // Create A Child Mint For Each Donor-Case
+    // I think i should add the global verifiers registry so that i can query it for the total votes cast
     #[account(
-        init_if_needed,
-        payer = donor,
-        seeds = [b"recognition_nft", donor.key().as_ref(), case_id.as_bytes()],
-        bump,
-        mint::decimals = 0,
-        mint::authority = multisig,
-        mint::freeze_authority = multisig,
+        mut,
+        seeds = [b"verifiers_list"],
+        bump = verifiers_list.verifier_registry_bump,
     )]
-    pub donor_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub verifiers_list: Account<'info, VerifiersList>,
 
+    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
     #[account(
-        init_if_needed,
-        payer = donor,
-        associated_token::mint = donor_nft_mint,
-        associated_token::authority = donor,
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
-    pub donor_nft_account: InterfaceAccount<'info, TokenAccount>,
+    pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // donor nft is a regular nft, verified to belong to the collection nft of curachain
-    /// CHECK: Initialized Via Metaplex CPI, so safe
     #[account(
         mut,
-        seeds =[
-            b"metadata",
-            metadata_program.key().as_ref(),
-            donor_nft_mint.key().as_ref()
-        ],
-        seeds::program = metadata_program.key(),
-        bump,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
     )]
-    pub donor_nft_metadata: UncheckedAccount<'info>,
+    pub patient_case: Account<'info, PatientCase>,
 
-    /// CHECK: Initialized Via Metaplex CPI, so safe
+    /// CHECKED: This account does not exist yet, and may be created upon successful verification
     #[account(
         mut,
-        seeds =[
-            b"metadata",
-            metadata_program.key().as_ref(),
-            donor_nft_mint.key().as_ref(),
-            b"edition",
-        ],
-        seeds::program = metadata_program.key(),
-        bump,
     )]
-    pub master_edition: UncheckedAccount<'info>,
+    pub patient_escrow: AccountInfo<'info>,
 
-    pub metadata_program: Program<'info, Metadata>,
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
+    #[account(
+        mut,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
+    )]
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: The Submitter On A Refund, Or The Forfeit Pool On A Forfeit; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
-
-    pub rent: Sysvar<'info, Rent>,
 }
 
 
-// Multisig Operations => Any of The Members Can Propose Funds Release
+// ANYBODY SETTLES A CASE'S VERIFICATION ONCE ITS WINDOW HAS CLOSED
 #[derive(Accounts)]
-#[instruction(case_id: String, proposal_index: u64)]
-pub struct ProposeFundRelease<'info> {
-
-    #[account(
-        mut,
-        constraint = multisig.multisig_members.contains(&proposer.key) @CuraChainError::NotMultisigMember,
-    )]
-    pub proposer: Signer<'info>,
+#[instruction(case_id: String)]
+pub struct FinalizeVerification<'info> {
+    // Pays For The Escrow If The Case Is Verified
+    #[account(mut)]
+    pub caller: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [b"multisig", b"escrow-authority".as_ref()],
-        bump = multisig.multisig_bump
+        seeds = [b"verifiers_list"],
+        bump = verifiers_list.verifier_registry_bump,
     )]
-    pub multisig: Account<'info, Multisig>,
+    pub verifiers_list: Account<'info, VerifiersList>,
 
-    // Get Case Lookup pda using specified Case ID
     #[account(
         mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // We Use the case_lookup to find the Patient case
     #[account(
         mut,
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
     )]
     pub patient_case: Account<'info, PatientCase>,
 
-    // Initialize The Proposal
+    /// CHECKED: This account does not exist yet, and is created if the case is verified
+    #[account(mut)]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
     #[account(
-        init,
-        payer = proposer,
-        space = 8 + Proposal::INIT_SPACE,
-        seeds = [b"proposal", case_id.as_bytes(), proposal_index.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
+
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Whoever Posted The Bond, Refunded If The Case Is Verified; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 
-// Multisig Members Votes To Either Approve Or Reject The Transfer Proposal
+// ADMIN ADDS OR REMOVES A SENIOR VERIFIER, CREATING THE SENIOR PANEL ON FIRST USE
 #[derive(Accounts)]
-#[instruction(case_id: String, proposal_index: u64)]
-pub struct ApproveProposal<'info> {
+pub struct UpdateSeniorPanel<'info> {
     #[account(
         mut,
-        constraint = multisig.multisig_members.contains(&multisig_member.key) @CuraChainError::NotMultisigMember,
+        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
     )]
-    pub multisig_member: Signer<'info>,
+    pub admin: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [b"multisig", b"escrow-authority".as_ref()],
-        bump = multisig.multisig_bump
+        seeds = [b"admin", admin.key().as_ref()],
+        bump = admin_account.bump
     )]
-    pub multisig: Account<'info, Multisig>,
+    pub admin_account: Account<'info, Administrator>,
 
-    // Get Case Lookup pda using specified Case ID
     #[account(
-        mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
-        bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        init_if_needed,
+        payer = admin,
+        space = 8 + SeniorPanel::INIT_SPACE,
+        seeds = [b"senior_panel"],
+        bump,
     )]
-    pub case_lookup: Account<'info, CaseIDLookup>,
+    pub senior_panel: Account<'info, SeniorPanel>,
 
-    // Get The Proposal
+    // Its Escalation Quorum Bounds How Small The Panel Can Get
     #[account(
-        mut,
-        seeds = [b"proposal", case_id.as_bytes(), proposal_index.to_le_bytes().as_ref()],
-        bump = proposal.proposal_bump,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
 }
 
 
-// FUND RELEASE TO TREATMENT FACILITY
+// SENIOR VERIFIERS DECIDE A SPLIT VOTE ESCALATED TO THEIR PANEL
 #[derive(Accounts)]
-#[instruction(case_id: String, proposal_index: u64)]
-pub struct ReleaseFunds<'info> {
-    // Get Case Lookup pda using specified Case ID
+#[instruction(case_id: String)]
+pub struct SeniorPanelVote<'info> {
+    // The Senior Verifier Whose Vote Verifies The Case Pays For The Escrow
+    #[account(
+        mut,
+        constraint = senior_panel.members.contains(senior_verifier.key) @ CuraChainError::NotSeniorVerifier,
+    )]
+    pub senior_verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"senior_panel"],
+        bump = senior_panel.panel_bump,
+    )]
+    pub senior_panel: Account<'info, SeniorPanel>,
+
     #[account(
         mut,
-        seeds = [b"case_lookup", case_id.as_bytes()],
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
         bump = case_lookup.case_lookup_bump,
-        constraint = case_lookup.case_id_in_lookup == case_id @CuraChainError::InvalidCaseID,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
     pub case_lookup: Account<'info, CaseIDLookup>,
 
-    // We Use the case_lookup to find the Patient case
     #[account(
         mut,
         seeds = [b"patient", case_lookup.patient_address.as_ref()],
         bump = patient_case.patient_case_bump,
         constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
-        constraint = patient_case.case_id == case_id @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
     )]
     pub patient_case: Account<'info, PatientCase>,
 
-    /// CHECKED: This account has already been created and it's safe now. 
+    /// CHECKED: This account does not exist yet, and is created if the panel verifies the case
+    #[account(mut)]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
     #[account(
         mut,
-        //seeds = [b"patient_escrow", case_id.as_bytes().as_ref() ,patient_case.key().as_ref(),],
-        //bump = case_lookup.patient_escrow_bump,
-        owner = system_program.key(),
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
     )]
-    pub patient_escrow: AccountInfo<'info>,
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
 
-    ///CHECKED: The Facility Address To Receive Funds For Patient Treatment
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
     #[account(mut)]
-    pub facility_address: AccountInfo<'info>,
+    pub bond_vault: UncheckedAccount<'info>,
 
-    // Facility Token ATA:= to receive spl donations made to a case
+    /// CHECK: Whoever Posted The Bond, Refunded If The Case Is Verified; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
 
-    // Any Of The Multisig Members ( Of Which Admin Is A Member) Can Initiate Transfer
+    pub system_program: Program<'info, System>,
+}
+
+
+// MULTISIG MEMBERS CO-APPROVE A CASE WHOSE AMOUNT TIER NEEDS MORE THAN THE VERIFIERS' VOTE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct CoApproveVerification<'info> {
+    // The Member Whose Co-Approval Completes Verification Pays For The Escrow
     #[account(
         mut,
-        constraint = multisig.multisig_members.contains(&transfer_authority.key) @ CuraChainError::UnauthorizedToTransfer,
+        constraint = multisig.multisig_members.contains(multisig_member.key) @CuraChainError::NotMultisigMember,
     )]
-    pub transfer_authority: Signer<'info>,
+    pub multisig_member: Signer<'info>,
 
     #[account(
-        mut,
         seeds = [b"multisig", b"escrow-authority".as_ref()],
         bump = multisig.multisig_bump
     )]
     pub multisig: Account<'info, Multisig>,
 
-    // Get The Proposal
     #[account(
         mut,
-        seeds = [b"proposal", case_id.as_bytes(), proposal_index.to_le_bytes().as_ref()],
-        bump = proposal.proposal_bump,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
     )]
-    pub proposal: Account<'info, Proposal>,
-
-    /// CHECKED:  Remaining Accounts: Token Mint In Spl Donations, Patient Token Vault, Facility ATA
-    //pub remaining_accounts: Vec<AccountInfo<'info>>,
-
-    pub system_program: Program<'info, System>,
-
-    pub associated_token: Program<'info, AssociatedToken>,
+    pub case_lookup: Account<'info, CaseIDLookup>,
 
-    pub token_program: Interface<'info, TokenInterface>
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECKED: This account does not exist yet, and is created once the case is verified
+    #[account(mut)]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
+    #[account(
+        mut,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
+    )]
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
+
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Whoever Posted The Bond, Refunded Once The Case Is Verified; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// VERIFIER PAUSES VERIFICATION TO ASK THE PATIENT FOR MORE INFORMATION
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct RequestCaseInfo<'info> {
+    #[account(
+        constraint = verifier.key() == verifier_account.verifier_key.key() @ CuraChainError::OnlyVerifier,
+    )]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"verifier_role", verifier.key().as_ref()],
+        bump = verifier_account.verifier_bump
+    )]
+    pub verifier_account: Account<'info, Verifier>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+}
+
+
+// PATIENT OR CASE MANAGER ANSWERS AN OPEN REQUEST FOR INFORMATION, RESUMING VERIFICATION
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct RespondToInfoRequest<'info> {
+    pub responder: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = responder.key() == patient_case.patient_pubkey || responder.key() == patient_case.case_manager
+            @ CuraChainError::UnauthorizedInfoResponder,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct AdminOverrideCase<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin", admin.key().as_ref()],
+        bump = admin_account.bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECK: This account does not exist yet and will be created as the escrow PDA for the patient case. It is safe because the PDA is derived and checked in the instruction.
+    #[account(
+        mut,
+        // This account does not exist yet, and may be created upon admin override verification
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
+    #[account(
+        mut,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
+    )]
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
+
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// CHECK: The Submitter On A Refund, Or The Forfeit Pool On A Forfeit; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// IF CASE FAILS VERIFICATION, WE CALL THIS INSTRUCTION TO CLOSE THE PATIENT CASE PDA
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+
+pub struct ClosePatientCase<'info> {
+
+    // Anybody can call this instruction to close the patient case
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Let's get the Case Lookup PDA using the specified case ID of the original format, CASE####
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECK: Paid The Case Rent (The Sponsor, Or Else The Submitter), So Gets It Back Less The Closing Bounty
+    #[account(
+        mut,
+        constraint = submitter.key() == patient_case.rent_payer() @ CuraChainError::InvalidSubmitter,
+    )]
+    pub submitter: UncheckedAccount<'info>,
+
+    // Releases The Records So They Are No Longer Held By This Case
+    #[account(
+        mut,
+        seeds = [b"records_fingerprint", patient_case.records_fingerprint.as_ref()],
+        bump = records_fingerprint.fingerprint_bump,
+    )]
+    pub records_fingerprint: Account<'info, RecordsFingerprint>,
+
+    // Records The Case ID As Rejected Once The Case And Its Lookup Are Gone
+    #[account(
+        init,
+        payer = user,
+        space = 8 + RejectedCaseTombstone::INIT_SPACE,
+        seeds = [b"rejected_case", case_number(&case_id).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub case_tombstone: Account<'info, RejectedCaseTombstone>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.config_bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // Have The Verifier Registry So I Can Query The Expected Number Of Verifiers To Have Voted
+    #[account(
+        mut,
+        seeds = [b"verifiers_list"],
+        bump = verifiers_list.verifier_registry_bump,
+    )]
+    pub verifiers_list: Account<'info, VerifiersList>,
+
+    // Only Needed Once The Case Has Been Appealed, To Record How The Second Round Ended
+    #[account(
+        mut,
+        seeds = [b"case_appeal", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_appeal.appeal_bump,
+    )]
+    pub case_appeal: Option<Account<'info, CaseAppeal>>,
+
+    /// CHECK: The Case's Bond Vault PDA, Checked Against Its Stored Bump When The Bond Is Settled
+    #[account(mut)]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// CHECK: The Submitter On A Refund, Or The Forfeit Pool On A Forfeit; Checked When The Bond Is Settled
+    #[account(mut)]
+    pub bond_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+
+
+
+// DONOR'S CONTEXT STRUCT
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct SolDonation<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    // Donor Info PDA here
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"donor", donor.key().as_ref()],
+        bump,
+        space = 8 + DonorInfo::INIT_SPACE,
+    )]
+    pub donor_account: Account<'info, DonorInfo>,
+
+    // Get Case Lookup pda using specified Case ID
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    // We Use the case_lookup to find the Patient case
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECKED: This account has already been created and it's safe now. 
+    #[account(
+        mut,
+        //seeds = [b"patient_escrow", patient_case.case_id.to_le_bytes().as_ref() ,patient_case.key().as_ref(),],
+        //bump = case_lookup.patient_escrow_bump,
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // Tracks This Donor's SOL Contributions To This Case For Refunds
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + Contribution::INIT_SPACE,
+        seeds = [b"contribution", case_number(&case_id).to_le_bytes().as_ref(), donor.key().as_ref(), NATIVE_SOL_ADDRESS.as_ref()],
+        bump,
+    )]
+    pub contribution: Box<Account<'info, Contribution>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+@. 1. Donor Can Donate Any Preferred token to a particular case
+@. 2. Donor Account That Tracks Donations Across All Cases
+@. 3. Recognition NFT for Donor
+ */
+
+// DONOR'S CONTEXT STRUCT
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct SplDonation<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    // Donor Info PDA here
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"donor", donor.key().as_ref()],
+        bump,
+        space = 8 + DonorInfo::INIT_SPACE,
+    )]
+    pub donor_account: Account<'info, DonorInfo>,
+
+    pub donation_token: InterfaceAccount<'info, Mint>,
+
+    // Donor ATA From Which To Donate Token
+    #[account(
+        mut,
+        associated_token::mint = donation_token,
+        associated_token::authority = donor.key()
+    )]
+    pub donor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // Get Case Lookup pda using specified Case ID
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    // We Use the case_lookup to find the Patient case
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECKED: This account has already been created and it's safe now. 
+    #[account(
+        mut,
+        //seeds = [b"patient_escrow", patient_case.case_id.to_le_bytes().as_ref() ,patient_case.key().as_ref(),],
+        //bump = case_lookup.patient_escrow_bump,
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // Get Associated Token Vault For Specified Patient Case
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [
+            b"patient_token_vault",
+            case_number(&case_id).to_le_bytes().as_ref(),
+            patient_escrow.key().as_ref(),
+            donation_token.key().as_ref()
+        ],
+        bump,
+        token::authority = multisig,
+        token::mint = donation_token// Not using associated_token::mint and authority because of customized seeds
+    )]
+    pub patient_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // Tracks This Donor's Contributions In This Token To This Case For Refunds
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + Contribution::INIT_SPACE,
+        seeds = [b"contribution", case_number(&case_id).to_le_bytes().as_ref(), donor.key().as_ref(), donation_token.key().as_ref()],
+        bump,
+    )]
+    pub contribution: Box<Account<'info, Contribution>>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct MintNFT<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == admin_account.admin_pubkey.key() @ CuraChainError::OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin", admin.key().as_ref()],
+        bump = admin_account.bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    // Donor Info PDA here
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"donor", donor.key().as_ref()],
+        bump,
+        space = 8 + DonorInfo::INIT_SPACE,
+    )]
+    pub donor_account: Box<Account<'info, DonorInfo>>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    // RECOGNITION NFT LOGIC
+    // Recognition Collection NFT for Curachain ----- Assumes Program's NFT Recognition Has Been Minted
+    pub parent_recognition_collection_nft: InterfaceAccount<'info, Mint>,
+
+    // donor nft is a regular nft, verified to belong to the collection nft of curachain
+    #[account(
+        mut,
+        seeds =[
+            b"metadata",
+            metadata_program.key().as_ref(),
+            parent_recognition_collection_nft.key().as_ref()
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub parent_collection_nft_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        mut,
+        seeds =[
+            b"metadata",
+            metadata_program.key().as_ref(),
+            parent_recognition_collection_nft.key().as_ref(),
+            b"edition",
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub parent_collection_master_edition: Account<'info, MasterEditionAccount>,
+
+    // Create A Child Mint For Each Donor-Case
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"recognition_nft", donor.key().as_ref(), case_number(&case_id).to_le_bytes().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = multisig,
+        mint::freeze_authority = multisig,
+    )]
+    pub donor_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = donor_nft_mint,
+        associated_token::authority = donor,
+    )]
+    pub donor_nft_account: InterfaceAccount<'info, TokenAccount>,
+
+    // donor nft is a regular nft, verified to belong to the collection nft of curachain
+    /// CHECK: Initialized Via Metaplex CPI, so safe
+    #[account(
+        mut,
+        seeds =[
+            b"metadata",
+            metadata_program.key().as_ref(),
+            donor_nft_mint.key().as_ref()
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub donor_nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Initialized Via Metaplex CPI, so safe
+    #[account(
+        mut,
+        seeds =[
+            b"metadata",
+            metadata_program.key().as_ref(),
+            donor_nft_mint.key().as_ref(),
+            b"edition",
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    pub metadata_program: Program<'info, Metadata>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+
+// Multisig Operations => Any of The Members Can Propose Funds Release
+#[derive(Accounts)]
+#[instruction(case_id: String, proposal_index: u64)]
+pub struct ProposeFundRelease<'info> {
+
+    #[account(
+        mut,
+        constraint = multisig.multisig_members.contains(&proposer.key) @CuraChainError::NotMultisigMember,
+    )]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // Get Case Lookup pda using specified Case ID
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    // We Use the case_lookup to find the Patient case
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    // The Facility Invoice The Proposal Pays Towards
+    #[account(
+        seeds = [b"invoice", case_number(&case_id).to_le_bytes().as_ref(), invoice.invoice_number_hash.as_ref()],
+        bump = invoice.invoice_bump,
+        constraint = invoice.case_id == case_number(&case_id) @ CuraChainError::InvoiceMismatch,
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    // Initialize The Proposal
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", case_number(&case_id).to_le_bytes().as_ref(), proposal_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// Multisig Members Votes To Either Approve Or Reject The Transfer Proposal
+#[derive(Accounts)]
+#[instruction(case_id: String, proposal_index: u64)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        mut,
+        constraint = multisig.multisig_members.contains(&multisig_member.key) @CuraChainError::NotMultisigMember,
+    )]
+    pub multisig_member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // Get Case Lookup pda using specified Case ID
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    // Get The Proposal
+    #[account(
+        mut,
+        seeds = [b"proposal", case_number(&case_id).to_le_bytes().as_ref(), proposal_index.to_le_bytes().as_ref()],
+        bump = proposal.proposal_bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+
+// FUND RELEASE TO TREATMENT FACILITY
+#[derive(Accounts)]
+#[instruction(case_id: String, proposal_index: u64)]
+pub struct ReleaseFunds<'info> {
+    // Get Case Lookup pda using specified Case ID
+    #[account(
+        mut,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    // We Use the case_lookup to find the Patient case
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECKED: This account has already been created and it's safe now. 
+    #[account(
+        mut,
+        //seeds = [b"patient_escrow", case_number(&case_id).to_le_bytes().as_ref() ,patient_case.key().as_ref(),],
+        //bump = case_lookup.patient_escrow_bump,
+        owner = system_program.key(),
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // The Registered Facility This Case Is Bound To
+    #[account(
+        seeds = [b"facility", facility.facility_key.as_ref()],
+        bump = facility.facility_bump,
+        constraint = patient_case.facility == Some(facility.key()) @ CuraChainError::FacilityNotBound,
+        constraint = facility.status == FacilityStatus::Verified @ CuraChainError::FacilityNotVerified,
+    )]
+    pub facility: Account<'info, Facility>,
+
+    ///CHECKED: The Facility Address To Receive Funds For Patient Treatment, Must Be Its Registered Payout Wallet
+    #[account(
+        mut,
+        constraint = facility_address.key() == facility.payout_wallet @ CuraChainError::InvalidPayoutWallet,
+    )]
+    pub facility_address: AccountInfo<'info>,
+
+    // Facility Token ATA:= to receive spl donations made to a case
+
+    // Any Of The Multisig Members ( Of Which Admin Is A Member) Can Initiate Transfer
+    #[account(
+        mut,
+        constraint = multisig.multisig_members.contains(&transfer_authority.key) @ CuraChainError::UnauthorizedToTransfer,
+    )]
+    pub transfer_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // Get The Proposal
+    #[account(
+        mut,
+        seeds = [b"proposal", case_number(&case_id).to_le_bytes().as_ref(), proposal_index.to_le_bytes().as_ref()],
+        bump = proposal.proposal_bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // The Invoice The Proposal Pays Towards; Payments Are Capped At What Is Still Owed On It
+    #[account(
+        mut,
+        seeds = [b"invoice", case_number(&case_id).to_le_bytes().as_ref(), invoice.invoice_number_hash.as_ref()],
+        bump = invoice.invoice_bump,
+        constraint = invoice.key() == proposal.invoice @ CuraChainError::InvoiceMismatch,
+        constraint = invoice.facility == facility.key() @ CuraChainError::InvoiceMismatch,
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    // Required Whenever The Case Has A Treatment Plan, Which Then Caps Each Release At The Next Milestone
+    #[account(
+        mut,
+        seeds = [b"treatment_plan", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = treatment_plan.plan_bump,
+    )]
+    pub treatment_plan: Option<Account<'info, TreatmentPlan>>,
+
+    /// CHECKED:  Remaining Accounts For Token Invoices: Invoice Mint, Patient Token Vault, Facility ATA
+    //pub remaining_accounts: Vec<AccountInfo<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub associated_token: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>
+}
+
+
+// ONCE EVERYTHING RAISED HAS BEEN RELEASED, ANYBODY CAN ARCHIVE A CASE INTO A COMPACT RECEIPT
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct FinalizeAndArchiveCase<'info> {
+    // Pays For The Receipt; Rent Of Everything Closed Goes Back To Whoever Paid It
+    #[account(mut)]
+    pub archiver: Signer<'info>,
+
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECK: Receives The Case And Lookup Rent; Must Be Whoever Paid It, The Sponsor Or Else The Submitter
+    #[account(
+        mut,
+        constraint = submitter.key() == patient_case.rent_payer() @ CuraChainError::InvalidSubmitter,
+    )]
+    pub submitter: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA Holding The Case's SOL, Verified By Its Seeds
+    #[account(
+        mut,
+        seeds = [b"patient_escrow", case_number(&case_id).to_le_bytes().as_ref(), patient_case.key().as_ref()],
+        bump = case_lookup.patient_escrow_bump,
+        owner = system_program.key(),
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    /// CHECK: Receives The Escrow Rent; Must Be Whoever Created The Escrow
+    #[account(
+        mut,
+        constraint = escrow_payer.key() == case_lookup.escrow_payer @ CuraChainError::InvalidEscrowPayer,
+    )]
+    pub escrow_payer: UncheckedAccount<'info>,
+
+    // Releases The Records So They Are No Longer Held By This Case
+    #[account(
+        mut,
+        seeds = [b"records_fingerprint", patient_case.records_fingerprint.as_ref()],
+        bump = records_fingerprint.fingerprint_bump,
+    )]
+    pub records_fingerprint: Account<'info, RecordsFingerprint>,
+
+    #[account(
+        init,
+        payer = archiver,
+        space = 8 + CaseReceipt::INIT_SPACE,
+        seeds = [b"case_receipt", case_number(&case_id).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub case_receipt: Account<'info, CaseReceipt>,
+
+    // Authority Over The Patient Token Vaults, Needed To Close Them
+    #[account(
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// CHECKED: Remaining Accounts, One Pair Per SPL Mint The Case Holds: Patient Token Vault, Vault Payer
+    //pub remaining_accounts: Vec<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// ANYBODY CAN EXPIRE A CASE ONCE ITS CAMPAIGN DEADLINE HAS PASSED
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct ExpirePatientCase<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+}
+
+
+// DONOR RECLAIMS THEIR SOL CONTRIBUTION FROM A REFUNDING CASE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct ClaimSolRefund<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    /// CHECK: Escrow PDA Holding The Case's SOL, Verified By Its Seeds
+    #[account(
+        mut,
+        seeds = [b"patient_escrow", case_number(&case_id).to_le_bytes().as_ref(), patient_case.key().as_ref()],
+        bump = case_lookup.patient_escrow_bump,
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    // The Contribution Is Closed Once Refunded, Returning Its Rent To The Donor
+    #[account(
+        mut,
+        close = donor,
+        seeds = [b"contribution", case_number(&case_id).to_le_bytes().as_ref(), donor.key().as_ref(), NATIVE_SOL_ADDRESS.as_ref()],
+        bump = contribution.contribution_bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// DONOR RECLAIMS THEIR SPL CONTRIBUTION FROM A REFUNDING CASE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct ClaimTokenRefund<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Box<Account<'info, PatientCase>>,
+
+    /// CHECK: Escrow PDA Of The Case, Only Used To Derive The Token Vault
+    #[account(
+        seeds = [b"patient_escrow", case_number(&case_id).to_le_bytes().as_ref(), patient_case.key().as_ref()],
+        bump = case_lookup.patient_escrow_bump,
+    )]
+    pub patient_escrow: AccountInfo<'info>,
+
+    pub donation_token: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"patient_token_vault",
+            case_number(&case_id).to_le_bytes().as_ref(),
+            patient_escrow.key().as_ref(),
+            donation_token.key().as_ref()
+        ],
+        bump,
+        token::authority = multisig,
+        token::mint = donation_token,
+    )]
+    pub patient_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = donation_token,
+        associated_token::authority = donor,
+    )]
+    pub donor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = donor,
+        seeds = [b"contribution", case_number(&case_id).to_le_bytes().as_ref(), donor.key().as_ref(), donation_token.key().as_ref()],
+        bump = contribution.contribution_bump,
+    )]
+    pub contribution: Box<Account<'info, Contribution>>,
+
+    #[account(
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// CASE MANAGER SHARES THE RECORDS KEY WITH ONE VERIFIER
+#[derive(Accounts)]
+#[instruction(case_id: String, verifier_address: Pubkey)]
+pub struct GrantRecordsAccess<'info> {
+    #[account(mut)]
+    pub case_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_manager == case_manager.key() @ CuraChainError::OnlyCaseManager,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        seeds = [b"verifier_role", verifier_address.as_ref()],
+        bump = verifier_account.verifier_bump,
+        constraint = verifier_account.is_verifier @ CuraChainError::InvalidVerifierAddress,
+    )]
+    pub verifier_account: Account<'info, Verifier>,
+
+    // Granting Again Replaces The Envelope, E.g. After The Verifier Rotates Their Key
+    #[account(
+        init_if_needed,
+        payer = case_manager,
+        space = 8 + RecordsAccessGrant::INIT_SPACE,
+        seeds = [b"records_access", case_number(&case_id).to_le_bytes().as_ref(), verifier_address.as_ref()],
+        bump,
+    )]
+    pub records_access_grant: Account<'info, RecordsAccessGrant>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// CASE MANAGER REVOKES A VERIFIER'S RECORDS ACCESS, RETURNING THE GRANT RENT TO WHOEVER PAID IT
+#[derive(Accounts)]
+#[instruction(case_id: String, verifier_address: Pubkey)]
+pub struct RevokeRecordsAccess<'info> {
+    pub case_manager: Signer<'info>,
+
+    /// CHECK: Only Receives Lamports, And Must Match The Granter Recorded On The Grant
+    #[account(
+        mut,
+        constraint = granted_by.key() == records_access_grant.granted_by @ CuraChainError::InvalidSubmitter,
+    )]
+    pub granted_by: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_manager == case_manager.key() @ CuraChainError::OnlyCaseManager,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        mut,
+        close = granted_by,
+        seeds = [b"records_access", case_number(&case_id).to_le_bytes().as_ref(), verifier_address.as_ref()],
+        bump = records_access_grant.grant_bump,
+    )]
+    pub records_access_grant: Account<'info, RecordsAccessGrant>,
+}
+
+
+// BENEFICIARY TAKES OVER MANAGEMENT OF A CASE SUBMITTED ON THEIR BEHALF
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct AssumeCaseControl<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.patient_pubkey == beneficiary.key() @ CuraChainError::OnlyPatient,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+}
+
+
+// ADMIN OR A MULTISIG MEMBER REGISTERS A TREATMENT FACILITY
+#[derive(Accounts)]
+#[instruction(facility_key: Pubkey)]
+pub struct RegisterFacility<'info> {
+    #[account(
+        mut,
+        constraint = multisig.is_admin_or_member(&authority.key()) @ CuraChainError::NotMultisigMember,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Facility::INIT_SPACE,
+        seeds = [b"facility", facility_key.as_ref()],
+        bump,
+    )]
+    pub facility: Account<'info, Facility>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// ADMIN OR A MULTISIG MEMBER UPDATES A FACILITY'S PAYOUT WALLET OR VERIFICATION STATUS
+#[derive(Accounts)]
+#[instruction(facility_key: Pubkey)]
+pub struct UpdateFacility<'info> {
+    #[account(
+        constraint = multisig.is_admin_or_member(&authority.key()) @ CuraChainError::NotMultisigMember,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [b"facility", facility_key.as_ref()],
+        bump = facility.facility_bump,
+    )]
+    pub facility: Account<'info, Facility>,
+}
+
+
+// BIND A CASE TO THE REGISTERED FACILITY THAT WILL TREAT THE PATIENT
+#[derive(Accounts)]
+#[instruction(case_id: String, facility_key: Pubkey)]
+pub struct BindCaseFacility<'info> {
+    // Either The Case Manager, Or The Admin / A Multisig Member
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"multisig", b"escrow-authority".as_ref()],
+        bump = multisig.multisig_bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        seeds = [b"facility", facility_key.as_ref()],
+        bump = facility.facility_bump,
+        constraint = facility.status == FacilityStatus::Verified @ CuraChainError::FacilityNotVerified,
+    )]
+    pub facility: Account<'info, Facility>,
+}
+
+
+// A BOUND FACILITY SUBMITS AN INVOICE AGAINST A CASE
+#[derive(Accounts)]
+#[instruction(case_id: String, invoice_number_hash: [u8; 32])]
+pub struct SubmitInvoice<'info> {
+    #[account(mut)]
+    pub facility_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"facility", facility_signer.key().as_ref()],
+        bump = facility.facility_bump,
+        constraint = facility.status == FacilityStatus::Verified @ CuraChainError::FacilityNotVerified,
+    )]
+    pub facility: Account<'info, Facility>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.facility == Some(facility.key()) @ CuraChainError::FacilityNotBound,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        init,
+        payer = facility_signer,
+        space = 8 + Invoice::INIT_SPACE,
+        seeds = [b"invoice", case_number(&case_id).to_le_bytes().as_ref(), invoice_number_hash.as_ref()],
+        bump,
+    )]
+    pub invoice: Account<'info, Invoice>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// CASE MANAGER SETS THE MILESTONE SCHEDULE BEFORE THE CASE IS VERIFIED
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct SetTreatmentPlan<'info> {
+    #[account(mut)]
+    pub case_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_manager == case_manager.key() @ CuraChainError::OnlyCaseManager,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        init_if_needed,
+        payer = case_manager,
+        space = 8 + TreatmentPlan::INIT_SPACE,
+        seeds = [b"treatment_plan", case_number(&case_id).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub treatment_plan: Account<'info, TreatmentPlan>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+// THE BOUND FACILITY ATTESTS THAT THE NEXT MILESTONE'S TREATMENT TOOK PLACE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct AttestMilestone<'info> {
+    pub facility_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"facility", facility_signer.key().as_ref()],
+        bump = facility.facility_bump,
+    )]
+    pub facility: Account<'info, Facility>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.facility == Some(facility.key()) @ CuraChainError::FacilityNotBound,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        mut,
+        seeds = [b"treatment_plan", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = treatment_plan.plan_bump,
+    )]
+    pub treatment_plan: Account<'info, TreatmentPlan>,
+}
+
+
+// A REGISTERED VERIFIER SIGNS OFF ON THE NEXT MILESTONE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct SignOffMilestone<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"verifier_role", verifier.key().as_ref()],
+        bump = verifier_account.verifier_bump,
+        constraint = verifier_account.is_verifier @ CuraChainError::OnlyVerifier,
+    )]
+    pub verifier_account: Account<'info, Verifier>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    #[account(
+        mut,
+        seeds = [b"treatment_plan", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = treatment_plan.plan_bump,
+    )]
+    pub treatment_plan: Account<'info, TreatmentPlan>,
+}
+
+
+// THE PATIENT, CASE MANAGER OR BOUND FACILITY POSTS A PROGRESS UPDATE TO A CASE'S TIMELINE
+#[derive(Accounts)]
+#[instruction(case_id: String)]
+pub struct PostCaseUpdate<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    // Only Needed When The Bound Facility Is The One Posting
+    #[account(
+        seeds = [b"facility", poster.key().as_ref()],
+        bump = facility.facility_bump,
+    )]
+    pub facility: Option<Account<'info, Facility>>,
+
+    #[account(
+        seeds = [b"case_lookup", case_number(&case_id).to_le_bytes().as_ref()],
+        bump = case_lookup.case_lookup_bump,
+        constraint = case_lookup.case_id_in_lookup == case_number(&case_id) @CuraChainError::InvalidCaseID,
+    )]
+    pub case_lookup: Account<'info, CaseIDLookup>,
+
+    #[account(
+        mut,
+        seeds = [b"patient", case_lookup.patient_address.as_ref()],
+        bump = patient_case.patient_case_bump,
+        constraint = patient_case.key() == case_lookup.patient_pda.key() @ CuraChainError::InvalidCaseID,
+        constraint = patient_case.case_id == case_number(&case_id) @ CuraChainError::InvalidCaseID,
+    )]
+    pub patient_case: Account<'info, PatientCase>,
+
+    // The Page The Next Update Lands In, Opened When The Previous Page Fills Up
+    #[account(
+        init_if_needed,
+        payer = poster,
+        space = 8 + CaseUpdate::INIT_SPACE,
+        seeds = [
+            b"case_update",
+            case_number(&case_id).to_le_bytes().as_ref(),
+            (patient_case.update_count / MAX_UPDATES_PER_PAGE as u32).to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub case_update: Account<'info, CaseUpdate>,
+
+    pub system_program: Program<'info, System>,
 }
\ No newline at end of file
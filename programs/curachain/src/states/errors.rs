@@ -134,4 +134,286 @@ pub enum CuraChainError{
 
     #[msg("Voting period has expired. The case requires admin intervention now.")]
     VotingPeriodExpired,
+
+    #[msg("Only The Patient Who Submitted The Case Can Perform This Action")]
+    OnlyPatient,
+
+    #[msg("Manifest URI Is Empty Or Exceeds The Maximum Allowed Length")]
+    ManifestUriTooLong,
+
+    #[msg("Case Manifest Version Is Not Supported")]
+    UnsupportedManifestVersion,
+
+    #[msg("Amendment Does Not Change Any Field Of The Case")]
+    EmptyAmendment,
+
+    #[msg("Case Cannot Be Amended After Verifiers Have Started Voting")]
+    AmendmentAfterVotes,
+
+    #[msg("Case Has Already Been Withdrawn")]
+    CaseAlreadyWithdrawn,
+
+    #[msg("Case Is Not Accepting Donations In Its Current Status")]
+    CaseNotAcceptingDonations,
+
+    #[msg("Funds Cannot Be Released While The Case Is Refunding Donors")]
+    CaseRefunding,
+
+    #[msg("Campaign Duration Must Be Positive And Within The Protocol Maximum")]
+    InvalidCampaignDuration,
+
+    #[msg("Campaign Deadline Has Passed: Donations Are Closed")]
+    CampaignEnded,
+
+    #[msg("Campaign Deadline Has Not Passed Yet")]
+    CampaignStillActive,
+
+    #[msg("Case Cannot Be Expired In Its Current Status")]
+    CaseNotExpirable,
+
+    #[msg("Refunds Are Only Available While The Case Is Refunding Donors")]
+    CaseNotRefunding,
+
+    #[msg("All-Or-Nothing Case Has Not Reached Its Funding Goal Yet")]
+    FundingGoalNotReached,
+
+    #[msg("Case Manifest Does Not Follow The Canonical Format")]
+    InvalidManifest,
+
+    #[msg("Case Manifest Does Not Match The Hash Stored On The Case")]
+    ManifestHashMismatch,
+
+    #[msg("Verifier Has Not Set An Encryption Key Yet")]
+    EncryptionKeyNotSet,
+
+    #[msg("Envelope Was Not Sealed To The Verifier's Current Encryption Key")]
+    EnvelopeRecipientMismatch,
+
+    #[msg("Records Key Could Not Be Sealed")]
+    EncryptionError,
+
+    #[msg("Records Key Could Not Be Opened With The Given Secret")]
+    DecryptionError,
+
+    #[msg("Only The Party Currently Managing This Case Can Perform This Action")]
+    OnlyCaseManager,
+
+    #[msg("Account Does Not Match The Submitter Recorded On This Case")]
+    InvalidSubmitter,
+
+    #[msg("Submitting On Behalf Of Yourself, Use submit_cases Instead")]
+    BeneficiaryIsSubmitter,
+
+    #[msg("Beneficiary Already Manages This Case")]
+    AlreadyCaseManager,
+
+    #[msg("Facility Name Is Empty Or Too Long")]
+    InvalidFacilityName,
+
+    #[msg("Facility Is Not Verified")]
+    FacilityNotVerified,
+
+    #[msg("Case Is Not Bound To This Facility")]
+    FacilityNotBound,
+
+    #[msg("Case Is Already Bound To A Facility")]
+    FacilityAlreadyBound,
+
+    #[msg("Funds Can Only Be Paid To The Facility's Registered Payout Wallet")]
+    InvalidPayoutWallet,
+
+    #[msg("Only The Case Manager Before Verification, Or The Admin Or A Multisig Member, Can Bind A Facility")]
+    UnauthorizedFacilityBinding,
+
+    #[msg("Invoice Does Not Belong To This Case, Facility Or Proposal")]
+    InvoiceMismatch,
+
+    #[msg("Invoice Has Already Been Settled")]
+    InvoiceAlreadySettled,
+
+    #[msg("Invoice Due Date Must Be In The Future")]
+    InvalidDueDate,
+
+    #[msg("Invoice Hashes Must Not Be Empty")]
+    InvalidInvoiceHash,
+
+    #[msg("No Funds Held In The Invoice Currency")]
+    NoFundsInInvoiceMint,
+
+    #[msg("Treatment Plan Needs Between 1 And 12 Milestones, Each With A Non-Zero Amount")]
+    InvalidTreatmentPlan,
+
+    #[msg("Treatment Plan Cannot Change Once The Case Is Verified")]
+    TreatmentPlanLocked,
+
+    #[msg("This Case Disburses By Milestone, So Its Treatment Plan Must Be Provided")]
+    TreatmentPlanRequired,
+
+    #[msg("Only The Next Milestone Can Be Confirmed Or Released")]
+    MilestoneOutOfOrder,
+
+    #[msg("Milestone Proof Has Already Been Recorded")]
+    MilestoneProofAlreadyRecorded,
+
+    #[msg("Milestone Proof Has Not Been Recorded Yet")]
+    MilestoneProofMissing,
+
+    #[msg("This Milestone Requires A Different Kind Of Proof")]
+    WrongMilestoneProof,
+
+    #[msg("Every Milestone Has Already Been Released")]
+    AllMilestonesReleased,
+
+    #[msg("Milestone Proof Hash Must Not Be Empty")]
+    EmptyProofHash,
+
+    #[msg("Case Has Reached The Maximum Number Of Releases")]
+    TooManyReleases,
+
+    #[msg("Case Still Holds Funds Or Has Not Released Anything Yet")]
+    CaseNotFullyReleased,
+
+    #[msg("Escrow Payer Does Not Match The Account That Created The Escrow")]
+    InvalidEscrowPayer,
+
+    #[msg("Vault Payer Does Not Match The Account That Created The Token Vault")]
+    InvalidVaultPayer,
+
+    #[msg("Patient Token Vault Still Holds Tokens")]
+    TokenVaultNotEmpty,
+
+    #[msg("Rejected Case Close Bounty Exceeds The Allowed Maximum")]
+    InvalidCloseBounty,
+
+    #[msg("A SOL Submission Bond Must Cover The Bond Vault's Rent-Exempt Minimum")]
+    InvalidBondConfig,
+
+    #[msg("Bond Vault Does Not Match The Case's Bond Vault PDA")]
+    InvalidBondVault,
+
+    #[msg("Bond Recipient Does Not Match The Submitter Or The Forfeit Pool")]
+    InvalidBondRecipient,
+
+    #[msg("Token Program Must Be The SPL Token Or Token-2022 Program")]
+    InvalidTokenProgram,
+
+    #[msg("Only The Patient, Case Manager Or Bound Facility Can Post Case Updates")]
+    UnauthorizedUpdatePoster,
+
+    #[msg("Update URI Must Be Non-Empty And Within The Allowed Length")]
+    InvalidUpdateUri,
+
+    #[msg("Update Content Hash Must Not Be Empty")]
+    EmptyContentHash,
+
+    #[msg("Appeal Window Must Not Be Negative")]
+    InvalidAppealWindow,
+
+    #[msg("Only A Case Rejected By Verifiers Can Be Appealed")]
+    CaseNotRejected,
+
+    #[msg("Case Has Already Been Appealed")]
+    AlreadyAppealed,
+
+    #[msg("The Appeal Window For This Case Has Closed")]
+    AppealWindowClosed,
+
+    #[msg("The Case Can Still Be Appealed, So It Cannot Be Closed Yet")]
+    AppealWindowOpen,
+
+    #[msg("Verifier Voted In The First Round And Is Recused From The Appeal")]
+    VerifierRecused,
+
+    #[msg("The Case Appeal Account Is Required For An Appealed Case")]
+    AppealAccountRequired,
+
+    #[msg("Only The Patient Or The Case Manager Can Appeal A Case")]
+    UnauthorizedAppellant,
+
+    #[msg("Voting Is Paused While A Request For Information Is Open")]
+    InfoRequestPending,
+
+    #[msg("Information Can Only Be Requested On A Case Pending Verification")]
+    CaseNotPendingVerification,
+
+    #[msg("Verifiers Have Already Paused This Round The Maximum Number Of Times")]
+    TooManyInfoRequests,
+
+    #[msg("Question And Answer Hashes Must Not Be Empty")]
+    EmptyInfoHash,
+
+    #[msg("Case Has No Open Request For Information")]
+    NoOpenInfoRequest,
+
+    #[msg("Only The Patient Or The Case Manager Can Respond To A Request For Information")]
+    UnauthorizedInfoResponder,
+
+    #[msg("Records Fingerprint Must Not Be Empty")]
+    EmptyRecordsFingerprint,
+
+    #[msg("Urgency Windows Must Be Positive And Within The Maximum, And Quorums Between 1 And 10000 Basis Points")]
+    InvalidUrgencyPolicy,
+
+    #[msg("Amount Tiers Must Be Sorted By Amount, With Participation And Approval Between 1 And 10000 Basis Points")]
+    InvalidAmountTier,
+
+    #[msg("Case Has Passed Verification And Is Waiting On Multisig Co-Approval")]
+    CaseAwaitingCoApproval,
+
+    #[msg("Case Is Not Waiting On Multisig Co-Approval")]
+    CaseNotAwaitingCoApproval,
+
+    #[msg("Multisig Member Has Already Co-Approved This Case")]
+    AlreadyCoApproved,
+
+    #[msg("Verifier Vote Weight Must Be Greater Than Zero")]
+    InvalidVerifierWeight,
+
+    #[msg("Verification Window Is Still Open")]
+    VerificationWindowOpen,
+
+    #[msg("Case Verification Has Already Been Settled")]
+    VerificationAlreadyFinalized,
+
+    #[msg("Case Rejection Has Not Been Recorded Yet; Call finalize_verification First")]
+    VerificationNotFinalized,
+
+    #[msg("Escalation Grey Zone Must Be At Most 10000 Basis Points, With A Senior Quorum Between 1 And The Panel Cap")]
+    InvalidEscalationPolicy,
+
+    #[msg("Case Has Been Escalated To The Senior Panel")]
+    CaseEscalated,
+
+    #[msg("Case Is Not Waiting On The Senior Panel")]
+    CaseNotEscalated,
+
+    #[msg("Only Senior Panel Members Can Decide Escalated Cases")]
+    NotSeniorVerifier,
+
+    #[msg("Senior Verifier Has Already Voted On This Escalation")]
+    SeniorVerifierAlreadyVoted,
+
+    #[msg("Senior Panel Is Full")]
+    SeniorPanelFull,
+
+    #[msg("The Senior Panel's Decision On This Case Is Final")]
+    EscalationDecisionFinal,
+
+    #[msg("Senior Panel Would Have Fewer Members Than The Escalation Quorum")]
+    SeniorPanelBelowQuorum,
+
+    #[msg("Senior Panel Still Has Time To Decide This Escalation")]
+    EscalationWindowOpen,
+
+    #[msg("Manifest Hash Does Not Commit To This Records Fingerprint")]
+    RecordsFingerprintMismatch,
+
+    #[msg("Whoever Set A Facility's Payout Wallet Cannot Also Verify It")]
+    FacilityVerifierIsWalletSetter,
+
+    #[msg("A Rejected Case Can Only Be Appealed Or Closed, Not Amended")]
+    CaseRejected,
+
+    #[msg("A Case Under Appeal Is Judged On The Evidence It Was Appealed With, Not Amended")]
+    CaseUnderAppeal,
 }
\ No newline at end of file
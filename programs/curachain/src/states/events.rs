@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::states::{BondOutcome, CaseOutcome, CaseStatus, ExpiryPolicy, FacilityStatus, FundingMode, InvoiceStatus, ManifestRef, ProofType, RoundResult, UpdateType, UrgencyLevel};
+
 #[event]
 pub struct InitializeAdmin {
     pub admin_address: Pubkey,
@@ -14,6 +16,13 @@ pub struct GlobalRegistryInitializeEvent {
     pub message: String,
 }
 
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub message: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AddingNewVerifier {
     pub address: Pubkey,
@@ -32,16 +41,36 @@ pub struct RemovingExistingVerifier {
 #[event]
 pub struct PatientCaseSubmission {
     pub message: String,
-    pub description: String,
+    pub manifest: ManifestRef,
     pub case_id: String,
+    pub beneficiary: Pubkey,
+    pub submitter: Pubkey,
+    pub sponsor: Option<Pubkey>,
+    pub urgency: UrgencyLevel,
+    pub verification_deadline: i64,
     pub total_needed_amount: u64,
     pub total_raised: u64,
-    pub link_to_records: String,
+    pub funding_mode: FundingMode,
+    pub funding_deadline: i64,
     pub is_verified: bool,
     pub timestamp: i64,
 }
 
 
+#[event]
+pub struct PatientCaseAmended {
+    pub message: String,
+    pub case_id: String,
+    pub amendment_count: u8,
+    pub previous_manifest: Option<ManifestRef>,
+    pub new_manifest: Option<ManifestRef>,
+    pub previous_amount_needed: Option<u64>,
+    pub new_amount_needed: Option<u64>,
+    pub votes_reset: bool,
+    pub timestamp: i64,
+}
+
+
 #[event]
 pub struct PatientCaseVerificationStatus {
     pub message: String,
@@ -51,10 +80,25 @@ pub struct PatientCaseVerificationStatus {
 }
 
 
+#[event]
+pub struct PatientCaseWithdrawn {
+    pub message: String,
+    pub case_id: String,
+    pub patient_address: Pubkey,
+    pub case_closed: bool,
+    pub new_status: Option<CaseStatus>,
+    pub timestamp: i64,
+}
+
+
 #[event]
 pub struct CloseRejectedPatientCase {
     pub message: String,
     pub case_id: String,
+    pub submitter: Pubkey,
+    pub rent_returned: u64,
+    pub closed_by: Pubkey,
+    pub caller_bounty: u64,
     pub timestamp: i64,
 }
 
@@ -72,7 +116,348 @@ pub struct DonationsMade {
 pub struct ReleaseOfFunds {
     pub message: String,
     pub treatment_address: Pubkey,
+    pub invoice: Pubkey,
+    pub mint: Pubkey,
     pub transferred_amount: u64,
     pub case_id: String,
     pub timestamp: i64,
+}
+
+
+#[event]
+pub struct PatientCaseExpired {
+    pub message: String,
+    pub case_id: String,
+    pub new_status: CaseStatus,
+    pub expiry_policy: ExpiryPolicy,
+    pub funding_mode: FundingMode,
+    pub goal_reached: bool,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct DonationRefunded {
+    pub message: String,
+    pub donor_address: Pubkey,
+    pub mint: Pubkey,
+    pub refunded_amount: u64,
+    pub case_id: String,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct VerifierEncryptionKeySet {
+    pub message: String,
+    pub verifier_address: Pubkey,
+    pub encryption_key: [u8; 32],
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct RecordsAccessGranted {
+    pub message: String,
+    pub case_id: String,
+    pub verifier_address: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct RecordsAccessRevoked {
+    pub message: String,
+    pub case_id: String,
+    pub verifier_address: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseControlAssumed {
+    pub message: String,
+    pub case_id: String,
+    pub beneficiary: Pubkey,
+    pub previous_manager: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct FacilityRegistered {
+    pub message: String,
+    pub facility: Pubkey,
+    pub facility_key: Pubkey,
+    pub payout_wallet: Pubkey,
+    pub registered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct FacilityUpdated {
+    pub message: String,
+    pub facility: Pubkey,
+    pub payout_wallet: Pubkey,
+    pub status: FacilityStatus,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseFacilityBound {
+    pub message: String,
+    pub case_id: String,
+    pub facility: Pubkey,
+    pub previous_facility: Option<Pubkey>,
+    pub bound_by: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct InvoiceSubmitted {
+    pub message: String,
+    pub case_id: String,
+    pub invoice: Pubkey,
+    pub facility: Pubkey,
+    pub invoice_number_hash: [u8; 32],
+    pub document_hash: [u8; 32],
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub due_date: i64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct InvoicePaid {
+    pub message: String,
+    pub case_id: String,
+    pub invoice: Pubkey,
+    pub proposal_index: u64,
+    pub amount_paid_now: u64,
+    pub total_paid: u64,
+    pub outstanding: u64,
+    pub status: InvoiceStatus,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct TreatmentPlanSet {
+    pub message: String,
+    pub case_id: String,
+    pub milestone_amounts: Vec<u64>,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct MilestoneProofRecorded {
+    pub message: String,
+    pub case_id: String,
+    pub milestone_index: u8,
+    pub proof_type: ProofType,
+    pub proof_hash: [u8; 32],
+    pub attested_by: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct MilestoneReleased {
+    pub message: String,
+    pub case_id: String,
+    pub milestone_index: u8,
+    pub amount_released_now: u64,
+    pub milestone_amount_released: u64,
+    pub milestone_amount: u64,
+    pub plan_total_released: u64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseArchived {
+    pub message: String,
+    pub case_id: String,
+    pub receipt: Pubkey,
+    pub outcome: CaseOutcome,
+    pub total_released: u64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct SubmissionBondPosted {
+    pub message: String,
+    pub case_id: String,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct SubmissionBondSettled {
+    pub message: String,
+    pub case_id: String,
+    pub outcome: BondOutcome,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseProgressUpdated {
+    pub message: String,
+    pub case_id: String,
+    pub page_index: u32,
+    pub update_index: u32,
+    pub update_type: UpdateType,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub posted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseAppealed {
+    pub message: String,
+    pub case_id: String,
+    pub evidence: ManifestRef,
+    pub first_round: RoundResult,
+    pub recusal_applied: bool,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct AppealRoundConcluded {
+    pub message: String,
+    pub case_id: String,
+    pub first_round: RoundResult,
+    pub second_round: RoundResult,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseInfoRequested {
+    pub message: String,
+    pub case_id: String,
+    pub requested_by: Pubkey,
+    pub question_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseInfoProvided {
+    pub message: String,
+    pub case_id: String,
+    pub question_hash: [u8; 32],
+    pub answer_hash: [u8; 32],
+    // How Long Verification Was Paused Waiting On This Answer
+    pub paused_for: i64,
+    pub verification_deadline: i64,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct DuplicateRecordsFlagged {
+    pub message: String,
+    pub case_id: String,
+    // The Earlier, Still Open Case Filed With The Same Records
+    pub duplicate_of: String,
+    pub records_fingerprint: [u8; 32],
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct VerificationAwaitingCoApproval {
+    pub message: String,
+    pub case_id: String,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseCoApproved {
+    pub message: String,
+    pub case_id: String,
+    pub multisig_member: Pubkey,
+    pub co_approvals: u8,
+    // Set Once The Co-Approval Reaching The Threshold Verifies The Case
+    pub is_verified: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerifierWeightUpdated {
+    pub message: String,
+    pub address: Pubkey,
+    pub previous_weight: u16,
+    pub vote_weight: u16,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct VerificationFinalized {
+    pub message: String,
+    pub case_id: String,
+    pub finalized_by: Pubkey,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+    pub approved: bool,
+    // Set When The Case Passed But Its Amount Tier Still Needs Multisig Co-Approval
+    pub awaiting_co_approval: bool,
+    // Set When The Vote Was Split Closely Enough To Go To The Senior Panel Instead Of Being Rejected
+    pub escalated: bool,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct CaseEscalated {
+    pub message: String,
+    pub case_id: String,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+    pub yes_share_bps: u16,
+    pub senior_quorum: u8,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct EscalationResolved {
+    pub message: String,
+    pub case_id: String,
+    pub senior_yes_votes: u8,
+    pub senior_no_votes: u8,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+
+#[event]
+pub struct SeniorPanelUpdated {
+    pub message: String,
+    pub member: Pubkey,
+    pub added: bool,
+    pub panel_size: u8,
+    pub timestamp: i64,
 }
\ No newline at end of file
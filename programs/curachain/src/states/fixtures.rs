@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::*, verification_policy::VerificationStrategy};
+
+
+// An Admin With Three Members, Two Of Whom Must Approve
+pub fn multisig() -> Multisig {
+    Multisig {
+        multisig_admin: Pubkey::new_unique(),
+        multisig_members: (0..3).map(|_| Pubkey::new_unique()).collect(),
+        required_threshold: 2,
+        multisig_bump: 255,
+    }
+}
+
+
+// A Self-Submitted Case, Fresh Off Submission: Unverified, With No Votes, Donations Or Facility
+pub fn patient_case() -> PatientCase {
+    let patient = Pubkey::new_unique();
+
+    PatientCase {
+        patient_pubkey: patient,
+        urgency: UrgencyLevel::Elective,
+        manifest: ManifestRef { version: 1, hash: [1u8; 32], uri: "ipfs://case".to_string() },
+        total_amount_needed: 20_000,
+        total_sol_raised: 0,
+        spl_donations: vec![],
+        case_id: 1,
+        verification_yes_votes: 0,
+        voted_verifiers: vec![],
+        voter_weights: vec![],
+        verification_no_votes: 0,
+        is_verified: false,
+        patient_case_bump: 255,
+        case_funded: false,
+        submission_time: 1_000,
+        amendment_count: 0,
+        case_status: CaseStatus::PendingVerification,
+        funding_deadline: 1_000 + 30 * 24 * 60 * 60,
+        expiry_policy: ExpiryPolicy::RefundDonors,
+        total_sol_contributed: 0,
+        funding_mode: FundingMode::KeepWhatYouRaise,
+        goal_reached: false,
+        submitter: patient,
+        sponsor: None,
+        case_manager: patient,
+        facility: None,
+        treatment_plan: None,
+        total_released: 0,
+        releases: vec![],
+        submission_bond: None,
+        update_count: 0,
+        verification_round: 1,
+        rejected_at: None,
+        appeal: None,
+        recused_verifiers: vec![],
+        info_request: None,
+        paused_duration: 0,
+        info_requests_this_round: 0,
+        records_fingerprint: [2u8; 32],
+        duplicate_of: None,
+        verification_window: 10 * 24 * 60 * 60,
+        quorum_bps: 5000,
+        verification_tier: AmountTier {
+            min_amount: 0,
+            participation_bps: 5000,
+            approval_bps: 7000,
+            min_yes_votes: 0,
+            requires_multisig: false,
+        },
+        awaiting_co_approval: false,
+        co_approvals: vec![],
+        verification_strategy: VerificationStrategy::Supermajority,
+        yes_weight: 0,
+        no_weight: 0,
+        allow_early_approval: true,
+        escalation_policy: EscalationPolicy { grey_zone_min_bps: 0, senior_quorum: 0, decision_window: 0 },
+        escalation: None,
+    }
+}
+
+
+// Records A Verifier's Vote On The Case As verify_patient_case Would
+pub fn cast_vote(patient_case: &mut PatientCase, is_yes: bool) -> Pubkey {
+    let verifier = Pubkey::new_unique();
+    patient_case.voted_verifiers.push(verifier);
+    patient_case.voter_weights.push(1);
+
+    if is_yes {
+        patient_case.verification_yes_votes += 1;
+        patient_case.yes_weight += 1;
+    } else {
+        patient_case.verification_no_votes += 1;
+        patient_case.no_weight += 1;
+    }
+    verifier
+}
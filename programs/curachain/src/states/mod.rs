@@ -3,10 +3,15 @@ pub mod constants;
 pub mod contexts;
 pub mod errors;
 pub mod events;
+pub mod views;
+
+#[cfg(test)]
+pub mod fixtures;
 
 
 pub use accounts::*;
 pub use constants::*;
 pub use errors::*;
 pub use contexts::*;
-pub use events::*;
\ No newline at end of file
+pub use events::*;
+pub use views::*;
\ No newline at end of file
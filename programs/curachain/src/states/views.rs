@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{AmendmentPolicy, CaseStatus, Escalation, ExpiryPolicy, FundingMode, ManifestRef, SplDonations, UrgencyLevel};
+
+
+// RETURN TYPES OF THE READ-ONLY get_* INSTRUCTIONS
+// Each Is Borsh-Encoded Into The Transaction's Return Data; Clients Decode It With The Matching Struct Below
+
+
+// Everything Clients Need To Display A Case, Including Votes And SPL Donations
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct CaseSummary {
+    pub case_id: u64,
+
+    pub beneficiary: Pubkey,
+
+    pub submitter: Pubkey,
+
+    pub sponsor: Option<Pubkey>,
+
+    pub case_manager: Pubkey,
+
+    pub urgency: UrgencyLevel,
+
+    pub manifest: ManifestRef,
+
+    pub case_status: CaseStatus,
+
+    pub is_verified: bool,
+
+    pub verification_yes_votes: u8,
+
+    pub verification_no_votes: u8,
+
+    pub funding_mode: FundingMode,
+
+    pub funding_deadline: i64,
+
+    pub total_amount_needed: u64,
+
+    pub total_sol_raised: u64,
+
+    pub spl_donations: Vec<SplDonations>,
+
+    pub goal_reached: bool,
+
+    pub case_funded: bool,
+
+    pub total_released: u64,
+
+    pub facility: Option<Pubkey>,
+
+    pub treatment_plan: Option<Pubkey>,
+
+    pub submission_time: i64,
+
+    // Progress Updates Posted So Far, Spread Over CaseUpdate Pages
+    pub update_count: u32,
+
+    // Earlier Open Case Filed With The Same Records, If Any
+    pub duplicate_of: Option<u64>,
+
+    // Senior Panel Review Of A Split Vote, If The Case Had One
+    pub escalation: Option<Escalation>,
+}
+
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct DonorSummary {
+    pub donor_address: Pubkey,
+
+    pub total_donations: u64,
+
+    pub donated_cases: Vec<u64>,
+}
+
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalStatus {
+    pub case_id: u64,
+
+    pub proposal_index: u64,
+
+    pub invoice: Pubkey,
+
+    pub approvals: u8,
+
+    pub rejections: u8,
+
+    // Approvals Needed Before The Proposal Can Be Executed
+    pub required_approvals: u8,
+
+    pub approved: bool,
+
+    pub executed: bool,
+}
+
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolStats {
+    // Case IDs Are Assigned Sequentially, So This Is Also The Latest Case ID
+    pub total_cases_submitted: u64,
+
+    pub total_verifiers: u32,
+
+    pub multisig_members: u8,
+
+    pub amendment_policy: AmendmentPolicy,
+
+    pub expiry_policy: ExpiryPolicy,
+
+    pub max_campaign_duration: i64,
+
+    pub submission_bond_amount: u64,
+
+    pub submission_bond_mint: Pubkey,
+}
@@ -0,0 +1,594 @@
+use anchor_lang::prelude::*;
+
+
+/*
+VERIFICATION POLICY
+The Single Place That Decides Where A Case's Verification Vote Stands, Shared By Voting And Closing.
+1. A Tally Is Taken From The Case: Votes And Vote Weight Cast, And How Many Verifiers (And How Much Weight) May Vote.
+2. Participation Comes First: Until Enough Of The Eligible Panel Has Voted, The Vote Is Pending.
+3. The Configured Strategy Then Judges The Votes Cast, And The Minimum Yes Count Is Applied On Top Of Every Strategy.
+4. Once The Verification Window Closes Nobody Else Can Vote, So A Vote Still Pending Is Final As A Rejection.
+5. A Rejected Vote Whose Yes Share Still Reaches The Grey-Zone Floor Is A Split Vote, Escalated Rather Than Rejected.
+Everything Here Is Pure Arithmetic On Plain Values, So It Is Unit Tested Without A Validator.
+*/
+
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+
+// How The Votes Cast On A Case Are Judged Once Enough Verifiers Have Voted
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum VerificationStrategy {
+    // More Yes Votes Than No Votes
+    SimpleMajority,
+
+    // Yes Votes Reach approval_bps Of The Votes Cast
+    Supermajority,
+
+    // At Least min_yes_votes Yes Votes, And At Least One, However The Rest Voted
+    MinimumYesCount,
+
+    // Like Supermajority, But Each Verifier Counts With Its Vote Weight, Participation Included
+    Weighted,
+}
+
+// Where A Verification Vote Stands
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TallyOutcome {
+    Pending,
+
+    Approved,
+
+    Rejected,
+}
+
+// The Votes On A Case, And The Panel They Were Cast By
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Tally {
+    pub yes_votes: u32,
+
+    pub no_votes: u32,
+
+    pub yes_weight: u64,
+
+    pub no_weight: u64,
+
+    // Verifiers Who May Vote In The Current Round
+    pub eligible_voters: u32,
+
+    pub eligible_weight: u64,
+}
+
+impl Tally {
+    pub fn votes_cast(&self) -> u32 {
+        self.yes_votes.saturating_add(self.no_votes)
+    }
+
+    pub fn weight_cast(&self) -> u64 {
+        self.yes_weight.saturating_add(self.no_weight)
+    }
+
+    // Every Eligible Verifier Has Voted, So No Later Vote Can Change The Outcome
+    pub fn is_complete(&self) -> bool {
+        self.votes_cast() >= self.eligible_voters
+    }
+}
+
+// The Thresholds A Case Is Held To, From Its Urgency Level And Amount Tier
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PolicyParams {
+    // Share Of The Eligible Panel That Must Vote, In Basis Points
+    pub participation_bps: u16,
+
+    // Share Of Votes Cast That Must Be Yes, In Basis Points; Used By Supermajority And Weighted
+    pub approval_bps: u16,
+
+    pub min_yes_votes: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VerificationPolicy {
+    pub strategy: VerificationStrategy,
+
+    pub params: PolicyParams,
+}
+
+impl VerificationPolicy {
+    pub fn new(strategy: VerificationStrategy, params: PolicyParams) -> Self {
+        Self { strategy, params }
+    }
+
+    pub fn evaluate(&self, tally: &Tally) -> TallyOutcome {
+        if !self.participation_reached(tally) {
+            return TallyOutcome::Pending;
+        }
+
+        if !self.votes_cast_approve(tally) {
+            return TallyOutcome::Rejected;
+        }
+
+        // Enough Yes Votes Settles It; Otherwise The Case Waits Only While The Verifiers Yet To Vote Could Still Get There
+        let min_yes_votes = self.min_yes_votes() as u32;
+        let votes_outstanding = tally.eligible_voters.saturating_sub(tally.votes_cast());
+        if tally.yes_votes >= min_yes_votes {
+            TallyOutcome::Approved
+        } else if tally.yes_votes.saturating_add(votes_outstanding) < min_yes_votes {
+            TallyOutcome::Rejected
+        } else {
+            TallyOutcome::Pending
+        }
+    }
+
+    // The Verdict Once The Window Has Closed: Falling Short Of Quorum Or Of The Minimum Yes Count Rejects The Case
+    pub fn finalize(&self, tally: &Tally) -> TallyOutcome {
+        match self.evaluate(tally) {
+            TallyOutcome::Pending => TallyOutcome::Rejected,
+            outcome => outcome,
+        }
+    }
+
+    // Share Of The Votes Cast That Were Yes, In Basis Points Rounded Down; By Weight Under Weighted
+    pub fn yes_share_bps(&self, tally: &Tally) -> u16 {
+        let (yes, cast) = self.yes_and_cast(tally);
+        if cast == 0 {
+            return 0;
+        }
+        (yes * BPS_DENOMINATOR / cast) as u16
+    }
+
+    // A Split Vote: Enough Verifiers Voted And The Yes Share Reached The Floor, Yet The Vote Fell Short; A Floor Of 0 Disables It
+    pub fn in_grey_zone(&self, tally: &Tally, grey_zone_min_bps: u16) -> bool {
+        let (yes, cast) = self.yes_and_cast(tally);
+
+        grey_zone_min_bps > 0
+            && self.participation_reached(tally)
+            && meets_share(yes, cast, grey_zone_min_bps)
+            && self.evaluate(tally) != TallyOutcome::Approved
+    }
+
+    fn yes_and_cast(&self, tally: &Tally) -> (u128, u128) {
+        match self.strategy {
+            VerificationStrategy::Weighted => (tally.yes_weight as u128, tally.weight_cast() as u128),
+            _ => (tally.yes_votes as u128, tally.votes_cast() as u128),
+        }
+    }
+
+    // Measured In Vote Weight Under Weighted, In Verifiers Otherwise; An Empty Panel Never Reaches It.
+    // Turnout Must Strictly Exceed The Share, As The Original Quorum Did, Unless The Whole Panel Has Voted
+    pub fn participation_reached(&self, tally: &Tally) -> bool {
+        let (cast, eligible) = match self.strategy {
+            VerificationStrategy::Weighted => (tally.weight_cast() as u128, tally.eligible_weight as u128),
+            _ => (tally.votes_cast() as u128, tally.eligible_voters as u128),
+        };
+
+        eligible > 0 && cast > 0 && (cast >= eligible || exceeds_share(cast, eligible, self.params.participation_bps))
+    }
+
+    fn votes_cast_approve(&self, tally: &Tally) -> bool {
+        match self.strategy {
+            VerificationStrategy::SimpleMajority => tally.yes_votes > tally.no_votes,
+            VerificationStrategy::Supermajority => {
+                meets_share(tally.yes_votes as u128, tally.votes_cast() as u128, self.params.approval_bps)
+            },
+            VerificationStrategy::MinimumYesCount => true,
+            VerificationStrategy::Weighted => {
+                meets_share(tally.yes_weight as u128, tally.weight_cast() as u128, self.params.approval_bps)
+            },
+        }
+    }
+
+    // A Minimum Yes Count Of Zero Would Let MinimumYesCount Approve A Case Nobody Backed
+    fn min_yes_votes(&self) -> u8 {
+        match self.strategy {
+            VerificationStrategy::MinimumYesCount => std::cmp::max(self.params.min_yes_votes, 1),
+            _ => self.params.min_yes_votes,
+        }
+    }
+}
+
+// part / whole >= bps / 10_000, Compared Without Division So Nothing Is Lost To Rounding
+fn meets_share(part: u128, whole: u128, bps: u16) -> bool {
+    part * BPS_DENOMINATOR >= whole * bps as u128
+}
+
+// part / whole > bps / 10_000
+fn exceeds_share(part: u128, whole: u128, bps: u16) -> bool {
+    part * BPS_DENOMINATOR > whole * bps as u128
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_STRATEGIES: [VerificationStrategy; 4] = [
+        VerificationStrategy::SimpleMajority,
+        VerificationStrategy::Supermajority,
+        VerificationStrategy::MinimumYesCount,
+        VerificationStrategy::Weighted,
+    ];
+
+    fn params(participation_bps: u16, approval_bps: u16, min_yes_votes: u8) -> PolicyParams {
+        PolicyParams { participation_bps, approval_bps, min_yes_votes }
+    }
+
+    // Every Verifier Carries A Weight Of One, So Weighted Behaves Like Supermajority
+    fn tally(yes_votes: u32, no_votes: u32, eligible_voters: u32) -> Tally {
+        Tally {
+            yes_votes,
+            no_votes,
+            yes_weight: yes_votes as u64,
+            no_weight: no_votes as u64,
+            eligible_voters,
+            eligible_weight: eligible_voters as u64,
+        }
+    }
+
+    fn weighted_tally(yes_weight: u64, no_weight: u64, eligible_weight: u64, yes_votes: u32, no_votes: u32, eligible_voters: u32) -> Tally {
+        Tally { yes_votes, no_votes, yes_weight, no_weight, eligible_voters, eligible_weight }
+    }
+
+    fn evaluate(strategy: VerificationStrategy, params: PolicyParams, tally: Tally) -> TallyOutcome {
+        VerificationPolicy::new(strategy, params).evaluate(&tally)
+    }
+
+    // Straightforward Restatement Of The Rules, Written With Division, To Check evaluate Against
+    fn reference(strategy: VerificationStrategy, params: PolicyParams, tally: Tally) -> TallyOutcome {
+        let (cast, eligible) = match strategy {
+            VerificationStrategy::Weighted => (tally.weight_cast() as f64, tally.eligible_weight as f64),
+            _ => (tally.votes_cast() as f64, tally.eligible_voters as f64),
+        };
+        if eligible == 0.0 || cast == 0.0 || (cast < eligible && cast / eligible <= params.participation_bps as f64 / 10_000.0) {
+            return TallyOutcome::Pending;
+        }
+
+        let approval = params.approval_bps as f64 / 10_000.0;
+        let approves = match strategy {
+            VerificationStrategy::SimpleMajority => tally.yes_votes > tally.no_votes,
+            VerificationStrategy::Supermajority => tally.yes_votes as f64 / cast >= approval,
+            VerificationStrategy::MinimumYesCount => true,
+            VerificationStrategy::Weighted => tally.yes_weight as f64 / cast >= approval,
+        };
+        if !approves {
+            return TallyOutcome::Rejected;
+        }
+
+        let min_yes = match strategy {
+            VerificationStrategy::MinimumYesCount => params.min_yes_votes.max(1) as u32,
+            _ => params.min_yes_votes as u32,
+        };
+        let outstanding = tally.eligible_voters - tally.votes_cast();
+        if tally.yes_votes >= min_yes {
+            TallyOutcome::Approved
+        } else if tally.yes_votes + outstanding < min_yes {
+            TallyOutcome::Rejected
+        } else {
+            TallyOutcome::Pending
+        }
+    }
+
+    #[test]
+    fn matches_reference_for_every_small_tally() {
+        let policies = [
+            params(5000, 7000, 0),
+            params(5000, 5000, 0),
+            params(6000, 8000, 3),
+            params(3000, 5000, 1),
+            params(10_000, 10_000, 2),
+            params(1, 1, 0),
+        ];
+
+        for strategy in ALL_STRATEGIES {
+            for policy in policies {
+                for eligible in 0..=12u32 {
+                    for yes in 0..=eligible {
+                        for no in 0..=(eligible - yes) {
+                            let tally = tally(yes, no, eligible);
+                            assert_eq!(
+                                evaluate(strategy, policy, tally),
+                                reference(strategy, policy, tally),
+                                "{:?} {:?} {:?}", strategy, policy, tally
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_reference_for_every_small_weighted_tally() {
+        let policies = [params(5000, 7000, 0), params(6000, 8000, 2), params(2500, 5000, 1)];
+        let weights = [1u64, 2, 3, 5];
+
+        for policy in policies {
+            for eligible in 1..=4usize {
+                for yes in 0..=eligible {
+                    for no in 0..=(eligible - yes) {
+                        // Give The Yes Voters The Heaviest Weights First, Then The Lightest
+                        for yes_heavy in [true, false] {
+                            let mut panel = weights[..eligible].to_vec();
+                            if !yes_heavy {
+                                panel.reverse();
+                            }
+                            let yes_weight: u64 = panel[..yes].iter().sum();
+                            let no_weight: u64 = panel[yes..yes + no].iter().sum();
+                            let eligible_weight: u64 = panel.iter().sum();
+
+                            let tally = weighted_tally(yes_weight, no_weight, eligible_weight, yes as u32, no as u32, eligible as u32);
+                            assert_eq!(
+                                evaluate(VerificationStrategy::Weighted, policy, tally),
+                                reference(VerificationStrategy::Weighted, policy, tally),
+                                "{:?} {:?}", policy, tally
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn finalize_settles_every_tally() {
+        let policies = [params(5000, 7000, 0), params(6000, 8000, 3), params(1, 1, 1)];
+
+        for strategy in ALL_STRATEGIES {
+            for policy in policies {
+                for eligible in 0..=10u32 {
+                    for yes in 0..=eligible {
+                        for no in 0..=(eligible - yes) {
+                            let tally = tally(yes, no, eligible);
+                            let expected = match evaluate(strategy, policy, tally) {
+                                TallyOutcome::Pending => TallyOutcome::Rejected,
+                                outcome => outcome,
+                            };
+                            assert_eq!(VerificationPolicy::new(strategy, policy).finalize(&tally), expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn finalize_rejects_short_of_quorum_and_min_yes() {
+        let policy = VerificationPolicy::new(VerificationStrategy::Supermajority, params(5000, 7000, 3));
+
+        // Nobody Voted
+        assert_eq!(policy.finalize(&tally(0, 0, 10)), TallyOutcome::Rejected);
+
+        // Unanimous, But Only Four Of Ten Voted
+        assert_eq!(policy.finalize(&tally(4, 0, 10)), TallyOutcome::Rejected);
+
+        // Quorum And Share Reached, Yet Two Yes Votes Are Below The Minimum Of Three
+        assert_eq!(policy.finalize(&tally(2, 0, 4)), TallyOutcome::Rejected);
+
+        assert_eq!(policy.finalize(&tally(5, 1, 10)), TallyOutcome::Approved);
+        assert_eq!(policy.finalize(&tally(3, 3, 10)), TallyOutcome::Rejected);
+
+        // An Empty Panel Never Approves
+        assert_eq!(policy.finalize(&Tally::default()), TallyOutcome::Rejected);
+    }
+
+    #[test]
+    fn grey_zone_catches_split_votes_only() {
+        let policy = VerificationPolicy::new(VerificationStrategy::Supermajority, params(5000, 7000, 0));
+
+        // 60% Yes Falls Between The 50% Floor And The 70% Approval Share
+        assert!(policy.in_grey_zone(&tally(6, 4, 10), 5000));
+
+        // Exactly The Floor Is Inside, Just Below It Is Not
+        assert!(policy.in_grey_zone(&tally(5, 5, 10), 5000));
+        assert!(!policy.in_grey_zone(&tally(4, 6, 10), 5000));
+
+        // Reaching The Approval Share Is No Longer A Split Vote
+        assert!(!policy.in_grey_zone(&tally(7, 3, 10), 5000));
+
+        // Short Of Quorum Is Not A Split Vote Either
+        assert!(!policy.in_grey_zone(&tally(3, 1, 10), 5000));
+
+        // A Floor Of Zero Turns Escalation Off
+        assert!(!policy.in_grey_zone(&tally(6, 4, 10), 0));
+    }
+
+    #[test]
+    fn grey_zone_matches_yes_share_for_every_small_rejection() {
+        for strategy in ALL_STRATEGIES {
+            let policy = VerificationPolicy::new(strategy, params(5000, 7000, 0));
+            for eligible in 1..=12u32 {
+                for yes in 0..=eligible {
+                    for no in 0..=(eligible - yes) {
+                        let tally = tally(yes, no, eligible);
+                        if policy.evaluate(&tally) != TallyOutcome::Rejected {
+                            continue;
+                        }
+
+                        let in_band = yes * 10_000 >= (yes + no) * 5000;
+                        assert_eq!(policy.in_grey_zone(&tally, 5000), in_band, "{:?} {:?}", strategy, tally);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grey_zone_uses_weight_under_weighted() {
+        let policy = VerificationPolicy::new(VerificationStrategy::Weighted, params(5000, 7000, 0));
+
+        // Two Yes Votes Out Of Five Is Only 40% By Count, But Carry 60% Of The Weight Cast
+        let heavy_yes = weighted_tally(6, 4, 10, 2, 3, 5);
+        assert_eq!(policy.evaluate(&heavy_yes), TallyOutcome::Rejected);
+        assert_eq!(policy.yes_share_bps(&heavy_yes), 6000);
+        assert!(policy.in_grey_zone(&heavy_yes, 5000));
+    }
+
+    #[test]
+    fn yes_share_rounds_down() {
+        let policy = VerificationPolicy::new(VerificationStrategy::Supermajority, params(5000, 7000, 0));
+
+        assert_eq!(policy.yes_share_bps(&tally(2, 1, 3)), 6666);
+        assert_eq!(policy.yes_share_bps(&tally(0, 0, 3)), 0);
+        assert_eq!(policy.yes_share_bps(&tally(3, 0, 3)), 10_000);
+    }
+
+    #[test]
+    fn empty_panel_is_always_pending() {
+        for strategy in ALL_STRATEGIES {
+            assert_eq!(evaluate(strategy, params(1, 1, 0), Tally::default()), TallyOutcome::Pending);
+        }
+    }
+
+    #[test]
+    fn no_votes_cast_is_pending_even_with_zero_participation_share() {
+        for strategy in ALL_STRATEGIES {
+            assert_eq!(evaluate(strategy, params(0, 5000, 0), tally(0, 0, 10)), TallyOutcome::Pending);
+        }
+    }
+
+    #[test]
+    fn participation_boundary_is_strict() {
+        let policy = params(5000, 7000, 0);
+
+        // Exactly Half Of Ten Verifiers Is Not Enough; Six Are
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(5, 0, 10)), TallyOutcome::Pending);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(6, 0, 10)), TallyOutcome::Approved);
+
+        // Half Of Three Verifiers Is 1.5, So Two Votes Are Needed
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(1, 0, 3)), TallyOutcome::Pending);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(2, 0, 3)), TallyOutcome::Approved);
+    }
+
+    #[test]
+    fn supermajority_boundary_is_inclusive() {
+        let policy = params(5000, 7000, 0);
+
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(7, 3, 10)), TallyOutcome::Approved);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(6, 4, 10)), TallyOutcome::Rejected);
+
+        // Two Of Three Is Below 70%
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(2, 1, 3)), TallyOutcome::Rejected);
+    }
+
+    #[test]
+    fn simple_majority_needs_strictly_more_yes() {
+        let policy = params(5000, 7000, 0);
+
+        assert_eq!(evaluate(VerificationStrategy::SimpleMajority, policy, tally(3, 2, 5)), TallyOutcome::Approved);
+        assert_eq!(evaluate(VerificationStrategy::SimpleMajority, policy, tally(2, 2, 5)), TallyOutcome::Rejected);
+        assert_eq!(evaluate(VerificationStrategy::SimpleMajority, policy, tally(1, 2, 5)), TallyOutcome::Rejected);
+
+        // approval_bps Plays No Part
+        assert_eq!(evaluate(VerificationStrategy::SimpleMajority, params(5000, 10_000, 0), tally(3, 2, 5)), TallyOutcome::Approved);
+    }
+
+    #[test]
+    fn minimum_yes_count_ignores_no_votes() {
+        let policy = params(5000, 7000, 2);
+
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(2, 5, 10)), TallyOutcome::Approved);
+
+        // One Yes With Three Verifiers Still To Vote Can Still Reach Two
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(1, 6, 10)), TallyOutcome::Pending);
+
+        // One Yes And Everybody Voted Can Not
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(1, 9, 10)), TallyOutcome::Rejected);
+    }
+
+    #[test]
+    fn minimum_yes_count_needs_at_least_one_yes() {
+        let policy = params(5000, 7000, 0);
+
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(0, 5, 10)), TallyOutcome::Pending);
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(0, 10, 10)), TallyOutcome::Rejected);
+        assert_eq!(evaluate(VerificationStrategy::MinimumYesCount, policy, tally(1, 9, 10)), TallyOutcome::Approved);
+    }
+
+    #[test]
+    fn min_yes_floor_applies_to_every_strategy() {
+        let policy = params(5000, 7000, 3);
+
+        for strategy in ALL_STRATEGIES {
+            // Two Unanimous Yes Votes Pass Every Share Test, But Fall Short Of Three While Others Can Still Vote
+            assert_eq!(evaluate(strategy, policy, tally(2, 0, 4)), TallyOutcome::Pending, "{:?}", strategy);
+
+            // With Nobody Left To Vote The Floor Is Out Of Reach
+            assert_eq!(evaluate(strategy, policy, tally(2, 0, 2)), TallyOutcome::Rejected, "{:?}", strategy);
+
+            assert_eq!(evaluate(strategy, policy, tally(3, 0, 4)), TallyOutcome::Approved, "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn failing_share_rejects_before_min_yes_is_considered() {
+        let policy = params(5000, 7000, 3);
+
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(1, 3, 6)), TallyOutcome::Rejected);
+        assert_eq!(evaluate(VerificationStrategy::SimpleMajority, policy, tally(1, 3, 6)), TallyOutcome::Rejected);
+    }
+
+    #[test]
+    fn weighted_measures_share_and_participation_by_weight() {
+        let policy = params(5000, 7000, 0);
+
+        // One Heavy Verifier Of Weight 6 Out Of 10 Carries Participation And Approval Alone
+        let heavy_yes = weighted_tally(6, 0, 10, 1, 0, 5);
+        assert_eq!(evaluate(VerificationStrategy::Weighted, policy, heavy_yes), TallyOutcome::Approved);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, heavy_yes), TallyOutcome::Pending);
+
+        // Three Light Yes Votes Against One Heavy No Vote Lose By Weight, Yet Win By Count
+        let heavy_no = weighted_tally(3, 6, 10, 3, 1, 4);
+        assert_eq!(evaluate(VerificationStrategy::Weighted, policy, heavy_no), TallyOutcome::Rejected);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, heavy_no), TallyOutcome::Approved);
+
+        // Weight Just Under Half Of The Panel Is Not Enough Participation
+        let light = weighted_tally(4, 0, 9, 2, 0, 3);
+        assert_eq!(evaluate(VerificationStrategy::Weighted, policy, light), TallyOutcome::Pending);
+    }
+
+    #[test]
+    fn default_tier_keeps_the_original_rule() {
+        // Half The Verifiers Vote, 70% Of Them Yes
+        let policy = params(5000, 7000, 0);
+
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(1, 0, 2)), TallyOutcome::Pending);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(0, 1, 2)), TallyOutcome::Pending);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(2, 0, 2)), TallyOutcome::Approved);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(1, 1, 2)), TallyOutcome::Rejected);
+        assert_eq!(evaluate(VerificationStrategy::Supermajority, policy, tally(1, 0, 3)), TallyOutcome::Pending);
+    }
+
+    #[test]
+    fn extreme_values_do_not_overflow() {
+        let policy = params(10_000, 10_000, u8::MAX);
+        let tally = weighted_tally(u64::MAX, 0, u64::MAX, u32::MAX, 0, u32::MAX);
+
+        for strategy in ALL_STRATEGIES {
+            assert_eq!(evaluate(strategy, policy, tally), TallyOutcome::Approved, "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn tally_is_complete_once_every_eligible_verifier_has_voted() {
+        assert!(!tally(3, 2, 6).is_complete());
+        assert!(tally(3, 3, 6).is_complete());
+
+        // Completeness Counts Verifiers, Not Weight
+        assert!(weighted_tally(9, 1, 20, 2, 1, 3).is_complete());
+    }
+
+    #[test]
+    fn more_yes_votes_never_turn_an_approval_into_a_rejection() {
+        let policy = params(5000, 7000, 2);
+
+        for strategy in ALL_STRATEGIES {
+            for eligible in 1..=10u32 {
+                for yes in 0..eligible {
+                    for no in 0..(eligible - yes) {
+                        if evaluate(strategy, policy, tally(yes, no, eligible)) == TallyOutcome::Approved {
+                            assert_eq!(evaluate(strategy, policy, tally(yes + 1, no, eligible)), TallyOutcome::Approved);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
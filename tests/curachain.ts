@@ -12,10 +12,14 @@ import {
 } from "@solana/web3.js";
 import chai, { assert, expect } from "chai";
 import chaiAsPromised from "chai-as-promised";
+import { createHash } from "crypto";
 import { createMint, getOrCreateAssociatedTokenAccount, getAssociatedTokenAddress, mintTo, getAccount, TOKEN_PROGRAM_ID, getMinimumBalanceForRentExemptAccount, ACCOUNT_SIZE, createInitializeAccountInstruction, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 chai.use(chaiAsPromised);
 
+// Cases Are Keyed By Their Number, Little-Endian, In Every PDA Seed
+const caseIdSeed = (caseNumber: number) => new BN(caseNumber).toArrayLike(Buffer, "le", 8);
+
 describe("CuraChain", () => {
 
   //Testing
@@ -46,6 +50,9 @@ describe("CuraChain", () => {
   const patient2Keypair = anchor.web3.Keypair.generate();
   const patient3Keypair = anchor.web3.Keypair.generate(); 
   const facility_address = anchor.web3.Keypair.generate();
+  const bondForfeitPool = anchor.web3.Keypair.generate();
+  // NGO Covering Patient 2's Rent And Bond, So Patient 2 Only Signs
+  const ngoSponsorKeypair = anchor.web3.Keypair.generate();
 
   //Airdrop function below
 
@@ -58,6 +65,45 @@ describe("CuraChain", () => {
     await provider.connection.confirmTransaction(airdropSig);
   }
 
+  // Each Case's Submission Bond Is Held In A Vault PDA Keyed By The Case Number
+  function bondVault(caseNumber: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("bond_vault"), caseIdSeed(caseNumber)],
+      program.programId
+    )[0];
+  }
+
+  // Fingerprint Of A Case's Records Bundle And The PDA Registering It; Tests Pick A Byte Per Bundle
+  function recordsFingerprint(seed: number) {
+    return Array.from({ length: 32 }, () => seed);
+  }
+
+  function recordsFingerprintPDA(seed: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("records_fingerprint"), Buffer.from(recordsFingerprint(seed))],
+      program.programId
+    )[0];
+  }
+
+  // Stand-In Body Hash Of A Case's Off-Chain Manifest; Tests Pick A Byte Per Manifest
+  function manifestBodyHash(seed: number) {
+    return Array.from({ length: 32 }, () => seed);
+  }
+
+  // Pointer To A Case's Off-Chain Manifest, Whose Hash Commits To Its Body Hash And Records Fingerprint
+  function caseManifest(seed: number, uri: string, recordsSeed: number = seed) {
+    const hash = createHash("sha256")
+      .update(Buffer.from("curachain:case-manifest-commitment"))
+      .update(Buffer.from(manifestBodyHash(seed)))
+      .update(Buffer.from(recordsFingerprint(recordsSeed)))
+      .digest();
+    return {
+      version: 1,
+      hash: Array.from(hash),
+      uri,
+    };
+  }
+
   //Setting up airdrop
 
   async function setupActors(provider, users, amount) {
@@ -100,6 +146,7 @@ describe("CuraChain", () => {
         patient1Keypair.publicKey,
         patient2Keypair.publicKey,
         patient3Keypair.publicKey,
+        ngoSponsorKeypair.publicKey,
       ],
       5
     );
@@ -209,6 +256,55 @@ describe("CuraChain", () => {
     );
     expect(caseCounterData.currentId.toNumber()).to.equal(0);
     expect(caseCounterData.counterBump).to.equal(caseCounterBump);
+
+    // Admin Sets Up The Protocol Config Needed For Case Submissions
+    const [protocolConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
+      program.programId
+    );
+
+    await program.methods
+      .updateProtocolConfig({
+        amendmentPolicy: { resetVotes: {} },
+        maxCampaignDuration: new BN(90 * 24 * 60 * 60),
+        expiryPolicy: { refundDonors: {} },
+        rejectedCloseBountyBps: 100,
+        submissionBondAmount: new BN(0.01 * LAMPORTS_PER_SOL),
+        submissionBondMint: PublicKey.default,
+        bondForfeitPool: bondForfeitPool.publicKey,
+        appealWindow: new BN(0),
+        // Elective, Urgent And Emergency Cases
+        urgencyPolicies: [
+          { verificationWindow: new BN(10 * 24 * 60 * 60), quorumBps: 5000 },
+          { verificationWindow: new BN(3 * 24 * 60 * 60), quorumBps: 5000 },
+          { verificationWindow: new BN(24 * 60 * 60), quorumBps: 3000 },
+        ],
+        // Only Large Cases Need A Stricter Vote And Multisig Co-Approval; Smaller Ones Keep The 50% / 70% Rule
+        amountTiers: [
+          { minAmount: new BN(1_000_000), participationBps: 6000, approvalBps: 8000, minYesVotes: 3, requiresMultisig: true },
+        ],
+        // Yes Votes Must Reach The Tier's Approval Share Of The Votes Cast
+        verificationStrategy: { supermajority: {} },
+        // Settle A Case As Soon As Its Vote Passes Or Fails, Rather Than Waiting For finalize_verification
+        allowEarlyApproval: true,
+        // No Grey Zone, So Split Votes Are Rejected Outright As The Close Tests Expect
+        escalationPolicy: { greyZoneMinBps: 0, seniorQuorum: 0, decisionWindow: new BN(0) },
+      })
+      .accounts({
+        admin: newAdmin.publicKey,
+        //@ts-ignore
+        adminAccount: adminPDA,
+        protocolConfig: protocolConfigPDA,
+        seniorPanel: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    const protocolConfigData = await program.account.protocolConfig.fetch(
+      protocolConfigPDA
+    );
+    expect(protocolConfigData.maxCampaignDuration.toNumber()).to.equal(90 * 24 * 60 * 60);
   });
 
 
@@ -358,6 +454,7 @@ describe("CuraChain", () => {
     );
     expect(verifier1Details.isVerifier).to.be.true;
     expect(verifier1Details.verifierBump).to.eq(verifier1Bump);
+    expect(verifier1Details.voteWeight).to.eq(1);
 
     // Asserting Verifier 2 Data Initialized correctly
     const verifier2Details = await program.account.verifier.fetch(verifier2PDA);
@@ -371,6 +468,8 @@ describe("CuraChain", () => {
       verifiersRegistryPDA
     );
     expect(globalVerifiersListData.allVerifiers.length).to.equal(6);
+    // Every New Verifier Votes With A Weight Of 1
+    expect(globalVerifiersListData.totalWeight.toNumber()).to.equal(6);
   });
 
 
@@ -490,33 +589,46 @@ describe("CuraChain", () => {
     );
     // Case LookUp PDAs for Patient 1 and 2 and 3
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0001")],
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
       program.programId
     );
 
     const [caseLookupPDA2, caseLookupBump2] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0002")],
+      [Buffer.from("case_lookup"), caseIdSeed(2)],
       program.programId
     );
 
     const [caseLookupPDA3, caseLookupBump3] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0003")],
+      [Buffer.from("case_lookup"), caseIdSeed(3)],
+      program.programId
+    );
+
+    const [protocolConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
       program.programId
     );
 
     // Let Patient 1 Call The submit Cases Instruction
     await program.methods
       .submitCases(
-        "suffering from Cystic Fibrosis for 2 years now",
+        caseManifest(1, "www.gmail.com/drive/folders/medical_records.pdf"),
+        recordsFingerprint(1),
+        manifestBodyHash(1),
         new BN(20000),
-        "www.gmail.com/drive/folders/medical_records.pdf"
+        new BN(30 * 24 * 60 * 60),
+        { keepWhatYouRaise: {} },
+        { elective: {} }
       )
       .accounts({
         patient: patient1Keypair.publicKey,
+        payer: patient1Keypair.publicKey,
         //@ts-ignore
         patientCase: patient1CasePDA,
         caseCounter: caseCounterPDA,
         caseLookup: caseLookupPDA,
+        protocolConfig: protocolConfigPDA,
+        bondVault: bondVault(1),
+        recordsFingerprint: recordsFingerprintPDA(1),
         systemProgram: SystemProgram.programId,
       })
       .signers([patient1Keypair])
@@ -525,34 +637,50 @@ describe("CuraChain", () => {
     // Let Patient 2 Call The Submit Cases Instruction
     await program.methods
       .submitCases(
-        "suffering from Ehlers-Danlos Syndrome for a year now",
+        caseManifest(2, "www.github.com/squash/medical_records.pdf"),
+        recordsFingerprint(2),
+        manifestBodyHash(2),
         new BN(50000),
-        "www.github.com/squash/medical_records.pdf"
+        new BN(30 * 24 * 60 * 60),
+        { keepWhatYouRaise: {} },
+        { elective: {} }
       )
       .accounts({
         patient: patient2Keypair.publicKey,
+        payer: ngoSponsorKeypair.publicKey,
         //@ts-ignore
         patientCase: patient2CasePDA,
         caseCounter: caseCounterPDA,
         caseLookup: caseLookupPDA2,
+        protocolConfig: protocolConfigPDA,
+        bondVault: bondVault(2),
+        recordsFingerprint: recordsFingerprintPDA(2),
         systemProgram: SystemProgram.programId,
       })
-      .signers([patient2Keypair])
+      .signers([patient2Keypair, ngoSponsorKeypair])
       .rpc();
 
-    // Let Patient 3 Call The Submit Cases Instruction
+    // Let Patient 3 Call The Submit Cases Instruction, Reusing Patient 1's Records While Case 1 Is Still Open
     await program.methods
       .submitCases(
-        "suffering from Thyroid dysfunction for a year now",
+        caseManifest(3, "www.gmail.com/drive/folders/hospital_treatment_records.pdf", 1),
+        recordsFingerprint(1),
+        manifestBodyHash(3),
         new BN(100000),
-        "www.gmail.com/drive/folders/hospital_treatment_records.pdf"
+        new BN(30 * 24 * 60 * 60),
+        { keepWhatYouRaise: {} },
+        { urgent: {} }
       )
       .accounts({
         patient: patient3Keypair.publicKey,
+        payer: patient3Keypair.publicKey,
         // @ts-ignore
         patientCase: patient3CasePDA,
         caseCounter: caseCounterPDA,
         caseLookup: caseLookupPDA3,
+        protocolConfig: protocolConfigPDA,
+        bondVault: bondVault(3),
+        recordsFingerprint: recordsFingerprintPDA(1),
         systemProgram: SystemProgram.programId,
       })
       .signers([patient3Keypair])
@@ -570,9 +698,9 @@ describe("CuraChain", () => {
     );
 
     // Let's Make The Assertions For Patient 1 Here
-    expect(patient1CaseData.caseId.toString()).to.eq("CASE0001");
-    expect(patient1CaseData.caseDescription.toString()).contains(
-      "Cystic Fibrosis"
+    expect(patient1CaseData.caseId.toNumber()).to.eq(1);
+    expect(patient1CaseData.manifest.uri).to.eq(
+      "www.gmail.com/drive/folders/medical_records.pdf"
     );
     expect(patient1CaseData.verificationYesVotes).to.eq(0);
     expect(patient1CaseData.verificationNoVotes).to.eq(0);
@@ -581,26 +709,43 @@ describe("CuraChain", () => {
     expect(patient1CaseData.totalSolRaised.toNumber()).to.eq(0);
 
     // Let's Make Assertions For Patient 2 Here
-    expect(patient2CaseData.caseId.toString()).to.eq("CASE0002");
-    expect(patient2CaseData.caseDescription.toString()).contains(
-      "Ehlers-Danlos Syndrome"
+    expect(patient2CaseData.caseId.toNumber()).to.eq(2);
+    expect(patient2CaseData.manifest.uri).to.eq(
+      "www.github.com/squash/medical_records.pdf"
     );
     expect(patient2CaseData.verificationYesVotes).to.eq(0);
     expect(patient2CaseData.verificationNoVotes).to.eq(0);
     expect(patient2CaseData.isVerified).to.be.false;
     expect(patient2CaseData.totalAmountNeeded.toNumber()).to.eq(50000);
     expect(patient2CaseData.totalSolRaised.toNumber()).to.eq(0);
+    expect(patient2CaseData.submitter.toBase58()).to.eq(patient2Keypair.publicKey.toBase58());
+    expect(patient2CaseData.sponsor.toBase58()).to.eq(ngoSponsorKeypair.publicKey.toBase58());
 
     // Let's Make Assertions For Patient 3 Here
-    expect(patient3CaseData.caseId.toString()).to.eq("CASE0003");
-    expect(patient3CaseData.caseDescription.toString()).contains(
-      "Thyroid dysfunction"
+    expect(patient3CaseData.caseId.toNumber()).to.eq(3);
+    expect(patient3CaseData.manifest.uri).to.eq(
+      "www.gmail.com/drive/folders/hospital_treatment_records.pdf"
     );
     expect(patient3CaseData.verificationYesVotes).to.eq(0);
     expect(patient3CaseData.verificationNoVotes).to.eq(0);
     expect(patient3CaseData.isVerified).to.be.false;
     expect(patient3CaseData.totalAmountNeeded.toNumber()).to.eq(100000);
     expect(patient3CaseData.totalSolRaised.toNumber()).to.eq(0);
+
+    // All Three Cases Ask For Less Than The Large-Case Tier, So They Keep The Default Rule
+    expect(patient1CaseData.verificationTier.approvalBps).to.eq(7000);
+    expect(patient1CaseData.verificationTier.requiresMultisig).to.be.false;
+
+    // Case 3 Was Filed As Urgent, So It Gets The Shorter Urgent Verification Window
+    expect(patient3CaseData.urgency).to.deep.eq({ urgent: {} });
+    expect(patient3CaseData.verificationWindow.toNumber()).to.eq(3 * 24 * 60 * 60);
+
+    // Case 3 Reuses Case 1's Records While Case 1 Is Still Open, So It Is Flagged And Both Stay Live
+    expect(patient1CaseData.duplicateOf).to.eq(null);
+    expect(patient3CaseData.duplicateOf.toNumber()).to.eq(1);
+    const sharedRecords = await program.account.recordsFingerprint.fetch(recordsFingerprintPDA(1));
+    expect(sharedRecords.liveCaseIds.map((id) => id.toNumber())).to.deep.eq([1, 3]);
+    expect(sharedRecords.submissions).to.eq(2);
   });
 
   
@@ -620,7 +765,7 @@ describe("CuraChain", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("patient_escrow"),
-          Buffer.from("CASE0001"),
+          caseIdSeed(1),
           patient1CasePDA.toBuffer(),
         ],
         program.programId
@@ -632,7 +777,7 @@ describe("CuraChain", () => {
     );
 
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0001")],
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
       program.programId
     );
 
@@ -652,6 +797,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0001", true)
       .accounts({
         verifier: verifier1Keypair.publicKey,
+        bondVault: bondVault(1),
+        bondRecipient: patient1Keypair.publicKey,
+        caseAppeal: null,
         //@ts-ignore
         patientCase: patient1CasePDA,
         verifierAccount: verifier1PDA,
@@ -673,6 +821,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0001", true)
       .accounts({
         verifier: verifier2Keypair.publicKey,
+        bondVault: bondVault(1),
+        bondRecipient: patient1Keypair.publicKey,
+        caseAppeal: null,
         //@ts-ignore
         patientCase: patient1CasePDA,
         verifierAccount: verifier2PDA,
@@ -700,6 +851,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0001", false)
       .accounts({
         verifier: verifier3Keypair.publicKey,
+        bondVault: bondVault(1),
+        bondRecipient: patient1Keypair.publicKey,
+        caseAppeal: null,
         //@ts-ignore
         patientCase: patient1CasePDA,
         verifierAccount: verifier3PDA,
@@ -716,6 +870,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0001", true)
       .accounts({
         verifier: verifier5Keypair.publicKey,
+        bondVault: bondVault(1),
+        bondRecipient: patient1Keypair.publicKey,
+        caseAppeal: null,
         //@ts-ignore
         patientCase: patient1CasePDA,
         verifierAccount: verifier5PDA,
@@ -740,6 +897,10 @@ describe("CuraChain", () => {
 
     // Verification Status is True
     expect(Patient1VerificationData.isVerified).to.be.true;
+
+    // Once Verified, The Submission Bond Is Refunded And Its Vault Emptied
+    expect(Patient1VerificationData.submissionBond).to.eq(null);
+    expect(await provider.connection.getAccountInfo(bondVault(1))).to.eq(null);
   });
 
 
@@ -758,7 +919,7 @@ describe("CuraChain", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("patient_escrow"),
-          Buffer.from("CASE0002"),
+          caseIdSeed(2),
           patient2CasePDA.toBuffer(),
         ],
         program.programId
@@ -770,7 +931,7 @@ describe("CuraChain", () => {
     );
 
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0002")],
+      [Buffer.from("case_lookup"), caseIdSeed(2)],
       program.programId
     );
 
@@ -790,6 +951,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0002", true)
       .accounts({
         verifier: verifier1Keypair.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: patient2Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient2CasePDA,
         verfifierAccount: verifier1PDA,
@@ -811,6 +975,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0002", false)
       .accounts({
         verifier: verifier2Keypair.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: patient2Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient2CasePDA,
         verfifierAccount: verifier2PDA,
@@ -832,6 +999,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0002", true)
       .accounts({
         verifier: verifier3Keypair.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: patient2Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient2CasePDA,
         verifierAccount: verifier3PDA,
@@ -853,6 +1023,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0002", false)
       .accounts({
         verifier: verifier5Keypair.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: patient2Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient2CasePDA,
         verifierAccount: verifier5PDA,
@@ -874,6 +1047,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0002", true)
       .accounts({
         verifier: verifier6Keypair.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: patient2Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient2CasePDA,
         verifierAccount: verifier6PDA,
@@ -915,7 +1091,7 @@ describe("CuraChain", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("patient_escrow"),
-          Buffer.from("CASE0003"),
+          caseIdSeed(3),
           patient3CasePDA.toBuffer(),
         ],
         program.programId
@@ -927,7 +1103,7 @@ describe("CuraChain", () => {
     );
 
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0003")],
+      [Buffer.from("case_lookup"), caseIdSeed(3)],
       program.programId
     );
 
@@ -946,6 +1122,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0003", false)
       .accounts({
         verifier: verifier2Keypair.publicKey,
+        bondVault: bondVault(3),
+        bondRecipient: patient3Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient3CasePDA,
         verfifierAccount: verifier2PDA,
@@ -967,6 +1146,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0003", false)
       .accounts({
         verifier: verifier3Keypair.publicKey,
+        bondVault: bondVault(3),
+        bondRecipient: patient3Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient3CasePDA,
         verifierAccount: verifier3PDA,
@@ -988,6 +1170,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0003", false)
       .accounts({
         verifier: verifier5Keypair.publicKey,
+        bondVault: bondVault(3),
+        bondRecipient: patient3Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient3CasePDA,
         verifierAccount: verifier5PDA,
@@ -1009,6 +1194,9 @@ describe("CuraChain", () => {
       .verifyPatient("CASE0003", true)
       .accounts({
         verifier: verifier6Keypair.publicKey,
+        bondVault: bondVault(3),
+        bondRecipient: patient3Keypair.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         patientCase: patient3CasePDA,
         verifierAccount: verifier6PDA,
@@ -1050,7 +1238,7 @@ describe("CuraChain", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("patient_escrow"),
-          Buffer.from("CASE0002"),
+          caseIdSeed(2),
           patient2CasePDA.toBuffer(),
         ],
         program.programId
@@ -1062,7 +1250,7 @@ describe("CuraChain", () => {
     );
 
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0002")],
+      [Buffer.from("case_lookup"), caseIdSeed(2)],
       program.programId
     );
 
@@ -1083,6 +1271,9 @@ describe("CuraChain", () => {
         .verifyPatient("CASE0002", true)
         .accounts({
           verifier: verifier5Keypair.publicKey,
+          bondVault: bondVault(2),
+          bondRecipient: patient2Keypair.publicKey,
+          caseAppeal: null,
           // @ts-ignore
           patientCase: patient2CasePDA,
           verifierAccount: verifier5PDA,
@@ -1119,7 +1310,7 @@ describe("CuraChain", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("patient_escrow"),
-          Buffer.from("CASE0001"),
+          caseIdSeed(1),
           patient1CasePDA.toBuffer(),
         ],
         program.programId
@@ -1131,7 +1322,7 @@ describe("CuraChain", () => {
     );
 
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0001")],
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
       program.programId
     );
 
@@ -1146,6 +1337,9 @@ describe("CuraChain", () => {
         .verifyPatient("CASE0001", true)
         .accounts({
           verifier: verifier6Keypair.publicKey,
+          bondVault: bondVault(1),
+          bondRecipient: patient1Keypair.publicKey,
+          caseAppeal: null,
           // @ts-ignore
           patientCase: patient1CasePDA,
           verifierAccount: verifier6PDA,
@@ -1245,7 +1439,7 @@ describe("CuraChain", () => {
     );
     
     const [caseLookupPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0001")],
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
       program.programId
     );
     
@@ -1257,7 +1451,7 @@ describe("CuraChain", () => {
     const [patientEscrowPDA] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("patient_escrow"),
-        Buffer.from("CASE0001"),
+        caseIdSeed(1),
         patient1CasePDA.toBuffer(),
       ],
       program.programId
@@ -1277,6 +1471,17 @@ describe("CuraChain", () => {
     
     // Test a simple SOL donation
     const donationAmount = new BN(0.1 * LAMPORTS_PER_SOL);
+
+    // Donor 1's SOL Contribution Record For This Case
+    const [donor1ContributionPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution"),
+        caseIdSeed(1),
+        donor1Keypair.publicKey.toBuffer(),
+        PublicKey.default.toBuffer(),
+      ],
+      program.programId
+    );
     
     try {
       await program.methods
@@ -1288,6 +1493,7 @@ describe("CuraChain", () => {
           patientCase: patient1CasePDA,
           patientEscrow: patientEscrowPDA,
           multisig: multisigPDA,
+          contribution: donor1ContributionPDA,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -1378,7 +1584,7 @@ describe("CuraChain", () => {
     const [patientTokenVaultPDA] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("patient_token_vault"),
-        Buffer.from(caseId),
+        caseIdSeed(Number(caseId.replace("CASE", ""))),
         patientEscrowPDA.toBuffer(),
         mint.toBuffer()
       ],
@@ -1469,7 +1675,11 @@ describe("CuraChain", () => {
     // Let's get the respective PDAs
     // Pretty Clear Case 3 Was Rejected, as out of 4 Verifiers, 3 rejected and only 1 approved.
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0003")],
+      [Buffer.from("case_lookup"), caseIdSeed(3)],
+      program.programId
+    );
+    const [caseTombstonePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rejected_case"), caseIdSeed(3)],
       program.programId
     );
     const [verifiersListPDA, verifiersListBump] =
@@ -1483,14 +1693,43 @@ describe("CuraChain", () => {
         program.programId
       );
 
+    // With No Appeal Window Configured, The Rejection Is Final And The Case Cannot Be Appealed
+    const [caseAppealPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_appeal"), caseIdSeed(3)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .appealCase("CASE0003", caseManifest(7, "ipfs://case3-appeal-evidence"))
+        .accounts({
+          appellant: patient3Keypair.publicKey,
+          // @ts-ignore
+          caseLookup: caseLookupPDA,
+          patientCase: patient3CasePDA,
+          caseAppeal: caseAppealPDA,
+          verifiersList: verifiersListPDA,
+        })
+        .signers([patient3Keypair])
+        .rpc();
+      expect.fail("Appeal Should Have Failed Once The Window Closed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.eq("AppealWindowClosed");
+    }
+
     // Let Patient 2 call that instruction
     await program.methods
       .closeRejectedCase("CASE0003")
       .accounts({
         user: patient2Keypair.publicKey,
+        bondVault: bondVault(3),
+        bondRecipient: bondForfeitPool.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         caseLookup: caseLookupPDA,
         patientCase: patient3CasePDA,
+        submitter: patient3Keypair.publicKey,
+        recordsFingerprint: recordsFingerprintPDA(1),
+        caseTombstone: caseTombstonePDA,
         verifiersList: verifiersListPDA,
       })
       .signers([patient2Keypair])
@@ -1502,6 +1741,15 @@ describe("CuraChain", () => {
       patient3CasePDA
     );
     expect(patient3CaseCloseData).to.eq(null);
+
+    // The Lookup Is Closed Too, Leaving Only A Tombstone Recording The Case ID As Rejected
+    expect(await provider.connection.getAccountInfo(caseLookupPDA)).to.eq(null);
+    const case3Tombstone = await program.account.rejectedCaseTombstone.fetch(caseTombstonePDA);
+    expect(case3Tombstone.caseId.toNumber()).to.eq(3);
+
+    // Verifiers Rejected The Case, So Its Submission Bond Went To The Forfeit Pool
+    expect(await provider.connection.getAccountInfo(bondVault(3))).to.eq(null);
+    expect(await provider.connection.getBalance(bondForfeitPool.publicKey)).to.be.greaterThanOrEqual(0.01 * LAMPORTS_PER_SOL);
   });
 
 
@@ -1509,7 +1757,11 @@ describe("CuraChain", () => {
   it("Test 23- A verified case cannot be CLOSED, even by the ADMIN", async () => {
     // Pretty Clear Case I is verified. Attempt to close it will produce an error
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0001")],
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [caseTombstonePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rejected_case"), caseIdSeed(1)],
       program.programId
     );
     const [verifiersListPDA, verifiersListBump] =
@@ -1529,9 +1781,15 @@ describe("CuraChain", () => {
         .closeRejectedCase("CASE0001")
         .accounts({
           user: newAdmin.publicKey,
+          bondVault: bondVault(1),
+          bondRecipient: bondForfeitPool.publicKey,
+          caseAppeal: null,
           // @ts-ignore
           caseLookup: caseLookupPDA,
           patientCase: patient1CasePDA,
+          submitter: patient1Keypair.publicKey,
+          recordsFingerprint: recordsFingerprintPDA(1),
+          caseTombstone: caseTombstonePDA,
           verifiersList: verifiersListPDA,
         })
         .signers([newAdmin])
@@ -1546,7 +1804,11 @@ describe("CuraChain", () => {
 
     // Pretty Clear Case I is verified. Attempt to close it will produce an error
     const [caseLookupPDA, caseLookupBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("case_lookup"), Buffer.from("CASE0002")],
+      [Buffer.from("case_lookup"), caseIdSeed(2)],
+      program.programId
+    );
+    const [caseTombstonePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rejected_case"), caseIdSeed(2)],
       program.programId
     );
     const [verifiersListPDA, verifiersListBump] =
@@ -1565,9 +1827,16 @@ describe("CuraChain", () => {
       .closeRejectedCase("CASE0002")
       .accounts({
         user: newAdmin.publicKey,
+        bondVault: bondVault(2),
+        bondRecipient: bondForfeitPool.publicKey,
+        caseAppeal: null,
         // @ts-ignore
         caseLookup: caseLookupPDA,
         patientCase: patient2CasePDA,
+        // Case 2 Was Sponsored, So Its Rent Goes Back To The NGO
+        submitter: ngoSponsorKeypair.publicKey,
+        recordsFingerprint: recordsFingerprintPDA(2),
+        caseTombstone: caseTombstonePDA,
         verifiersList: verifiersListPDA,
       })
       .signers([newAdmin])
@@ -1580,4 +1849,277 @@ describe("CuraChain", () => {
     );
     expect(patient2CaseCloseData).to.eq(null);
   });
-});
+
+
+  //READ-ONLY VIEWS RETURN TYPED DATA WITHOUT ANY SIGNER
+  it("Test 25- Views return a case summary and protocol stats", async () => {
+    const [caseLookupPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [patient1CasePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient"), patient1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const caseSummary = await program.methods
+      .getCaseSummary("CASE0001")
+      .accounts({
+        // @ts-ignore
+        caseLookup: caseLookupPDA,
+        patientCase: patient1CasePDA,
+      })
+      .view();
+
+    expect(caseSummary.caseId.toNumber()).to.eq(1);
+    expect(caseSummary.isVerified).to.be.true;
+    expect(caseSummary.beneficiary.toBase58()).to.eq(patient1Keypair.publicKey.toBase58());
+    expect(caseSummary.verificationYesVotes).to.eq(3);
+
+    const protocolStats = await program.methods.getProtocolStats().view();
+
+    expect(protocolStats.totalCasesSubmitted.toNumber()).to.eq(3);
+    expect(protocolStats.submissionBondAmount.toNumber()).to.eq(0.01 * LAMPORTS_PER_SOL);
+  });
+
+
+  //THE PATIENT POSTS A PROGRESS UPDATE TO THEIR VERIFIED CASE
+  it("Test 26- Patient posts a progress update to the case timeline", async () => {
+    const [caseLookupPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [patient1CasePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient"), patient1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+    // The First Page Of Updates For Case 1
+    const [caseUpdatePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_update"), caseIdSeed(1), new BN(0).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+
+    await program.methods
+      .postCaseUpdate("CASE0001", { treatmentStarted: {} }, Array.from({ length: 32 }, () => 7), "www.github.com/updates/1.json")
+      .accounts({
+        poster: patient1Keypair.publicKey,
+        facility: null,
+        // @ts-ignore
+        caseLookup: caseLookupPDA,
+        patientCase: patient1CasePDA,
+        caseUpdate: caseUpdatePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([patient1Keypair])
+      .rpc();
+
+    const caseUpdatePage = await program.account.caseUpdate.fetch(caseUpdatePDA);
+    expect(caseUpdatePage.updates.length).to.eq(1);
+    expect(caseUpdatePage.updates[0].uri).to.eq("www.github.com/updates/1.json");
+
+    const patient1CaseData = await program.account.patientCase.fetch(patient1CasePDA);
+    expect(patient1CaseData.updateCount).to.eq(1);
+  });
+
+  it("Test 27- Verifiers cannot pause a case that has already been verified", async () => {
+    const [caseLookupPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [patient1CasePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient"), patient1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const [verifier1PDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_role"), verifier1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .requestInfo("CASE0001", Array.from({ length: 32 }, () => 9))
+        .accounts({
+          verifier: verifier1Keypair.publicKey,
+          // @ts-ignore
+          verifierAccount: verifier1PDA,
+          caseLookup: caseLookupPDA,
+          patientCase: patient1CasePDA,
+        })
+        .signers([verifier1Keypair])
+        .rpc();
+      expect.fail("Requesting Information On A Verified Case Should Fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.eq("CaseNotPendingVerification");
+    }
+
+    const patient1CaseData = await program.account.patientCase.fetch(patient1CasePDA);
+    expect(patient1CaseData.infoRequest).to.eq(null);
+  });
+
+  it("Test 28- Admin sets a verifier's vote weight, keeping the registry's total weight in step", async () => {
+    const [adminPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin"), newAdmin.publicKey.toBuffer()],
+      program.programId
+    );
+    const [verifier6PDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_role"), verifier6Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const [verifiersRegistryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifiers_list")],
+      program.programId
+    );
+
+    const totalWeightBefore = (await program.account.verifiersList.fetch(verifiersRegistryPDA)).totalWeight.toNumber();
+
+    await program.methods
+      .setVerifierWeight(verifier6Keypair.publicKey, 3)
+      .accounts({
+        admin: newAdmin.publicKey,
+        // @ts-ignore
+        adminAccount: adminPDA,
+        verifier: verifier6PDA,
+        verifiersList: verifiersRegistryPDA,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    const verifier6Details = await program.account.verifier.fetch(verifier6PDA);
+    expect(verifier6Details.voteWeight).to.eq(3);
+    const totalWeightAfter = (await program.account.verifiersList.fetch(verifiersRegistryPDA)).totalWeight.toNumber();
+    expect(totalWeightAfter).to.eq(totalWeightBefore + 2);
+
+    // A Weight Of Zero Would Silence The Verifier Without Removing Them
+    try {
+      await program.methods
+        .setVerifierWeight(verifier6Keypair.publicKey, 0)
+        .accounts({
+          admin: newAdmin.publicKey,
+          // @ts-ignore
+          adminAccount: adminPDA,
+          verifier: verifier6PDA,
+          verifiersList: verifiersRegistryPDA,
+        })
+        .signers([newAdmin])
+        .rpc();
+      expect.fail("A Zero Vote Weight Should Be Rejected");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.eq("InvalidVerifierWeight");
+    }
+  });
+
+  it("Test 29- Finalizing verification fails on a case that verifiers already settled", async () => {
+    const [caseLookupPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [patient1CasePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient"), patient1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const [patientEscrowPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient_escrow"), caseIdSeed(1), patient1CasePDA.toBuffer()],
+      program.programId
+    );
+    const [verifiersRegistryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifiers_list")],
+      program.programId
+    );
+
+    // Anybody May Call It; A Random Caller Is Turned Away Only Because The Case Is Already Verified
+    try {
+      await program.methods
+        .finalizeVerification("CASE0001")
+        .accounts({
+          caller: ngoSponsorKeypair.publicKey,
+          // @ts-ignore
+          verifiersList: verifiersRegistryPDA,
+          caseLookup: caseLookupPDA,
+          patientCase: patient1CasePDA,
+          patientEscrow: patientEscrowPDA,
+          caseAppeal: null,
+          bondVault: bondVault(1),
+          bondRecipient: patient1Keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ngoSponsorKeypair])
+        .rpc();
+      expect.fail("Finalizing A Verified Case Should Fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.eq("CaseAlreadyVerified");
+    }
+
+    const patient1CaseData = await program.account.patientCase.fetch(patient1CasePDA);
+    expect(patient1CaseData.isVerified).to.be.true;
+    expect(patient1CaseData.allowEarlyApproval).to.be.true;
+  });
+
+  it("Test 30- Admin builds the senior panel, whose members only vote on escalated cases", async () => {
+    const [adminPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin"), newAdmin.publicKey.toBuffer()],
+      program.programId
+    );
+    const [seniorPanelPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("senior_panel")],
+      program.programId
+    );
+    const [protocolConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
+      program.programId
+    );
+    const [caseLookupPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_lookup"), caseIdSeed(1)],
+      program.programId
+    );
+    const [patient1CasePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient"), patient1Keypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const [patientEscrowPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("patient_escrow"), caseIdSeed(1), patient1CasePDA.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .addOrRemoveSeniorVerifier(verifier1Keypair.publicKey, { add: {} })
+      .accounts({
+        admin: newAdmin.publicKey,
+        // @ts-ignore
+        adminAccount: adminPDA,
+        seniorPanel: seniorPanelPDA,
+        protocolConfig: protocolConfigPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    const seniorPanelData = await program.account.seniorPanel.fetch(seniorPanelPDA);
+    expect(seniorPanelData.members.map((member) => member.toBase58())).to.deep.eq([verifier1Keypair.publicKey.toBase58()]);
+
+    // CASE0001 Was Verified Outright, So There Is Nothing For The Panel To Decide
+    try {
+      await program.methods
+        .seniorPanelVote("CASE0001", true)
+        .accounts({
+          seniorVerifier: verifier1Keypair.publicKey,
+          // @ts-ignore
+          seniorPanel: seniorPanelPDA,
+          caseLookup: caseLookupPDA,
+          patientCase: patient1CasePDA,
+          patientEscrow: patientEscrowPDA,
+          caseAppeal: null,
+          bondVault: bondVault(1),
+          bondRecipient: patient1Keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier1Keypair])
+        .rpc();
+      expect.fail("Senior Panel Voting On A Case That Was Never Escalated Should Fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.eq("CaseNotEscalated");
+    }
+
+    const patient1CaseData = await program.account.patientCase.fetch(patient1CasePDA);
+    expect(patient1CaseData.escalation).to.eq(null);
+  });
+});
\ No newline at end of file